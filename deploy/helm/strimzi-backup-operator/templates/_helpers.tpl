@@ -67,3 +67,102 @@ Container image
 {{- define "strimzi-backup-operator.image" -}}
 {{- printf "%s:%s" .Values.image.repository (default .Chart.AppVersion .Values.image.tag) }}
 {{- end }}
+
+{{/*
+The KafkaBackup and KafkaRestore CRDs from files/crds. With conversion, their
+spec.conversion points at the webhook Service serviceName, trusting caBundle,
+or the CA cert-manager injects. Expects a dict with the root context and
+conversion, serviceName and caBundle.
+*/}}
+{{- define "strimzi-backup-operator.convertedCrds" -}}
+{{- $root := .root }}
+{{- range $file := list "kafkabackups.yaml" "kafkarestores.yaml" }}
+{{- $crd := $root.Files.Get (printf "files/crds/%s" $file) | fromYaml }}
+{{- $annotations := dict }}
+{{- if $root.Values.crds.keep }}
+{{- $_ := set $annotations "helm.sh/resource-policy" "keep" }}
+{{- end }}
+{{- if $.conversion }}
+{{- $clientConfig := dict "service" (dict "name" $.serviceName "namespace" $root.Release.Namespace "path" "/convert") }}
+{{- if $.caBundle }}
+{{- $_ := set $clientConfig "caBundle" $.caBundle }}
+{{- end }}
+{{- $_ := set $crd.spec "conversion" (dict "strategy" "Webhook" "webhook" (dict "clientConfig" $clientConfig "conversionReviewVersions" (list "v1"))) }}
+{{- if $root.Values.webhook.certManager.enabled }}
+{{- $_ := set $annotations "cert-manager.io/inject-ca-from" (printf "%s/%s-webhook" $root.Release.Namespace (include "strimzi-backup-operator.fullname" $root)) }}
+{{- end }}
+{{- end }}
+{{- $_ := set $crd.metadata "labels" (include "strimzi-backup-operator.labels" $root | fromYaml) }}
+{{- if $annotations }}
+{{- $_ := set $crd.metadata "annotations" $annotations }}
+{{- end }}
+---
+{{ toYaml $crd }}
+{{- end }}
+{{- end }}
+
+{{/*
+Rules of the operator's ClusterRole, or of its Role in each watched namespace
+when clusterScoped is false. Expects a dict with the root context's Values
+and clusterScoped.
+*/}}
+{{- define "strimzi-backup-operator.rules" -}}
+# Read Strimzi resources
+- apiGroups: ["kafka.strimzi.io"]
+  resources: ["kafkas", "kafkanodepools", "kafkatopics", "kafkausers", "kafkaconnects", "kafkaconnectors"]
+  verbs: ["get", "list", "watch"]
+# Rebuild a missing cluster for restores with createCluster
+- apiGroups: ["kafka.strimzi.io"]
+  resources: ["kafkas", "kafkanodepools"]
+  verbs: ["create", "patch"]
+# Recreate backed-up topics on restore, and create and delete scratch
+# topics for KafkaBackupVerification runs
+- apiGroups: ["kafka.strimzi.io"]
+  resources: ["kafkatopics"]
+  verbs: ["create", "patch", "delete"]
+# Recreate backed-up KafkaUsers, KafkaConnects and KafkaConnectors on restore
+- apiGroups: ["kafka.strimzi.io"]
+  resources: ["kafkausers", "kafkaconnects", "kafkaconnectors"]
+  verbs: ["create", "patch"]
+# Manage backup CRDs
+- apiGroups: ["kafkabackup.com"]
+  resources: ["kafkabackups", "kafkarestores", "kafkabackupschedules", "kafkabackupsnapshots", "backupstoragelocations", {{ if .clusterScoped }}"clusterbackupstoragelocations", {{ end }}"kafkabackupverifications"]
+  verbs: ["get", "list", "watch", "create", "update", "patch", "delete"]
+# Read grants allowing cross-namespace backupRefs
+- apiGroups: ["kafkabackup.com"]
+  resources: ["kafkabackupgrants"]
+  verbs: ["get", "list", "watch"]
+- apiGroups: ["kafkabackup.com"]
+  resources: ["kafkabackups/status", "kafkarestores/status", "kafkabackupschedules/status", "kafkabackupsnapshots/status", "backupstoragelocations/status", {{ if .clusterScoped }}"clusterbackupstoragelocations/status", {{ end }}"kafkabackupverifications/status"]
+  verbs: ["get", "update", "patch"]
+- apiGroups: ["kafkabackup.com"]
+  resources: ["kafkabackups/finalizers", "kafkarestores/finalizers", "kafkabackupschedules/finalizers", "kafkabackupsnapshots/finalizers", "kafkabackupverifications/finalizers"]
+  verbs: ["update"]
+# Create backup/restore jobs
+- apiGroups: ["batch"]
+  resources: ["jobs", "cronjobs"]
+  verbs: ["get", "list", "watch", "create", "update", "patch", "delete"]
+# Read secrets for credentials; copy storage credentials for
+# cross-namespace restores granted by a KafkaBackupGrant
+- apiGroups: [""]
+  resources: ["secrets"]
+  verbs: ["get", "list", "watch", "create", "patch", "delete"]
+# Manage configmaps for job configs
+- apiGroups: [""]
+  resources: ["configmaps"]
+  verbs: ["get", "list", "watch", "create", "update", "patch", "delete"]
+# Emit Kubernetes events
+- apiGroups: ["", "events.k8s.io"]
+  resources: ["events"]
+  verbs: ["create", "patch"]
+# Watch pods for job status
+- apiGroups: [""]
+  resources: ["pods"]
+  verbs: ["get", "list", "watch"]
+# Leader election
+{{- if and .clusterScoped .Values.leaderElection.enabled }}
+- apiGroups: ["coordination.k8s.io"]
+  resources: ["leases"]
+  verbs: ["get", "list", "watch", "create", "update", "patch", "delete"]
+{{- end }}
+{{- end }}
@@ -8,6 +8,7 @@ use crate::strimzi::tls::ResolvedTlsCerts;
 
 use super::logging_config::build_logging_config;
 use super::storage_config::build_storage_config;
+use super::storage_location::backup_storage;
 
 /// Build the complete kafka-backup config YAML from a KafkaBackup CR and resolved resources
 pub fn build_backup_config_yaml(
@@ -24,23 +25,11 @@ pub fn build_backup_config_yaml(
         Value::String("backup".to_string()),
     );
 
-    // Backup ID: incremental backups require a stable id across runs so the CLI can
-    // merge manifests and resume from the last saved offset (kafka-backup >= v0.13.5).
-    // We infer "incremental" from the same predicate the CLI itself uses —
-    // `offset_storage` is set and `continuous` is not `true`. In all other cases we
-    // keep the env-var form so scheduled jobs get a unique id per run.
-    let backup_id = if is_incremental_one_shot(backup) {
-        backup
-            .metadata
-            .name
-            .clone()
-            .unwrap_or_else(|| "backup".to_string())
-    } else {
-        "${BACKUP_ID}".to_string()
-    };
+    // Backup ID: the env-var form resolves to the Job name, so scheduled jobs
+    // get a unique id per run
     config.insert(
         Value::String("backup_id".to_string()),
-        Value::String(backup_id),
+        Value::String(backup_id_for(backup, "${BACKUP_ID}")),
     );
 
     // Source (Kafka cluster)
@@ -54,7 +43,7 @@ pub fn build_backup_config_yaml(
     config.insert(Value::String("source".to_string()), source);
 
     // Storage
-    let storage = build_storage_config(&backup.spec.storage)?;
+    let storage = build_storage_config(backup_storage(backup)?)?;
     config.insert(Value::String("storage".to_string()), storage);
 
     // Logging options
@@ -191,7 +180,9 @@ fn build_kafka_config(
 }
 
 /// Build backup options section
-fn build_backup_options(opts: &crate::crd::kafka_backup::BackupOptionsSpec) -> Result<Value> {
+pub(crate) fn build_backup_options(
+    opts: &crate::crd::kafka_backup::BackupOptionsSpec,
+) -> Result<Value> {
     let mut config = serde_yaml::Mapping::new();
 
     if opts.encryption.as_ref().is_some_and(|e| e.enabled) {
@@ -406,6 +397,23 @@ fn build_offset_storage_options(offset_storage: &crate::crd::common::OffsetStora
     Value::Mapping(config)
 }
 
+/// Backup ID the Job `job_name` of `backup` writes its manifest under.
+///
+/// Incremental backups require a stable id across runs so the CLI can merge
+/// manifests and resume from the last saved offset (kafka-backup >= v0.13.5),
+/// so they use the KafkaBackup's name. All other runs use the Job name.
+pub fn backup_id_for(backup: &KafkaBackup, job_name: &str) -> String {
+    if is_incremental_one_shot(backup) {
+        backup
+            .metadata
+            .name
+            .clone()
+            .unwrap_or_else(|| "backup".to_string())
+    } else {
+        job_name.to_string()
+    }
+}
+
 /// Whether this backup will behave as an incremental one-shot run.
 ///
 /// Mirrors the predicate in kafka-backup's `should_create_offset_store`: the offset
@@ -470,7 +478,7 @@ mod tests {
             connection: None,
             logging: None,
             env: Vec::new(),
-            storage: StorageSpec {
+            storage: Some(StorageSpec {
                 storage_type: StorageType::S3,
                 s3: Some(S3StorageSpec {
                     bucket: "test-bucket".to_string(),
@@ -486,7 +494,8 @@ mod tests {
                 azure: None,
                 gcs: None,
                 filesystem: None,
-            },
+            }),
+            storage_ref: None,
             backup: Some(BackupOptionsSpec {
                 compression: Some("zstd".to_string()),
                 compression_level: None,
@@ -515,6 +524,9 @@ mod tests {
             image: None,
             backoff_limit: None,
             consumer_groups: None,
+            users: None,
+            connect: None,
+            schema_registry: None,
         };
         let mut backup = KafkaBackup::new("test-backup", spec);
         backup.metadata.namespace = Some("kafka".to_string());
@@ -0,0 +1,120 @@
+use kube::api::ListParams;
+use kube::{Api, Client};
+
+use crate::crd::{BackupRefKind, KafkaBackupGrant};
+use crate::error::{Error, Result};
+
+/// Check that a KafkaBackupGrant in `source_namespace` lets KafkaRestores in
+/// `restore_namespace` reference the `kind` named `name`. References within
+/// one namespace need no grant.
+pub async fn check_backup_grant(
+    client: &Client,
+    restore_namespace: &str,
+    source_namespace: &str,
+    kind: &BackupRefKind,
+    name: &str,
+) -> Result<()> {
+    if restore_namespace == source_namespace {
+        return Ok(());
+    }
+
+    let grant_api: Api<KafkaBackupGrant> = Api::namespaced(client.clone(), source_namespace);
+    let grants = grant_api.list(&ListParams::default()).await?;
+    if grants
+        .items
+        .iter()
+        .any(|grant| grant_permits(grant, restore_namespace, kind, name))
+    {
+        return Ok(());
+    }
+
+    Err(Error::BackupRefNotPermitted {
+        kind: format!("{kind:?}"),
+        name: name.to_string(),
+        namespace: source_namespace.to_string(),
+        from: restore_namespace.to_string(),
+    })
+}
+
+/// Whether `grant` lets KafkaRestores in `from_namespace` reference the
+/// `kind` named `name` in the grant's namespace
+pub fn grant_permits(
+    grant: &KafkaBackupGrant,
+    from_namespace: &str,
+    kind: &BackupRefKind,
+    name: &str,
+) -> bool {
+    grant
+        .spec
+        .from
+        .iter()
+        .any(|from| from.namespace == from_namespace)
+        && grant
+            .spec
+            .to
+            .iter()
+            .any(|to| &to.kind == kind && to.name.as_deref().is_none_or(|granted| granted == name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grant(to: serde_json::Value) -> KafkaBackupGrant {
+        serde_json::from_value(serde_json::json!({
+            "apiVersion": "kafkabackup.com/v1alpha1",
+            "kind": "KafkaBackupGrant",
+            "metadata": {"name": "team-a", "namespace": "kafka-backups"},
+            "spec": {"from": [{"namespace": "team-a"}], "to": to}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn grant_permits_named_resource_to_listed_namespace_only() {
+        let grant = grant(serde_json::json!([{"kind": "KafkaBackup", "name": "orders"}]));
+
+        assert!(grant_permits(
+            &grant,
+            "team-a",
+            &BackupRefKind::KafkaBackup,
+            "orders"
+        ));
+        assert!(!grant_permits(
+            &grant,
+            "team-a",
+            &BackupRefKind::KafkaBackup,
+            "payments"
+        ));
+        assert!(!grant_permits(
+            &grant,
+            "team-b",
+            &BackupRefKind::KafkaBackup,
+            "orders"
+        ));
+        assert!(!grant_permits(
+            &grant,
+            "team-a",
+            &BackupRefKind::KafkaBackupSnapshot,
+            "orders"
+        ));
+    }
+
+    #[test]
+    fn grant_without_name_permits_every_resource_of_kind() {
+        let grant = grant(serde_json::json!([{"kind": "KafkaBackupSnapshot"}]));
+
+        assert!(grant_permits(
+            &grant,
+            "team-a",
+            &BackupRefKind::KafkaBackupSnapshot,
+            "orders-20260213-020000"
+        ));
+        assert!(!grant_permits(
+            &grant,
+            "team-a",
+            &BackupRefKind::KafkaBackup,
+            "orders"
+        ));
+    }
+}
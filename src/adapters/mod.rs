@@ -1,8 +1,11 @@
 pub mod backup_config;
+pub mod backup_grant;
 pub mod logging_config;
 pub mod restore_config;
+pub mod schema_registry;
 pub mod secrets;
 pub mod storage_config;
+pub mod storage_location;
 
 /// SASL mechanism value for SCRAM-SHA-512 as the kafka-backup binary's config
 /// parser expects it: `SCRAM-SHA512`, with no hyphen before the digits. The
@@ -1,5 +1,6 @@
 use serde_yaml::Value;
 
+use crate::crd::common::StorageSpec;
 use crate::crd::{KafkaBackup, KafkaRestore};
 use crate::error::{Error, Result};
 use crate::strimzi::kafka_cr::ResolvedKafkaCluster;
@@ -9,11 +10,32 @@ use crate::strimzi::tls::ResolvedTlsCerts;
 use super::backup_config::build_topic_selection;
 use super::logging_config::build_logging_config;
 use super::storage_config::build_storage_config;
+use super::storage_location::backup_storage;
+
+/// The backup a restore reads from, resolved from `spec.backupRef`
+#[derive(Clone, Debug)]
+pub struct RestoreSource {
+    /// Storage destination holding the backup
+    pub storage: StorageSpec,
+    /// ID of the backup to restore
+    pub backup_id: String,
+}
+
+impl RestoreSource {
+    /// Restore from `source_backup`, whose storage must be resolved. Uses
+    /// `backupRef.backupId` if set, else the backup's latest backup ID.
+    pub fn from_backup(restore: &KafkaRestore, source_backup: &KafkaBackup) -> Result<Self> {
+        Ok(Self {
+            storage: backup_storage(source_backup)?.clone(),
+            backup_id: resolve_backup_id(restore, source_backup)?,
+        })
+    }
+}
 
 /// Build the complete kafka-backup config YAML for a restore operation
 pub fn build_restore_config_yaml(
     restore: &KafkaRestore,
-    source_backup: &KafkaBackup,
+    source: &RestoreSource,
     cluster: &ResolvedKafkaCluster,
     tls_certs: &Option<ResolvedTlsCerts>,
     auth: &ResolvedAuth,
@@ -26,10 +48,9 @@ pub fn build_restore_config_yaml(
         Value::String("restore".to_string()),
     );
 
-    let backup_id = resolve_backup_id(restore, source_backup)?;
     config.insert(
         Value::String("backup_id".to_string()),
-        Value::String(backup_id),
+        Value::String(source.backup_id.clone()),
     );
 
     // Target (Kafka cluster)
@@ -42,8 +63,8 @@ pub fn build_restore_config_yaml(
     )?;
     config.insert(Value::String("target".to_string()), target);
 
-    // Storage (from source backup CR)
-    let storage = build_storage_config(&source_backup.spec.storage)?;
+    // Storage (from the resolved backup source)
+    let storage = build_storage_config(&source.storage)?;
     config.insert(Value::String("storage".to_string()), storage);
 
     // Logging options
@@ -167,15 +188,17 @@ fn build_kafka_config(
 }
 
 /// Build restore-specific options
-fn build_restore_options(restore: &KafkaRestore) -> Result<Value> {
+pub(crate) fn build_restore_options(restore: &KafkaRestore) -> Result<Value> {
     let mut config = serde_yaml::Mapping::new();
 
     // Point-in-time recovery
     if let Some(pitr) = &restore.spec.point_in_time {
+        let mut window_start = None;
         if let Some(timestamp) = &pitr.start_timestamp {
             let dt = chrono::DateTime::parse_from_rfc3339(timestamp).map_err(|_| {
                 Error::InvalidConfig(format!("Invalid startTimestamp format: {timestamp}"))
             })?;
+            window_start = Some(dt);
             config.insert(
                 Value::String("time_window_start".to_string()),
                 Value::Number(serde_yaml::Number::from(dt.timestamp_millis())),
@@ -185,6 +208,11 @@ fn build_restore_options(restore: &KafkaRestore) -> Result<Value> {
             let dt = chrono::DateTime::parse_from_rfc3339(timestamp).map_err(|_| {
                 Error::InvalidConfig(format!("Invalid timestamp format: {timestamp}"))
             })?;
+            if window_start.is_some_and(|start| start >= dt) {
+                return Err(Error::InvalidConfig(
+                    "pointInTime.startTimestamp must be before pointInTime.timestamp".to_string(),
+                ));
+            }
             config.insert(
                 Value::String("time_window_end".to_string()),
                 Value::Number(serde_yaml::Number::from(dt.timestamp_millis())),
@@ -201,7 +229,14 @@ fn build_restore_options(restore: &KafkaRestore) -> Result<Value> {
     // Topic mapping
     if !restore.spec.topic_mapping.is_empty() {
         let mut mapping = serde_yaml::Mapping::new();
+        let mut targets = std::collections::BTreeSet::new();
         for entry in &restore.spec.topic_mapping {
+            if !targets.insert(entry.target_topic.as_str()) {
+                return Err(Error::InvalidConfig(format!(
+                    "topicMapping must not map two topics to the same targetTopic '{}'",
+                    entry.target_topic
+                )));
+            }
             mapping.insert(
                 Value::String(entry.source_topic.clone()),
                 Value::String(entry.target_topic.clone()),
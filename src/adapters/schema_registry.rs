@@ -0,0 +1,344 @@
+use kube::Client;
+use reqwest::{Method, StatusCode, Url};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{json, Value};
+use tracing::{debug, info};
+
+use crate::adapters::secrets::{extract_secret_data, get_secret};
+use crate::crd::common::{SchemaRegistrySpec, SubjectSelection};
+use crate::error::{Error, Result};
+
+/// Content type of Schema Registry REST requests and responses
+const CONTENT_TYPE: &str = "application/vnd.schemaregistry.v1+json";
+
+/// Registry mode in which schemas can be registered under given IDs
+const IMPORT_MODE: &str = "IMPORT";
+
+/// Schemas, compatibility levels and modes exported from a Schema Registry
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaRegistryExport {
+    /// Global compatibility level
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compatibility: Option<String>,
+    /// Global mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+    #[serde(default)]
+    pub subjects: Vec<SubjectExport>,
+}
+
+/// A subject with its registered schema versions
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SubjectExport {
+    pub subject: String,
+    /// Compatibility level set on the subject itself
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compatibility: Option<String>,
+    /// Mode set on the subject itself
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+    #[serde(default)]
+    pub versions: Vec<SchemaVersion>,
+}
+
+/// One registered version of a subject, as returned by
+/// `GET /subjects/{subject}/versions/{version}`
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaVersion {
+    pub version: i32,
+    pub id: i64,
+    /// Schema format; AVRO when absent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_type: Option<String>,
+    pub schema: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub references: Vec<Value>,
+}
+
+/// Client for the REST API of a Confluent-compatible Schema Registry
+pub struct SchemaRegistryClient {
+    http: reqwest::Client,
+    url: Url,
+    credentials: Option<(String, String)>,
+}
+
+impl SchemaRegistryClient {
+    /// Create a client for `url`, with optional basic-auth `credentials`
+    pub fn new(url: &str, credentials: Option<(String, String)>) -> Result<Self> {
+        let url = Url::parse(url).map_err(|e| {
+            Error::InvalidConfig(format!("invalid Schema Registry URL '{url}': {e}"))
+        })?;
+        if url.cannot_be_a_base() {
+            return Err(Error::InvalidConfig(format!(
+                "invalid Schema Registry URL '{url}'"
+            )));
+        }
+        Ok(Self {
+            http: reqwest::Client::new(),
+            url,
+            credentials,
+        })
+    }
+
+    /// Create a client for `spec`, reading its credentials from Secrets in
+    /// `namespace`
+    pub async fn from_spec(
+        client: &Client,
+        namespace: &str,
+        spec: &SchemaRegistrySpec,
+    ) -> Result<Self> {
+        let credentials = match &spec.authentication {
+            Some(auth) => {
+                let username_secret =
+                    get_secret(client, &auth.username_secret.name, namespace).await?;
+                let password_secret =
+                    get_secret(client, &auth.password_secret.name, namespace).await?;
+                Some((
+                    extract_secret_data(&username_secret, &auth.username_secret.key)?,
+                    extract_secret_data(&password_secret, &auth.password_secret.key)?,
+                ))
+            }
+            None => None,
+        };
+        Self::new(&spec.url, credentials)
+    }
+
+    /// Export the subjects selected by `selection` with all their versions,
+    /// plus the global and per-subject compatibility levels and modes
+    pub async fn export(
+        &self,
+        selection: Option<&SubjectSelection>,
+    ) -> Result<SchemaRegistryExport> {
+        let mut export = SchemaRegistryExport {
+            compatibility: self.compatibility(None).await?,
+            mode: self.mode(None, false).await?,
+            subjects: Vec::new(),
+        };
+
+        let mut subjects: Vec<String> = self.get(&["subjects"]).await?.unwrap_or_default();
+        subjects.sort();
+        for subject in subjects {
+            if let Some(selection) = selection {
+                if !selection.matches(&subject)? {
+                    continue;
+                }
+            }
+            let versions: Vec<i32> = self
+                .get(&["subjects", &subject, "versions"])
+                .await?
+                .unwrap_or_default();
+            let mut exported = SubjectExport {
+                compatibility: self.compatibility(Some(&subject)).await?,
+                mode: self.mode(Some(&subject), false).await?,
+                subject: subject.clone(),
+                versions: Vec::with_capacity(versions.len()),
+            };
+            for version in versions {
+                let path = ["subjects", &subject, "versions", &version.to_string()];
+                if let Some(schema) = self.get(&path).await? {
+                    exported.versions.push(schema);
+                }
+            }
+            debug!(%subject, versions = exported.versions.len(), "Exported subject");
+            export.subjects.push(exported);
+        }
+        Ok(export)
+    }
+
+    /// Register the schema versions of `subject` under `target` in version
+    /// order, then apply its compatibility level and mode. Versions already
+    /// registered are skipped. IDs and version numbers are kept when `target`
+    /// is in IMPORT mode. Returns the number of versions registered.
+    pub async fn import_subject(&self, subject: &SubjectExport, target: &str) -> Result<usize> {
+        let preserve_ids = self.mode(Some(target), true).await?.as_deref() == Some(IMPORT_MODE);
+
+        let mut versions: Vec<&SchemaVersion> = subject.versions.iter().collect();
+        versions.sort_by_key(|v| v.version);
+        let mut registered = 0;
+        for version in versions {
+            let mut body = json!({"schema": version.schema});
+            if let Some(schema_type) = &version.schema_type {
+                body["schemaType"] = schema_type.clone().into();
+            }
+            if !version.references.is_empty() {
+                body["references"] = version.references.clone().into();
+            }
+
+            // Looking a schema up returns 404 until it is registered
+            let existing = self
+                .send(Method::POST, &["subjects", target], Some(&body))
+                .await?;
+            if existing.is_some() {
+                continue;
+            }
+
+            if preserve_ids {
+                body["id"] = version.id.into();
+                body["version"] = version.version.into();
+            }
+            self.send(Method::POST, &["subjects", target, "versions"], Some(&body))
+                .await?
+                .ok_or_else(|| {
+                    Error::SchemaRegistry(format!("subject '{target}' not found on registration"))
+                })?;
+            registered += 1;
+        }
+
+        if let Some(compatibility) = &subject.compatibility {
+            self.send(
+                Method::PUT,
+                &["config", target],
+                Some(&json!({"compatibility": compatibility})),
+            )
+            .await?;
+        }
+        if let Some(mode) = &subject.mode {
+            self.send(Method::PUT, &["mode", target], Some(&json!({"mode": mode})))
+                .await?;
+        }
+        if registered > 0 {
+            info!(subject = %target, versions = registered, preserve_ids, "Imported subject");
+        }
+        Ok(registered)
+    }
+
+    /// Compatibility level of `subject`, or the global level when `None`.
+    /// A subject without its own level returns `None`.
+    async fn compatibility(&self, subject: Option<&str>) -> Result<Option<String>> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Config {
+            compatibility_level: Option<String>,
+        }
+        let path: Vec<&str> = match subject {
+            Some(subject) => vec!["config", subject],
+            None => vec!["config"],
+        };
+        let config: Option<Config> = self
+            .get_with_query(&path, &[("defaultToGlobal", "false")])
+            .await?;
+        Ok(config.and_then(|c| c.compatibility_level))
+    }
+
+    /// Mode of `subject`, or the global mode when `None`. With
+    /// `default_to_global`, a subject without its own mode returns the global
+    /// one.
+    async fn mode(&self, subject: Option<&str>, default_to_global: bool) -> Result<Option<String>> {
+        #[derive(Deserialize)]
+        struct Mode {
+            mode: Option<String>,
+        }
+        let path: Vec<&str> = match subject {
+            Some(subject) => vec!["mode", subject],
+            None => vec!["mode"],
+        };
+        let default_to_global = default_to_global.to_string();
+        let mode: Option<Mode> = self
+            .get_with_query(&path, &[("defaultToGlobal", default_to_global.as_str())])
+            .await?;
+        Ok(mode.and_then(|m| m.mode))
+    }
+
+    async fn get<T: DeserializeOwned>(&self, path: &[&str]) -> Result<Option<T>> {
+        self.get_with_query(path, &[]).await
+    }
+
+    async fn get_with_query<T: DeserializeOwned>(
+        &self,
+        path: &[&str],
+        query: &[(&str, &str)],
+    ) -> Result<Option<T>> {
+        let url = self.endpoint(path);
+        let response = self
+            .request(Method::GET, url.clone())
+            .query(query)
+            .send()
+            .await;
+        match Self::read(url, response).await? {
+            Some(body) => serde_json::from_value(body).map(Some).map_err(|e| {
+                Error::SchemaRegistry(format!("unexpected response from {}: {e}", self.url))
+            }),
+            None => Ok(None),
+        }
+    }
+
+    async fn send(
+        &self,
+        method: Method,
+        path: &[&str],
+        body: Option<&Value>,
+    ) -> Result<Option<Value>> {
+        let url = self.endpoint(path);
+        let mut request = self.request(method, url.clone());
+        if let Some(body) = body {
+            request = request
+                .header(reqwest::header::CONTENT_TYPE, CONTENT_TYPE)
+                .body(body.to_string());
+        }
+        Self::read(url, request.send().await).await
+    }
+
+    fn request(&self, method: Method, url: Url) -> reqwest::RequestBuilder {
+        let request = self
+            .http
+            .request(method, url)
+            .header(reqwest::header::ACCEPT, CONTENT_TYPE);
+        match &self.credentials {
+            Some((username, password)) => request.basic_auth(username, Some(password)),
+            None => request,
+        }
+    }
+
+    /// URL of `path` below the registry URL, with each segment escaped
+    fn endpoint(&self, path: &[&str]) -> Url {
+        let mut url = self.url.clone();
+        if let Ok(mut segments) = url.path_segments_mut() {
+            segments.pop_if_empty().extend(path);
+        }
+        url
+    }
+
+    /// The JSON body of a successful response, or `None` on 404
+    async fn read(url: Url, response: reqwest::Result<reqwest::Response>) -> Result<Option<Value>> {
+        let response =
+            response.map_err(|e| Error::SchemaRegistry(format!("request to {url} failed: {e}")))?;
+        let status = response.status();
+        if status == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let text = response.text().await.map_err(|e| {
+            Error::SchemaRegistry(format!("reading response from {url} failed: {e}"))
+        })?;
+        if !status.is_success() {
+            return Err(Error::SchemaRegistry(format!(
+                "{url} returned {status}: {text}"
+            )));
+        }
+        if text.is_empty() {
+            return Ok(Some(Value::Null));
+        }
+        serde_json::from_str(&text)
+            .map(Some)
+            .map_err(|e| Error::SchemaRegistry(format!("unexpected response from {url}: {e}")))
+    }
+}
+
+/// Subject `subject` after renaming topics by `topic_mapping`: subjects
+/// following the TopicNameStrategy (`<topic>-key`, `<topic>-value`) of a
+/// mapped topic take the target topic's name; others are kept
+pub fn mapped_subject(
+    subject: &str,
+    topic_mapping: &[crate::crd::kafka_restore::TopicMappingEntry],
+) -> String {
+    for suffix in ["-key", "-value"] {
+        if let Some(topic) = subject.strip_suffix(suffix) {
+            if let Some(mapping) = topic_mapping.iter().find(|m| m.source_topic == topic) {
+                return format!("{}{suffix}", mapping.target_topic);
+            }
+        }
+    }
+    subject.to_string()
+}
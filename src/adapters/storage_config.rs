@@ -1,6 +1,6 @@
 use serde_yaml::Value;
 
-use crate::crd::common::{StorageSpec, StorageType};
+use crate::crd::common::{SecretKeyRef, StorageSpec, StorageType};
 use crate::error::{Error, Result};
 
 /// Build the storage section of the kafka-backup config YAML
@@ -237,6 +237,79 @@ pub fn get_storage_credentials_secret(storage: &StorageSpec) -> Option<(String,
     }
 }
 
+/// All Secret references in a StorageSpec, for the configured backend only
+pub fn storage_secret_refs(storage: &StorageSpec) -> Vec<&SecretKeyRef> {
+    let refs = match storage.storage_type {
+        StorageType::S3 => storage
+            .s3
+            .as_ref()
+            .map(|s| {
+                vec![
+                    s.credentials_secret.as_ref(),
+                    s.access_key_secret.as_ref(),
+                    s.secret_key_secret.as_ref(),
+                ]
+            })
+            .unwrap_or_default(),
+        StorageType::Azure => storage
+            .azure
+            .as_ref()
+            .map(|a| {
+                vec![
+                    a.credentials_secret.as_ref(),
+                    a.account_key_secret.as_ref(),
+                    a.sas_token_secret.as_ref(),
+                    a.client_secret_secret.as_ref(),
+                ]
+            })
+            .unwrap_or_default(),
+        StorageType::Gcs => storage
+            .gcs
+            .as_ref()
+            .map(|g| vec![g.credentials_secret.as_ref()])
+            .unwrap_or_default(),
+        StorageType::Filesystem => Vec::new(),
+    };
+    refs.into_iter().flatten().collect()
+}
+
+/// Mutable access to the Secret references in `storage`, in the same order as
+/// [`storage_secret_refs`]
+pub fn storage_secret_refs_mut(storage: &mut StorageSpec) -> Vec<&mut SecretKeyRef> {
+    let refs = match storage.storage_type {
+        StorageType::S3 => storage
+            .s3
+            .as_mut()
+            .map(|s| {
+                vec![
+                    s.credentials_secret.as_mut(),
+                    s.access_key_secret.as_mut(),
+                    s.secret_key_secret.as_mut(),
+                ]
+            })
+            .unwrap_or_default(),
+        StorageType::Azure => storage
+            .azure
+            .as_mut()
+            .map(|a| {
+                vec![
+                    a.credentials_secret.as_mut(),
+                    a.account_key_secret.as_mut(),
+                    a.sas_token_secret.as_mut(),
+                    a.client_secret_secret.as_mut(),
+                ]
+            })
+            .unwrap_or_default(),
+        StorageType::Gcs => storage
+            .gcs
+            .as_mut()
+            .map(|g| vec![g.credentials_secret.as_mut()])
+            .unwrap_or_default(),
+        StorageType::Filesystem => Vec::new(),
+    };
+    refs.into_iter().flatten().collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
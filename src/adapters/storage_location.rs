@@ -0,0 +1,101 @@
+use kube::{Api, Client, ResourceExt};
+
+use crate::crd::common::{StorageLocationKind, StorageLocationRef, StorageSpec};
+use crate::crd::{BackupStorageLocation, ClusterBackupStorageLocation, KafkaBackup};
+use crate::error::{Error, Result};
+
+/// Resolve an inline StorageSpec or a storage location reference to the
+/// StorageSpec to use. Exactly one of `storage` and `storage_ref` must be set.
+pub async fn resolve_storage(
+    client: &Client,
+    namespace: &str,
+    storage: Option<&StorageSpec>,
+    storage_ref: Option<&StorageLocationRef>,
+) -> Result<StorageSpec> {
+    check_storage_source(storage, storage_ref)?;
+    match (storage, storage_ref) {
+        (Some(storage), _) => Ok(storage.clone()),
+        (None, Some(storage_ref)) => resolve_storage_ref(client, namespace, storage_ref).await,
+        (None, None) => unreachable!("checked by check_storage_source"),
+    }
+}
+
+/// Check that exactly one of `storage` and `storage_ref` is set
+pub fn check_storage_source(
+    storage: Option<&StorageSpec>,
+    storage_ref: Option<&StorageLocationRef>,
+) -> Result<()> {
+    match (storage, storage_ref) {
+        (Some(_), Some(_)) => Err(Error::InvalidConfig(
+            "storage and storageRef are mutually exclusive".to_string(),
+        )),
+        (None, None) => Err(Error::InvalidConfig(
+            "one of storage or storageRef must be set".to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Fetch the StorageSpec held by a BackupStorageLocation or ClusterBackupStorageLocation
+pub async fn resolve_storage_ref(
+    client: &Client,
+    namespace: &str,
+    storage_ref: &StorageLocationRef,
+) -> Result<StorageSpec> {
+    let name = &storage_ref.name;
+    let not_found = |kind: &str| {
+        Error::InvalidConfig(format!(
+            "{kind} '{name}' referenced by storageRef not found"
+        ))
+    };
+
+    match storage_ref.kind {
+        Some(StorageLocationKind::ClusterBackupStorageLocation) => {
+            let api: Api<ClusterBackupStorageLocation> = Api::all(client.clone());
+            let location = api
+                .get_opt(name)
+                .await?
+                .ok_or_else(|| not_found("ClusterBackupStorageLocation"))?;
+            Ok(location.spec.storage)
+        }
+        Some(StorageLocationKind::BackupStorageLocation) | None => {
+            let api: Api<BackupStorageLocation> = Api::namespaced(client.clone(), namespace);
+            let location = api
+                .get_opt(name)
+                .await?
+                .ok_or_else(|| not_found("BackupStorageLocation"))?;
+            Ok(location.spec.storage)
+        }
+    }
+}
+
+/// Return a copy of `backup` whose `spec.storage` holds the resolved storage
+/// destination, so config, Job and retention code can read it directly.
+pub async fn with_resolved_storage(client: &Client, backup: &KafkaBackup) -> Result<KafkaBackup> {
+    let namespace = backup
+        .namespace()
+        .ok_or(Error::MissingObjectKey(".metadata.namespace"))?;
+    let storage = resolve_storage(
+        client,
+        &namespace,
+        backup.spec.storage.as_ref(),
+        backup.spec.storage_ref.as_ref(),
+    )
+    .await?;
+
+    let mut resolved = backup.clone();
+    resolved.spec.storage = Some(storage);
+    resolved.spec.storage_ref = None;
+    Ok(resolved)
+}
+
+/// The storage destination of a KafkaBackup whose storage has been resolved
+/// with [`with_resolved_storage`].
+pub fn backup_storage(backup: &KafkaBackup) -> Result<&StorageSpec> {
+    backup.spec.storage.as_ref().ok_or_else(|| {
+        Error::InvalidConfig(format!(
+            "KafkaBackup '{}' has no resolved storage; set spec.storage or spec.storageRef",
+            backup.name_any()
+        ))
+    })
+}
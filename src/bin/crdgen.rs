@@ -2,21 +2,66 @@ use kube::CustomResourceExt;
 use std::fs;
 use std::path::Path;
 
-use kafka_backup_operator::crd::{KafkaBackup, KafkaRestore};
+use kafka_backup_operator::crd::{
+    kafka_backup_crd, kafka_restore_crd, BackupStorageLocation, ClusterBackupStorageLocation,
+    KafkaBackupGrant, KafkaBackupSchedule, KafkaBackupSnapshot, KafkaBackupVerification,
+};
 
 fn main() {
     let crds_dir = Path::new("deploy/crds");
     fs::create_dir_all(crds_dir).expect("Failed to create deploy/crds directory");
 
     let backup_crd =
-        serde_yaml::to_string(&KafkaBackup::crd()).expect("Failed to serialize KafkaBackup CRD");
+        serde_yaml::to_string(&kafka_backup_crd()).expect("Failed to serialize KafkaBackup CRD");
     fs::write(crds_dir.join("kafkabackups.yaml"), backup_crd)
         .expect("Failed to write KafkaBackup CRD");
     println!("Generated deploy/crds/kafkabackups.yaml");
 
     let restore_crd =
-        serde_yaml::to_string(&KafkaRestore::crd()).expect("Failed to serialize KafkaRestore CRD");
+        serde_yaml::to_string(&kafka_restore_crd()).expect("Failed to serialize KafkaRestore CRD");
     fs::write(crds_dir.join("kafkarestores.yaml"), restore_crd)
         .expect("Failed to write KafkaRestore CRD");
     println!("Generated deploy/crds/kafkarestores.yaml");
+
+    let schedule_crd = serde_yaml::to_string(&KafkaBackupSchedule::crd())
+        .expect("Failed to serialize KafkaBackupSchedule CRD");
+    fs::write(crds_dir.join("kafkabackupschedules.yaml"), schedule_crd)
+        .expect("Failed to write KafkaBackupSchedule CRD");
+    println!("Generated deploy/crds/kafkabackupschedules.yaml");
+
+    let snapshot_crd = serde_yaml::to_string(&KafkaBackupSnapshot::crd())
+        .expect("Failed to serialize KafkaBackupSnapshot CRD");
+    fs::write(crds_dir.join("kafkabackupsnapshots.yaml"), snapshot_crd)
+        .expect("Failed to write KafkaBackupSnapshot CRD");
+    println!("Generated deploy/crds/kafkabackupsnapshots.yaml");
+
+    let location_crd = serde_yaml::to_string(&BackupStorageLocation::crd())
+        .expect("Failed to serialize BackupStorageLocation CRD");
+    fs::write(crds_dir.join("backupstoragelocations.yaml"), location_crd)
+        .expect("Failed to write BackupStorageLocation CRD");
+    println!("Generated deploy/crds/backupstoragelocations.yaml");
+
+    let cluster_location_crd = serde_yaml::to_string(&ClusterBackupStorageLocation::crd())
+        .expect("Failed to serialize ClusterBackupStorageLocation CRD");
+    fs::write(
+        crds_dir.join("clusterbackupstoragelocations.yaml"),
+        cluster_location_crd,
+    )
+    .expect("Failed to write ClusterBackupStorageLocation CRD");
+    println!("Generated deploy/crds/clusterbackupstoragelocations.yaml");
+
+    let grant_crd = serde_yaml::to_string(&KafkaBackupGrant::crd())
+        .expect("Failed to serialize KafkaBackupGrant CRD");
+    fs::write(crds_dir.join("kafkabackupgrants.yaml"), grant_crd)
+        .expect("Failed to write KafkaBackupGrant CRD");
+    println!("Generated deploy/crds/kafkabackupgrants.yaml");
+
+    let verification_crd = serde_yaml::to_string(&KafkaBackupVerification::crd())
+        .expect("Failed to serialize KafkaBackupVerification CRD");
+    fs::write(
+        crds_dir.join("kafkabackupverifications.yaml"),
+        verification_crd,
+    )
+    .expect("Failed to write KafkaBackupVerification CRD");
+    println!("Generated deploy/crds/kafkabackupverifications.yaml");
 }
@@ -0,0 +1,557 @@
+//! Operator settings, read from an optional YAML file and overridden by
+//! environment variables and then by command-line flags. The file is polled
+//! for changes; settings only read at startup are kept until a restart.
+
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::{Arc, LazyLock, PoisonError, RwLock};
+use std::time::Duration;
+
+use serde::{Deserialize, Deserializer};
+use tracing::{info, warn};
+
+use crate::controllers::leader::parse_duration;
+use crate::controllers::watch::{WatchNamespaces, WATCH_NAMESPACES_ENV};
+use crate::crd::common::ResourceRequirementsSpec;
+use crate::error::{Error, Result};
+use crate::reconcilers::{
+    DEFAULT_BACKUP_IMAGE, DEFAULT_JOB_SERVICE_ACCOUNT, JOB_SERVICE_ACCOUNT_ENV,
+};
+
+/// Environment variable holding the path of the configuration file, used
+/// when `--config` is not given
+pub const CONFIG_FILE_ENV: &str = "OPERATOR_CONFIG_FILE";
+
+/// How often the configuration file is checked for changes
+pub const RELOAD_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Operator settings
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct OperatorConfig {
+    /// Address of the health and metrics server. Read at startup only.
+    pub metrics_address: SocketAddr,
+    /// Namespaces to watch, all of them when empty. Read at startup only.
+    pub watch_namespaces: Vec<String>,
+    /// Reconciliation settings
+    pub reconcile: ReconcileConfig,
+    /// Defaults of backup and restore Jobs
+    pub jobs: JobConfig,
+}
+
+/// Reconciliation settings
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct ReconcileConfig {
+    /// Delay before a successfully reconciled resource is reconciled again
+    #[serde(deserialize_with = "duration")]
+    pub requeue_interval: Duration,
+    /// Delay before a failed reconciliation is retried
+    #[serde(deserialize_with = "duration")]
+    pub error_requeue_interval: Duration,
+    /// Reconciliations each controller runs at once, 0 for no limit. Read at
+    /// startup only.
+    pub concurrency: u16,
+}
+
+/// Defaults of backup and restore Jobs, used when a resource does not set them
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct JobConfig {
+    /// kafka-backup image
+    pub image: String,
+    /// Service account of Job pods, the namespace's default one when empty
+    pub service_account_name: String,
+    /// Resource requirements of Job containers
+    pub resources: Option<ResourceRequirementsSpec>,
+}
+
+impl Default for OperatorConfig {
+    fn default() -> Self {
+        Self {
+            metrics_address: SocketAddr::from(([0, 0, 0, 0], 9090)),
+            watch_namespaces: Vec::new(),
+            reconcile: ReconcileConfig::default(),
+            jobs: JobConfig::default(),
+        }
+    }
+}
+
+impl Default for ReconcileConfig {
+    fn default() -> Self {
+        Self {
+            requeue_interval: Duration::from_secs(300),
+            error_requeue_interval: Duration::from_secs(30),
+            concurrency: 0,
+        }
+    }
+}
+
+impl ReconcileConfig {
+    /// Settings of the kube-runtime controllers
+    pub fn controller_config(&self) -> kube::runtime::controller::Config {
+        kube::runtime::controller::Config::default().concurrency(self.concurrency)
+    }
+}
+
+impl Default for JobConfig {
+    fn default() -> Self {
+        Self {
+            image: DEFAULT_BACKUP_IMAGE.to_string(),
+            service_account_name: DEFAULT_JOB_SERVICE_ACCOUNT.to_string(),
+            resources: None,
+        }
+    }
+}
+
+/// Durations are written like `5m`, `30s` or `500ms`, or as seconds
+fn duration<'de, D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Duration, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Value {
+        Seconds(u64),
+        Text(String),
+    }
+    match Value::deserialize(deserializer)? {
+        Value::Seconds(seconds) => Ok(Duration::from_secs(seconds)),
+        Value::Text(text) => parse_duration(&text).map_err(serde::de::Error::custom),
+    }
+}
+
+/// A setting that can be overridden by an environment variable and a flag
+struct Setting {
+    flag: &'static str,
+    env: &'static str,
+    help: &'static str,
+    apply: fn(&mut OperatorConfig, &str) -> std::result::Result<(), String>,
+}
+
+const SETTINGS: &[Setting] = &[
+    Setting {
+        flag: "metrics-address",
+        env: "METRICS_ADDRESS",
+        help: "Address of the health and metrics server",
+        apply: |config, value| {
+            config.metrics_address = value
+                .parse()
+                .map_err(|_| format!("invalid socket address '{value}'"))?;
+            Ok(())
+        },
+    },
+    Setting {
+        flag: "watch-namespaces",
+        env: WATCH_NAMESPACES_ENV,
+        help: "Comma-separated namespaces to watch, all when empty",
+        apply: |config, value| {
+            config.watch_namespaces = value
+                .split(',')
+                .map(str::trim)
+                .filter(|ns| !ns.is_empty())
+                .map(str::to_string)
+                .collect();
+            Ok(())
+        },
+    },
+    Setting {
+        flag: "requeue-interval",
+        env: "RECONCILE_REQUEUE_INTERVAL",
+        help: "Delay before a reconciled resource is reconciled again",
+        apply: |config, value| {
+            config.reconcile.requeue_interval = parse_duration(value)?;
+            Ok(())
+        },
+    },
+    Setting {
+        flag: "error-requeue-interval",
+        env: "RECONCILE_ERROR_REQUEUE_INTERVAL",
+        help: "Delay before a failed reconciliation is retried",
+        apply: |config, value| {
+            config.reconcile.error_requeue_interval = parse_duration(value)?;
+            Ok(())
+        },
+    },
+    Setting {
+        flag: "concurrency",
+        env: "RECONCILE_CONCURRENCY",
+        help: "Reconciliations each controller runs at once, 0 for no limit",
+        apply: |config, value| {
+            config.reconcile.concurrency = value
+                .trim()
+                .parse()
+                .map_err(|_| format!("invalid concurrency '{value}'"))?;
+            Ok(())
+        },
+    },
+    Setting {
+        flag: "job-image",
+        env: "BACKUP_JOB_IMAGE",
+        help: "Default kafka-backup image of backup and restore Jobs",
+        apply: |config, value| {
+            config.jobs.image = value.trim().to_string();
+            Ok(())
+        },
+    },
+    Setting {
+        flag: "job-service-account",
+        env: JOB_SERVICE_ACCOUNT_ENV,
+        help: "Service account of Job pods, the namespace's default when empty",
+        apply: |config, value| {
+            config.jobs.service_account_name = value.trim().to_string();
+            Ok(())
+        },
+    },
+];
+
+/// Parsed command line
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Cli {
+    /// Path given with `--config`
+    pub config_file: Option<PathBuf>,
+    /// Print the usage and exit
+    pub help: bool,
+    /// Print the version and exit
+    pub version: bool,
+    /// Settings overridden by flags, as indexes into [`SETTINGS`] and values
+    overrides: Vec<(usize, String)>,
+}
+
+impl Cli {
+    /// Parse the arguments following the program name. Flags take their value
+    /// either as `--flag=value` or as the next argument.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self> {
+        let mut cli = Self::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let Some(flag) = arg.strip_prefix("--") else {
+                return Err(Error::InvalidConfig(format!("unexpected argument '{arg}'")));
+            };
+            let (flag, inline) = match flag.split_once('=') {
+                Some((flag, value)) => (flag, Some(value.to_string())),
+                None => (flag, None),
+            };
+            match flag {
+                "help" => cli.help = true,
+                "version" => cli.version = true,
+                _ => {
+                    let value = inline
+                        .or_else(|| args.next())
+                        .ok_or_else(|| Error::InvalidConfig(format!("--{flag} needs a value")))?;
+                    if flag == "config" {
+                        cli.config_file = Some(value.into());
+                    } else if let Some(i) = SETTINGS.iter().position(|s| s.flag == flag) {
+                        cli.overrides.push((i, value));
+                    } else {
+                        return Err(Error::InvalidConfig(format!("unknown flag --{flag}")));
+                    }
+                }
+            }
+        }
+        Ok(cli)
+    }
+
+    /// The configuration file, from `--config` or [`CONFIG_FILE_ENV`]
+    pub fn config_path(&self) -> Option<PathBuf> {
+        self.config_file.clone().or_else(|| {
+            std::env::var(CONFIG_FILE_ENV)
+                .ok()
+                .filter(|path| !path.is_empty())
+                .map(PathBuf::from)
+        })
+    }
+}
+
+/// Command-line usage
+pub fn usage() -> String {
+    let mut usage = format!(
+        "Usage: kafka-backup-operator [OPTIONS]\n\nOptions:\n  --config <PATH>\n      YAML configuration file [env: {CONFIG_FILE_ENV}]\n"
+    );
+    for setting in SETTINGS {
+        usage.push_str(&format!(
+            "  --{} <VALUE>\n      {} [env: {}]\n",
+            setting.flag, setting.help, setting.env
+        ));
+    }
+    usage.push_str("  --help\n      Print this help\n  --version\n      Print the version\n");
+    usage
+}
+
+impl OperatorConfig {
+    /// Read the configuration file named by `cli`, apply the environment and
+    /// flag overrides and validate the result
+    pub fn load(cli: &Cli) -> Result<Self> {
+        let mut config = match cli.config_path() {
+            Some(path) => {
+                let contents = std::fs::read_to_string(&path).map_err(|e| {
+                    Error::InvalidConfig(format!("reading {}: {e}", path.display()))
+                })?;
+                Self::from_yaml(&contents)
+                    .map_err(|e| Error::InvalidConfig(format!("{}: {e}", path.display())))?
+            }
+            None => Self::default(),
+        };
+        for setting in SETTINGS {
+            if let Ok(value) = std::env::var(setting.env) {
+                (setting.apply)(&mut config, &value)
+                    .map_err(|e| Error::InvalidConfig(format!("{}: {e}", setting.env)))?;
+            }
+        }
+        for (i, value) in &cli.overrides {
+            let setting = &SETTINGS[*i];
+            (setting.apply)(&mut config, value)
+                .map_err(|e| Error::InvalidConfig(format!("--{}: {e}", setting.flag)))?;
+        }
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Parse a configuration file. An empty file holds the defaults.
+    pub fn from_yaml(contents: &str) -> std::result::Result<Self, String> {
+        if contents.trim().is_empty() {
+            return Ok(Self::default());
+        }
+        serde_yaml::from_str(contents).map_err(|e| e.to_string())
+    }
+
+    /// Reject settings the controllers cannot run with
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |message: String| Err(Error::InvalidConfig(message));
+        if self.reconcile.requeue_interval.is_zero() {
+            return invalid("reconcile.requeueInterval must be positive".to_string());
+        }
+        if self.reconcile.error_requeue_interval.is_zero() {
+            return invalid("reconcile.errorRequeueInterval must be positive".to_string());
+        }
+        if self.jobs.image.trim().is_empty() {
+            return invalid("jobs.image must not be empty".to_string());
+        }
+        if let Some(ns) = self
+            .watch_namespaces
+            .iter()
+            .find(|ns| *ns != "*" && !is_namespace_name(ns))
+        {
+            return invalid(format!("watchNamespaces: '{ns}' is not a namespace name"));
+        }
+        if let Some(resources) = &self.jobs.resources {
+            let quantities = resources.requests.iter().chain(&resources.limits);
+            if let Some((name, _)) = quantities.into_iter().find(|(_, q)| q.trim().is_empty()) {
+                return invalid(format!("jobs.resources: '{name}' has no quantity"));
+            }
+        }
+        Ok(())
+    }
+
+    /// Namespaces the controllers watch
+    pub fn watch_namespaces(&self) -> WatchNamespaces {
+        WatchNamespaces::parse(&self.watch_namespaces.join(","))
+    }
+
+    /// Service account of Job pods, `None` for the namespace's default one
+    pub fn job_service_account(&self) -> Option<String> {
+        let name = self.jobs.service_account_name.trim();
+        (!name.is_empty()).then(|| name.to_string())
+    }
+
+    /// `self` with the settings only read at startup taken from `running`,
+    /// warning about each one that changed
+    fn keeping_startup_settings(mut self, running: &Self) -> Self {
+        if self.metrics_address != running.metrics_address {
+            warn!(
+                setting = "metricsAddress",
+                "Changing this setting needs an operator restart"
+            );
+            self.metrics_address = running.metrics_address;
+        }
+        if self.watch_namespaces() != running.watch_namespaces() {
+            warn!(
+                setting = "watchNamespaces",
+                "Changing this setting needs an operator restart"
+            );
+            self.watch_namespaces.clone_from(&running.watch_namespaces);
+        }
+        if self.reconcile.concurrency != running.reconcile.concurrency {
+            warn!(
+                setting = "reconcile.concurrency",
+                "Changing this setting needs an operator restart"
+            );
+            self.reconcile.concurrency = running.reconcile.concurrency;
+        }
+        self
+    }
+}
+
+/// Kubernetes namespace names are DNS labels
+fn is_namespace_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= 63
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        && !name.starts_with('-')
+        && !name.ends_with('-')
+}
+
+static CURRENT: RwLock<Option<Arc<OperatorConfig>>> = RwLock::new(None);
+static DEFAULT: LazyLock<Arc<OperatorConfig>> = LazyLock::new(Arc::default);
+
+/// The running configuration, the defaults until one is installed
+pub fn current() -> Arc<OperatorConfig> {
+    CURRENT
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+        .unwrap_or_else(|| Arc::clone(&DEFAULT))
+}
+
+/// Make `config` the running configuration
+pub fn install(config: OperatorConfig) {
+    *CURRENT.write().unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(config));
+}
+
+/// Reload the configuration whenever the file named by `cli` changes. An
+/// invalid file is logged and the running configuration kept.
+pub async fn watch(cli: Cli) {
+    let Some(path) = cli.config_path() else {
+        return std::future::pending().await;
+    };
+    let mut last = tokio::fs::read_to_string(&path).await.ok();
+    let mut interval = tokio::time::interval(RELOAD_INTERVAL);
+    interval.tick().await;
+    loop {
+        interval.tick().await;
+        let contents = tokio::fs::read_to_string(&path).await.ok();
+        if contents == last {
+            continue;
+        }
+        last = contents;
+        match OperatorConfig::load(&cli) {
+            Ok(config) => {
+                let running = current();
+                let config = config.keeping_startup_settings(&running);
+                if config != *running {
+                    info!(path = %path.display(), "Reloaded operator configuration");
+                    install(config);
+                }
+            }
+            Err(e) => {
+                warn!(error = %e, "Ignoring invalid operator configuration, keeping the running one");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn file_settings_fill_in_the_defaults() {
+        let config = OperatorConfig::from_yaml(
+            r#"
+metricsAddress: 127.0.0.1:8080
+reconcile:
+  requeueInterval: 10m
+  concurrency: 4
+jobs:
+  resources:
+    requests:
+      cpu: 500m
+"#,
+        )
+        .unwrap();
+        assert_eq!(config.metrics_address.to_string(), "127.0.0.1:8080");
+        assert_eq!(config.reconcile.requeue_interval, Duration::from_secs(600));
+        assert_eq!(
+            config.reconcile.error_requeue_interval,
+            Duration::from_secs(30)
+        );
+        assert_eq!(config.reconcile.concurrency, 4);
+        assert_eq!(config.jobs.image, DEFAULT_BACKUP_IMAGE);
+        assert_eq!(config.jobs.resources.unwrap().requests["cpu"], "500m");
+        assert_eq!(
+            OperatorConfig::from_yaml("").unwrap(),
+            OperatorConfig::default()
+        );
+    }
+
+    #[test]
+    fn unknown_file_settings_are_rejected() {
+        let error = OperatorConfig::from_yaml("reconcile:\n  requeue: 5m\n").unwrap_err();
+        assert!(error.contains("unknown field `requeue`"), "{error}");
+    }
+
+    #[test]
+    fn flags_override_the_file() {
+        let cli = Cli::parse(args(&[
+            "--concurrency=2",
+            "--job-image",
+            "registry.example.com/kafka-backup:v1",
+            "--watch-namespaces",
+            "kafka, kafka-staging",
+        ]))
+        .unwrap();
+        assert_eq!(cli.config_file, None);
+
+        let mut config = OperatorConfig::default();
+        for (i, value) in &cli.overrides {
+            (SETTINGS[*i].apply)(&mut config, value).unwrap();
+        }
+        assert_eq!(config.reconcile.concurrency, 2);
+        assert_eq!(config.jobs.image, "registry.example.com/kafka-backup:v1");
+        assert_eq!(
+            config.watch_namespaces(),
+            WatchNamespaces::Only(vec!["kafka".to_string(), "kafka-staging".to_string()])
+        );
+    }
+
+    #[test]
+    fn invalid_command_lines_are_rejected() {
+        assert!(Cli::parse(args(&["--requeue"])).is_err());
+        assert!(Cli::parse(args(&["--concurrency"])).is_err());
+        assert!(Cli::parse(args(&["serve"])).is_err());
+        assert!(Cli::parse(args(&["--help"])).unwrap().help);
+        assert!(usage().contains("--error-requeue-interval <VALUE>"));
+    }
+
+    #[test]
+    fn validation_rejects_unusable_settings() {
+        let mut config = OperatorConfig::default();
+        assert!(config.validate().is_ok());
+        assert_eq!(
+            config.job_service_account().as_deref(),
+            Some(DEFAULT_JOB_SERVICE_ACCOUNT)
+        );
+
+        config.reconcile.error_requeue_interval = Duration::ZERO;
+        assert!(config.validate().is_err());
+
+        let config = OperatorConfig {
+            watch_namespaces: vec!["Kafka_Prod".to_string()],
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+
+        let mut config = OperatorConfig::default();
+        config.jobs.service_account_name = " ".to_string();
+        assert_eq!(config.job_service_account(), None);
+    }
+
+    #[test]
+    fn reloads_keep_startup_settings() {
+        let running = OperatorConfig::default();
+        let mut next = OperatorConfig {
+            metrics_address: "127.0.0.1:9191".parse().unwrap(),
+            ..Default::default()
+        };
+        next.reconcile.concurrency = 8;
+        next.reconcile.requeue_interval = Duration::from_secs(60);
+
+        let next = next.keeping_startup_settings(&running);
+        assert_eq!(next.metrics_address, running.metrics_address);
+        assert_eq!(next.reconcile.concurrency, 0);
+        assert_eq!(next.reconcile.requeue_interval, Duration::from_secs(60));
+    }
+}
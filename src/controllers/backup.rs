@@ -2,24 +2,28 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use futures::StreamExt;
-use k8s_openapi::api::batch::v1::Job;
+use k8s_openapi::api::batch::v1::{CronJob, Job};
 use kube::{
     runtime::{
         controller::{Action, Controller},
         watcher::Config,
     },
-    Api, Client, ResourceExt,
+    Client, ResourceExt,
 };
-use tokio::time::Duration;
 use tracing::{error, info, instrument};
 
+use super::health::{ControllerHealth, ControllerProbe};
+use super::watch::{scoped_api, WatchNamespaces};
+use crate::config;
 use crate::crd::KafkaBackup;
 use crate::metrics::prometheus::MetricsState;
 use crate::reconcilers::backup::reconcile_backup;
+use crate::status::events;
 
 struct Context {
     client: Client,
     metrics: Arc<MetricsState>,
+    probe: ControllerProbe,
 }
 
 #[instrument(skip(ctx))]
@@ -32,12 +36,16 @@ async fn reconcile(
     info!(%name, %namespace, "Reconciling KafkaBackup");
 
     let started = Instant::now();
-    let result = reconcile_backup(backup, ctx.client.clone(), &ctx.metrics).await;
+    let _active = ctx.probe.reconciling();
+    let recorder = events::recorder(ctx.client.clone(), backup.as_ref());
+    let result = reconcile_backup(backup, ctx.client.clone(), &recorder, &ctx.metrics).await;
     ctx.metrics
         .record_reconciliation("backup", result.is_ok(), started.elapsed());
     result?;
 
-    Ok(Action::requeue(Duration::from_secs(300)))
+    Ok(Action::requeue(
+        config::current().reconcile.requeue_interval,
+    ))
 }
 
 fn error_policy(
@@ -47,36 +55,57 @@ fn error_policy(
 ) -> Action {
     let name = backup.name_any();
     error!(%name, %error, "Reconciliation error for KafkaBackup");
-    Action::requeue(Duration::from_secs(30))
+    Action::requeue(config::current().reconcile.error_requeue_interval)
 }
 
-pub async fn run(client: Client, metrics: Arc<MetricsState>) {
-    let backups = Api::<KafkaBackup>::all(client.clone());
-    let jobs = Api::<Job>::all(client.clone());
-
+pub async fn run(
+    client: Client,
+    metrics: Arc<MetricsState>,
+    health: Arc<ControllerHealth>,
+    namespaces: WatchNamespaces,
+) {
+    let probe = health.controller("KafkaBackup");
     let context = Arc::new(Context {
         client: client.clone(),
         metrics,
+        probe: probe.clone(),
     });
 
-    info!("Starting KafkaBackup controller");
+    info!(%namespaces, "Starting KafkaBackup controller");
 
-    Controller::new(backups, Config::default().any_semantic())
+    let controllers = namespaces.scopes().into_iter().map(|namespace| {
+        let controller = Controller::new(
+            scoped_api::<KafkaBackup>(&client, namespace),
+            Config::default().any_semantic(),
+        )
         // Watch owned Jobs so completion/failure updates the KafkaBackup
         // status immediately instead of waiting for the periodic requeue.
         .owns(
-            jobs,
+            scoped_api::<Job>(&client, namespace),
             Config::default().labels("kafkabackup.com/type=backup"),
         )
-        .shutdown_on_signal()
-        .run(reconcile, error_policy, context)
-        .for_each(|res| async move {
-            match res {
-                Ok(o) => info!("Reconciled KafkaBackup: {:?}", o),
-                Err(e) => error!("Reconcile failed: {:?}", e),
-            }
-        })
-        .await;
+        // Watch the owned CronJob so each scheduled run refreshes
+        // lastScheduleTime and nextScheduledBackup
+        .owns(
+            scoped_api::<CronJob>(&client, namespace),
+            Config::default().labels("kafkabackup.com/type=backup"),
+        );
+        let synced = probe.watch(namespace, controller.store());
+        let run = controller
+            .with_config(config::current().reconcile.controller_config())
+            .shutdown_on_signal()
+            .run(reconcile, error_policy, Arc::clone(&context))
+            .inspect(|res| probe.record(res))
+            .for_each(|res| async move {
+                match res {
+                    Ok(o) => info!("Reconciled KafkaBackup: {:?}", o),
+                    Err(e) => error!("Reconcile failed: {:?}", e),
+                }
+            });
+        futures::future::join(synced, run)
+    });
+    futures::future::join_all(controllers).await;
+    probe.stopped();
 
     info!("KafkaBackup controller shut down");
 }
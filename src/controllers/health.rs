@@ -0,0 +1,527 @@
+//! Health of the controllers, behind the liveness and readiness probes and
+//! the `/debug/controllers` endpoint. Each controller reports the initial
+//! sync of its watches, its watch errors and the reconciliations it runs.
+
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::hash::Hash;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use axum::{http::StatusCode, routing::get, Json, Router};
+use chrono::{DateTime, Utc};
+use kube::runtime::controller;
+use kube::runtime::reflector::{Lookup, Store};
+use kube::Client;
+use serde::Serialize;
+use tracing::{info, warn};
+
+/// A reconciliation running for longer than this is stuck, which fails the
+/// liveness probe
+pub const STALL_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// How long the API server check waits for a response
+pub const API_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How often the API server is checked. Probes read the last result, so
+/// they never wait on the API server themselves.
+pub const API_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+/// API group of the Strimzi resources the operator reads
+pub const STRIMZI_API_GROUP: &str = "kafka.strimzi.io";
+
+/// Health of the controllers this replica runs
+#[derive(Default)]
+pub struct ControllerHealth {
+    leading: AtomicBool,
+    serving_webhooks: AtomicBool,
+    next_reconcile: AtomicU64,
+    controllers: Mutex<BTreeMap<&'static str, ControllerState>>,
+    /// Last result of [`strimzi_served`], `None` until the first check
+    api: Mutex<Option<Result<bool, String>>>,
+}
+
+#[derive(Default)]
+struct ControllerState {
+    running: bool,
+    watches: Vec<WatchState>,
+    active: BTreeMap<u64, Instant>,
+    last_success: Option<DateTime<Utc>>,
+    reconcile_errors: u64,
+    watch_errors: u64,
+    last_watch_error: Option<String>,
+}
+
+struct WatchState {
+    namespace: Option<String>,
+    synced: bool,
+    objects: Box<dyn Fn() -> usize + Send>,
+}
+
+/// State of one controller, as served by `/debug/controllers`
+#[derive(Clone, Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ControllerStatus {
+    /// Kind the controller reconciles
+    pub controller: String,
+    /// Whether the controller is still running
+    pub running: bool,
+    /// Whether every watch completed its initial sync
+    pub synced: bool,
+    /// Watches of the controller, one per watched namespace
+    pub watches: Vec<WatchStatus>,
+    /// Reconciliations in progress. kube-runtime does not expose the
+    /// objects waiting in its scheduler, so this is the depth of the queue
+    /// being worked on.
+    pub active_reconciles: usize,
+    /// How long the oldest reconciliation in progress has been running
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oldest_active_reconcile_seconds: Option<u64>,
+    /// When a reconciliation last succeeded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_successful_reconcile: Option<DateTime<Utc>>,
+    /// Failed reconciliations
+    pub reconcile_errors: u64,
+    /// Errors of the watches, which are retried with a backoff
+    pub watch_errors: u64,
+    /// The last watch error
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_watch_error: Option<String>,
+}
+
+/// State of one watch of a controller
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchStatus {
+    /// Watched namespace, `None` for all of them
+    pub namespace: Option<String>,
+    /// Whether the initial list completed
+    pub synced: bool,
+    /// Objects in the watch's cache
+    pub objects: usize,
+}
+
+impl ControllerHealth {
+    /// Report the controllers of this replica until the returned guard is
+    /// dropped, which forgets them. Replicas that are not leading only
+    /// serve webhooks and metrics, so they are ready and live without any.
+    pub fn leading(self: &Arc<Self>) -> Leading {
+        self.leading.store(true, Ordering::SeqCst);
+        Leading(Arc::clone(self))
+    }
+
+    /// Start reporting the controller of `kind`
+    pub fn controller(self: &Arc<Self>, kind: &'static str) -> ControllerProbe {
+        self.lock().insert(
+            kind,
+            ControllerState {
+                running: true,
+                ..Default::default()
+            },
+        );
+        ControllerProbe {
+            health: Arc::clone(self),
+            kind,
+        }
+    }
+
+    /// Report that this replica serves the admission and conversion
+    /// webhooks. The Service in front of them follows readiness, so a
+    /// missing Strimzi API group no longer fails it.
+    pub fn serving_webhooks(&self) {
+        self.serving_webhooks.store(true, Ordering::SeqCst);
+    }
+
+    /// Record the result of an API server check, logging when it changes
+    pub fn record_api_check(&self, result: Result<bool, String>) {
+        let mut api = self.api.lock().unwrap_or_else(PoisonError::into_inner);
+        if api.as_ref() != Some(&result) {
+            match &result {
+                Ok(true) => info!("API server reachable"),
+                Ok(false) => warn!("{}", strimzi_missing()),
+                Err(e) => warn!(error = %e, "API server check failed"),
+            }
+        }
+        *api = Some(result);
+    }
+
+    /// Check the API server every [`API_CHECK_INTERVAL`], for the probes
+    pub async fn watch_api(&self, client: Client) {
+        loop {
+            self.record_api_check(strimzi_served(&client).await);
+            tokio::time::sleep(API_CHECK_INTERVAL).await;
+        }
+    }
+
+    /// Ready once the API server answered the last check and, on the leader,
+    /// every watch has completed its initial sync
+    pub fn readiness(&self) -> Result<(), String> {
+        let api = self
+            .api
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        match api {
+            None => return Err("API server not checked yet".to_string()),
+            Some(Err(e)) => return Err(e),
+            Some(Ok(false)) if !self.serving_webhooks.load(Ordering::SeqCst) => {
+                return Err(strimzi_missing());
+            }
+            Some(Ok(_)) => {}
+        }
+        if !self.leading.load(Ordering::SeqCst) {
+            return Ok(());
+        }
+        let controllers = self.lock();
+        if controllers.is_empty() {
+            return Err("controllers have not started".to_string());
+        }
+        let unsynced: Vec<String> =
+            controllers
+                .iter()
+                .flat_map(|(kind, state)| {
+                    state.watches.iter().filter(|watch| !watch.synced).map(
+                        move |watch| match &watch.namespace {
+                            Some(namespace) => format!("{kind} in {namespace}"),
+                            None => kind.to_string(),
+                        },
+                    )
+                })
+                .collect();
+        if unsynced.is_empty() {
+            Ok(())
+        } else {
+            Err(format!(
+                "waiting for the initial sync of {}",
+                unsynced.join(", ")
+            ))
+        }
+    }
+
+    /// Live unless a controller stopped or a reconciliation is stuck
+    pub fn liveness(&self) -> Result<(), String> {
+        self.liveness_at(Instant::now())
+    }
+
+    fn liveness_at(&self, now: Instant) -> Result<(), String> {
+        if !self.leading.load(Ordering::SeqCst) {
+            return Ok(());
+        }
+        for (kind, state) in self.lock().iter() {
+            if !state.running {
+                return Err(format!("{kind} controller stopped"));
+            }
+            if let Some(started) = state.active.values().min() {
+                let running = now.saturating_duration_since(*started);
+                if running > STALL_TIMEOUT {
+                    return Err(format!(
+                        "{kind} reconciliation running for {}s",
+                        running.as_secs()
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// State of every controller
+    pub fn status(&self) -> Vec<ControllerStatus> {
+        let now = Instant::now();
+        self.lock()
+            .iter()
+            .map(|(kind, state)| {
+                let watches: Vec<WatchStatus> = state
+                    .watches
+                    .iter()
+                    .map(|watch| WatchStatus {
+                        namespace: watch.namespace.clone(),
+                        synced: watch.synced,
+                        objects: (watch.objects)(),
+                    })
+                    .collect();
+                ControllerStatus {
+                    controller: kind.to_string(),
+                    running: state.running,
+                    synced: watches.iter().all(|watch| watch.synced),
+                    watches,
+                    active_reconciles: state.active.len(),
+                    oldest_active_reconcile_seconds: state
+                        .active
+                        .values()
+                        .min()
+                        .map(|started| now.saturating_duration_since(*started).as_secs()),
+                    last_successful_reconcile: state.last_success,
+                    reconcile_errors: state.reconcile_errors,
+                    watch_errors: state.watch_errors,
+                    last_watch_error: state.last_watch_error.clone(),
+                }
+            })
+            .collect()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BTreeMap<&'static str, ControllerState>> {
+        self.controllers
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn update(&self, kind: &str, f: impl FnOnce(&mut ControllerState)) {
+        if let Some(state) = self.lock().get_mut(kind) {
+            f(state);
+        }
+    }
+}
+
+/// Guard returned by [`ControllerHealth::leading`]
+pub struct Leading(Arc<ControllerHealth>);
+
+impl Drop for Leading {
+    fn drop(&mut self) {
+        self.0.leading.store(false, Ordering::SeqCst);
+        self.0.lock().clear();
+    }
+}
+
+/// Handle a controller reports its health through
+#[derive(Clone)]
+pub struct ControllerProbe {
+    health: Arc<ControllerHealth>,
+    kind: &'static str,
+}
+
+impl ControllerProbe {
+    /// Report the watch behind `store`. The returned future marks it synced
+    /// once its initial list completes, and runs alongside the controller.
+    pub fn watch<K>(
+        &self,
+        namespace: Option<&str>,
+        store: Store<K>,
+    ) -> impl Future<Output = ()> + Send + 'static
+    where
+        K: Lookup + Clone + Send + Sync + 'static,
+        K::DynamicType: Eq + Hash + Clone + Send + Sync,
+    {
+        let mut index = None;
+        let objects = store.clone();
+        self.health.update(self.kind, |state| {
+            index = Some(state.watches.len());
+            state.watches.push(WatchState {
+                namespace: namespace.map(str::to_string),
+                synced: false,
+                objects: Box::new(move || objects.len()),
+            });
+        });
+        let probe = self.clone();
+        async move {
+            if store.wait_until_ready().await.is_ok() {
+                probe.health.update(probe.kind, |state| {
+                    if let Some(watch) = index.and_then(|i| state.watches.get_mut(i)) {
+                        watch.synced = true;
+                    }
+                });
+            }
+        }
+    }
+
+    /// Report a reconciliation as running until the returned guard is dropped
+    pub fn reconciling(&self) -> ActiveReconcile {
+        let id = self.health.next_reconcile.fetch_add(1, Ordering::Relaxed);
+        self.health.update(self.kind, |state| {
+            state.active.insert(id, Instant::now());
+        });
+        ActiveReconcile {
+            probe: self.clone(),
+            id,
+        }
+    }
+
+    /// Record an item of the controller's output stream
+    pub fn record<T, E, Q>(&self, result: &Result<T, controller::Error<E, Q>>)
+    where
+        Q: std::fmt::Display,
+    {
+        self.health.update(self.kind, |state| match result {
+            Ok(_) => state.last_success = Some(Utc::now()),
+            Err(controller::Error::QueueError(e)) => {
+                state.watch_errors += 1;
+                state.last_watch_error = Some(e.to_string());
+            }
+            Err(_) => state.reconcile_errors += 1,
+        });
+    }
+
+    /// Report that the controller stopped
+    pub fn stopped(&self) {
+        self.health.update(self.kind, |state| state.running = false);
+    }
+}
+
+/// Guard returned by [`ControllerProbe::reconciling`]
+pub struct ActiveReconcile {
+    probe: ControllerProbe,
+    id: u64,
+}
+
+impl Drop for ActiveReconcile {
+    fn drop(&mut self) {
+        self.probe.health.update(self.probe.kind, |state| {
+            state.active.remove(&self.id);
+        });
+    }
+}
+
+/// Check that the API server is reachable and serves the Strimzi API group
+pub async fn check_api(client: &Client) -> Result<(), String> {
+    if strimzi_served(client).await? {
+        Ok(())
+    } else {
+        Err(strimzi_missing())
+    }
+}
+
+/// Whether the API server serves the Strimzi API group, or why it could not
+/// be asked
+async fn strimzi_served(client: &Client) -> Result<bool, String> {
+    let groups = tokio::time::timeout(API_CHECK_TIMEOUT, client.list_api_groups())
+        .await
+        .map_err(|_| "API server did not respond".to_string())?
+        .map_err(|e| format!("API server unreachable: {e}"))?;
+    Ok(groups.groups.iter().any(|g| g.name == STRIMZI_API_GROUP))
+}
+
+fn strimzi_missing() -> String {
+    format!("API group {STRIMZI_API_GROUP} is not served, are the Strimzi CRDs installed?")
+}
+
+/// Router serving `/healthz`, `/readyz` and `/debug/controllers`
+pub fn router(health: Arc<ControllerHealth>) -> Router {
+    let live = Arc::clone(&health);
+    let ready = Arc::clone(&health);
+    Router::new()
+        .route(
+            "/healthz",
+            get(move || async move { probe_response(live.liveness()) }),
+        )
+        .route(
+            "/readyz",
+            get(move || async move { probe_response(ready.readiness()) }),
+        )
+        .route(
+            "/debug/controllers",
+            get(move || async move { Json(health.status()) }),
+        )
+}
+
+fn probe_response(result: Result<(), String>) -> (StatusCode, String) {
+    match result {
+        Ok(()) => (StatusCode::OK, "ok".to_string()),
+        Err(e) => (StatusCode::SERVICE_UNAVAILABLE, e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::ConfigMap;
+    use kube::runtime::{reflector, watcher};
+
+    #[test]
+    fn standby_replicas_are_ready_and_live() {
+        let health = Arc::new(ControllerHealth::default());
+        health.record_api_check(Ok(true));
+        assert_eq!(health.readiness(), Ok(()));
+        assert_eq!(health.liveness(), Ok(()));
+
+        let leading = health.leading();
+        assert!(health.readiness().is_err());
+        health.controller("KafkaBackup").stopped();
+        assert!(health.liveness().is_err());
+
+        // Losing the lead forgets the stopped controllers
+        drop(leading);
+        assert_eq!(health.liveness(), Ok(()));
+        assert!(health.status().is_empty());
+    }
+
+    #[test]
+    fn readiness_follows_the_last_api_check() {
+        let health = Arc::new(ControllerHealth::default());
+        assert!(health.readiness().is_err());
+
+        health.record_api_check(Err("API server did not respond".to_string()));
+        assert_eq!(
+            health.readiness(),
+            Err("API server did not respond".to_string())
+        );
+
+        health.record_api_check(Ok(false));
+        assert!(health.readiness().unwrap_err().contains(STRIMZI_API_GROUP));
+
+        // The webhooks do not need Strimzi, so a replica serving them stays
+        // in rotation
+        health.serving_webhooks();
+        assert_eq!(health.readiness(), Ok(()));
+    }
+
+    #[tokio::test]
+    async fn ready_once_every_watch_has_synced() {
+        let health = Arc::new(ControllerHealth::default());
+        health.record_api_check(Ok(true));
+        let _leading = health.leading();
+        let probe = health.controller("KafkaBackup");
+        let (store, mut writer) = reflector::store::<ConfigMap>();
+        let synced = probe.watch(Some("kafka"), store);
+        assert_eq!(
+            health.readiness(),
+            Err("waiting for the initial sync of KafkaBackup in kafka".to_string())
+        );
+
+        writer.apply_watcher_event(&watcher::Event::InitDone);
+        synced.await;
+        assert_eq!(health.readiness(), Ok(()));
+        assert!(health.status()[0].synced);
+    }
+
+    #[test]
+    fn reconciliations_running_too_long_fail_liveness() {
+        let health = Arc::new(ControllerHealth::default());
+        let _leading = health.leading();
+        let probe = health.controller("KafkaRestore");
+
+        let active = probe.reconciling();
+        assert_eq!(health.liveness(), Ok(()));
+        let later = Instant::now() + STALL_TIMEOUT + Duration::from_secs(1);
+        assert!(health
+            .liveness_at(later)
+            .unwrap_err()
+            .starts_with("KafkaRestore reconciliation running for"));
+        assert_eq!(health.status()[0].active_reconciles, 1);
+
+        drop(active);
+        assert_eq!(health.liveness_at(later), Ok(()));
+        assert_eq!(health.status()[0].active_reconciles, 0);
+    }
+
+    #[test]
+    fn outcomes_are_counted_per_controller() {
+        let health = Arc::new(ControllerHealth::default());
+        let probe = health.controller("KafkaBackup");
+
+        type Outcome = Result<(), controller::Error<std::fmt::Error, std::fmt::Error>>;
+        probe.record::<(), _, _>(&Outcome::Ok(()));
+        probe.record::<(), _, _>(&Outcome::Err(controller::Error::QueueError(
+            std::fmt::Error,
+        )));
+        probe.record::<(), _, _>(&Outcome::Err(controller::Error::QueueError(
+            std::fmt::Error,
+        )));
+
+        let status = &health.status()[0];
+        assert_eq!(status.controller, "KafkaBackup");
+        assert!(status.last_successful_reconcile.is_some());
+        assert_eq!(status.watch_errors, 2);
+        assert_eq!(status.reconcile_errors, 0);
+        assert!(status.last_watch_error.is_some());
+    }
+}
@@ -0,0 +1,489 @@
+//! Lease-based leader election, so that only one operator replica runs the
+//! controllers. Standby replicas poll the Lease and take it over once its
+//! holder stops renewing it or releases it on shutdown.
+
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use k8s_openapi::api::coordination::v1::{Lease, LeaseSpec};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{MicroTime, ObjectMeta};
+use kube::api::PostParams;
+use kube::{Api, Client};
+use tracing::{info, warn};
+
+use crate::error::{Error, Result};
+use crate::metrics::prometheus::MetricsState;
+use crate::status::events::POD_NAME_ENV;
+
+/// Environment variables configuring leader election
+pub const ENABLED_ENV: &str = "LEADER_ELECTION_ENABLED";
+pub const LEASE_NAME_ENV: &str = "LEADER_ELECTION_LEASE_NAME";
+pub const LEASE_DURATION_ENV: &str = "LEADER_ELECTION_LEASE_DURATION";
+pub const RENEW_DEADLINE_ENV: &str = "LEADER_ELECTION_RENEW_DEADLINE";
+pub const RETRY_PERIOD_ENV: &str = "LEADER_ELECTION_RETRY_PERIOD";
+
+/// Lease name used when [`LEASE_NAME_ENV`] is unset
+pub const DEFAULT_LEASE_NAME: &str = "strimzi-backup-operator";
+
+/// Leader election settings
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LeaderElectionConfig {
+    /// Name of the Lease
+    pub lease_name: String,
+    /// Namespace of the Lease, the operator's own
+    pub namespace: String,
+    /// Holder identity of this replica, its pod name
+    pub identity: String,
+    /// How long a Lease that is not renewed stays held
+    pub lease_duration: Duration,
+    /// How long the leader keeps leading without a successful renewal
+    pub renew_deadline: Duration,
+    /// Interval between attempts to acquire or renew the Lease
+    pub retry_period: Duration,
+}
+
+impl LeaderElectionConfig {
+    /// Read the settings from the environment, `None` when leader election
+    /// is not enabled
+    pub fn from_env() -> Result<Option<Self>> {
+        let var = |name: &str| std::env::var(name).ok().filter(|v| !v.is_empty());
+        if var(ENABLED_ENV).as_deref() != Some("true") {
+            return Ok(None);
+        }
+        let duration = |name: &str, default: u64| {
+            var(name).map_or(Ok(Duration::from_secs(default)), |v| {
+                parse_duration(&v).map_err(|e| Error::InvalidConfig(format!("{name}: {e}")))
+            })
+        };
+        let config = Self {
+            lease_name: var(LEASE_NAME_ENV).unwrap_or_else(|| DEFAULT_LEASE_NAME.to_string()),
+            namespace: var("OPERATOR_NAMESPACE").ok_or_else(|| {
+                Error::InvalidConfig("leader election needs OPERATOR_NAMESPACE".to_string())
+            })?,
+            identity: var(POD_NAME_ENV)
+                .or_else(|| var("HOSTNAME"))
+                .ok_or_else(|| {
+                    Error::InvalidConfig(format!("leader election needs {POD_NAME_ENV}"))
+                })?,
+            lease_duration: duration(LEASE_DURATION_ENV, 15)?,
+            renew_deadline: duration(RENEW_DEADLINE_ENV, 10)?,
+            retry_period: duration(RETRY_PERIOD_ENV, 2)?,
+        };
+        if config.renew_deadline >= config.lease_duration
+            || config.retry_period >= config.renew_deadline
+        {
+            return Err(Error::InvalidConfig(
+                "leader election needs retryPeriod < renewDeadline < leaseDuration".to_string(),
+            ));
+        }
+        Ok(Some(config))
+    }
+}
+
+/// Parse a duration such as `15s`, `500ms`, `5m` or `1h`; a bare number is
+/// seconds
+pub fn parse_duration(value: &str) -> std::result::Result<Duration, String> {
+    let value = value.trim();
+    let (number, unit) = value
+        .find(|c: char| !c.is_ascii_digit())
+        .map_or((value, ""), |i| value.split_at(i));
+    let number: u64 = number
+        .parse()
+        .map_err(|_| format!("invalid duration '{value}'"))?;
+    match unit {
+        "" | "s" => Ok(Duration::from_secs(number)),
+        "ms" => Ok(Duration::from_millis(number)),
+        "m" => Ok(Duration::from_secs(number * 60)),
+        "h" => Ok(Duration::from_secs(number * 3600)),
+        _ => Err(format!("invalid duration unit in '{value}'")),
+    }
+}
+
+/// When this replica last saw the Lease's holder or renew time change.
+/// Expiry is measured on the local clock from that moment, as client-go does,
+/// so clock skew between nodes cannot make a live Lease look expired.
+#[derive(Debug, Default)]
+struct LeaseObserver {
+    observed: Option<(Option<String>, Option<MicroTime>, Instant)>,
+}
+
+impl LeaseObserver {
+    /// Record `current` as read at `now`, returning how long it has gone
+    /// unchanged
+    fn observe(&mut self, current: Option<&LeaseSpec>, now: Instant) -> Duration {
+        let holder = current.and_then(|spec| spec.holder_identity.clone());
+        let renew_time = current.and_then(|spec| spec.renew_time.clone());
+        match &self.observed {
+            Some((h, r, since)) if *h == holder && *r == renew_time => now - *since,
+            _ => {
+                self.observed = Some((holder, renew_time, now));
+                Duration::ZERO
+            }
+        }
+    }
+}
+
+/// The spec to write for `identity` to hold the Lease at `now`, `None` while
+/// another holder's Lease is still valid: it has changed within its lease
+/// duration, `unchanged_for` being the time since this replica saw it change
+fn claim(
+    current: Option<&LeaseSpec>,
+    identity: &str,
+    lease_duration: Duration,
+    now: DateTime<Utc>,
+    unchanged_for: Duration,
+) -> Option<LeaseSpec> {
+    let current = current.cloned().unwrap_or_default();
+    let holder = current.holder_identity.as_deref().filter(|h| !h.is_empty());
+    let held_by_us = holder == Some(identity);
+    if let (Some(_), false) = (holder, held_by_us) {
+        let held_for =
+            Duration::from_secs(current.lease_duration_seconds.unwrap_or_default().max(0) as u64);
+        if unchanged_for < held_for {
+            return None;
+        }
+    }
+    // Every change of holder after the Lease was first acquired is a
+    // transition
+    let transitions = current.lease_transitions.unwrap_or(0);
+    let transition = !held_by_us && current.acquire_time.is_some();
+    Some(LeaseSpec {
+        holder_identity: Some(identity.to_string()),
+        lease_duration_seconds: Some(lease_duration.as_secs().max(1) as i32),
+        acquire_time: if held_by_us {
+            current.acquire_time
+        } else {
+            Some(MicroTime(now))
+        },
+        renew_time: Some(MicroTime(now)),
+        lease_transitions: Some(transitions + i32::from(transition)),
+    })
+}
+
+/// Acquires, renews and releases the operator's Lease
+pub struct LeaderElector {
+    api: Api<Lease>,
+    config: LeaderElectionConfig,
+    observer: Mutex<LeaseObserver>,
+}
+
+impl LeaderElector {
+    pub fn new(client: Client, config: LeaderElectionConfig) -> Self {
+        Self {
+            api: Api::namespaced(client, &config.namespace),
+            config,
+            observer: Mutex::default(),
+        }
+    }
+
+    /// Try to acquire the Lease, or renew it when already held. Returns
+    /// whether this replica holds it; losing a write race is not an error.
+    pub async fn try_acquire_or_renew(&self) -> Result<bool> {
+        let now = Utc::now();
+        let Some(lease) = self.api.get_opt(&self.config.lease_name).await? else {
+            let lease = Lease {
+                metadata: ObjectMeta {
+                    name: Some(self.config.lease_name.clone()),
+                    ..Default::default()
+                },
+                spec: claim(
+                    None,
+                    &self.config.identity,
+                    self.config.lease_duration,
+                    now,
+                    Duration::ZERO,
+                ),
+            };
+            return ignore_conflict(self.api.create(&PostParams::default(), &lease).await);
+        };
+        let unchanged_for = self
+            .observer
+            .lock()
+            .unwrap()
+            .observe(lease.spec.as_ref(), Instant::now());
+        let Some(spec) = claim(
+            lease.spec.as_ref(),
+            &self.config.identity,
+            self.config.lease_duration,
+            now,
+            unchanged_for,
+        ) else {
+            return Ok(false);
+        };
+        // The resourceVersion makes the replace fail if another replica
+        // wrote the Lease since it was read
+        let lease = Lease {
+            metadata: lease.metadata,
+            spec: Some(spec),
+        };
+        ignore_conflict(
+            self.api
+                .replace(&self.config.lease_name, &PostParams::default(), &lease)
+                .await,
+        )
+    }
+
+    /// Give up the Lease so a standby replica can take over without waiting
+    /// for it to expire
+    pub async fn release(&self) -> Result<()> {
+        let Some(mut lease) = self.api.get_opt(&self.config.lease_name).await? else {
+            return Ok(());
+        };
+        let Some(spec) = lease.spec.as_mut() else {
+            return Ok(());
+        };
+        if spec.holder_identity.as_deref() != Some(self.config.identity.as_str()) {
+            return Ok(());
+        }
+        spec.holder_identity = None;
+        spec.lease_duration_seconds = Some(1);
+        spec.renew_time = Some(MicroTime(Utc::now()));
+        ignore_conflict(
+            self.api
+                .replace(&self.config.lease_name, &PostParams::default(), &lease)
+                .await,
+        )
+        .map(|_| ())
+    }
+
+    /// Keep renewing the Lease, returning once leadership is lost: another
+    /// replica took it, or no renewal succeeded within the renew deadline
+    async fn keep_renewing(&self) {
+        let mut renewed = Instant::now();
+        loop {
+            tokio::time::sleep(self.config.retry_period).await;
+            let attempt =
+                tokio::time::timeout(self.config.renew_deadline, self.try_acquire_or_renew());
+            match attempt.await {
+                Ok(Ok(true)) => renewed = Instant::now(),
+                Ok(Ok(false)) => return,
+                Ok(Err(e)) => warn!(error = %e, "Failed to renew the leader Lease"),
+                Err(_) => warn!("Timed out renewing the leader Lease"),
+            }
+            if renewed.elapsed() >= self.config.renew_deadline {
+                return;
+            }
+        }
+    }
+}
+
+fn ignore_conflict(result: kube::Result<Lease>) -> Result<bool> {
+    match result {
+        Ok(_) => Ok(true),
+        Err(kube::Error::Api(e)) if e.code == 409 => Ok(false),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Run `lead` whenever this replica holds the Lease. Standby replicas wait
+/// until `shutdown` completes. When leadership is lost, the controllers are
+/// stopped and the replica goes back to standby; when `lead` returns, which
+/// the controllers do on SIGTERM, the Lease is released.
+pub async fn run<F, Fut>(
+    elector: LeaderElector,
+    metrics: &MetricsState,
+    mut lead: F,
+    shutdown: impl Future<Output = ()>,
+) where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = ()>,
+{
+    let identity = elector.config.identity.clone();
+    let lease = elector.config.lease_name.clone();
+    tokio::pin!(shutdown);
+    loop {
+        info!(%identity, %lease, "Waiting for leadership");
+        loop {
+            match elector.try_acquire_or_renew().await {
+                Ok(true) => break,
+                Ok(false) => {}
+                Err(e) => warn!(error = %e, "Failed to acquire the leader Lease"),
+            }
+            tokio::select! {
+                _ = &mut shutdown => return,
+                _ = tokio::time::sleep(elector.config.retry_period) => {}
+            }
+        }
+
+        info!(%identity, %lease, "Acquired leadership, starting controllers");
+        metrics.operator_leader.set(1);
+        let lost = tokio::select! {
+            _ = lead() => false,
+            _ = elector.keep_renewing() => true,
+        };
+        metrics.operator_leader.set(0);
+        if !lost {
+            break;
+        }
+        warn!(%identity, %lease, "Lost leadership, stopping controllers");
+    }
+
+    match elector.release().await {
+        Ok(()) => info!(%identity, %lease, "Released leadership"),
+        Err(e) => warn!(error = %e, "Failed to release the leader Lease"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn held_by(holder: &str, renewed: DateTime<Utc>) -> LeaseSpec {
+        LeaseSpec {
+            holder_identity: Some(holder.to_string()),
+            lease_duration_seconds: Some(15),
+            acquire_time: Some(MicroTime(renewed - chrono::Duration::minutes(5))),
+            renew_time: Some(MicroTime(renewed)),
+            lease_transitions: Some(2),
+        }
+    }
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("15s"), Ok(Duration::from_secs(15)));
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("1m"), Ok(Duration::from_secs(60)));
+        assert_eq!(parse_duration("10"), Ok(Duration::from_secs(10)));
+        assert_eq!(parse_duration("2h"), Ok(Duration::from_secs(7200)));
+        assert!(parse_duration("10d").is_err());
+        assert!(parse_duration("soon").is_err());
+    }
+
+    #[test]
+    fn free_lease_is_acquired() {
+        let now = Utc::now();
+        let spec = claim(
+            None,
+            "operator-0",
+            Duration::from_secs(15),
+            now,
+            Duration::ZERO,
+        )
+        .unwrap();
+        assert_eq!(spec.holder_identity.as_deref(), Some("operator-0"));
+        assert_eq!(spec.lease_duration_seconds, Some(15));
+        assert_eq!(spec.acquire_time, Some(MicroTime(now)));
+        assert_eq!(spec.lease_transitions, Some(0));
+    }
+
+    #[test]
+    fn valid_lease_of_another_replica_is_left_alone() {
+        let now = Utc::now();
+        let current = held_by("operator-1", now - chrono::Duration::seconds(5));
+        assert_eq!(
+            claim(
+                Some(&current),
+                "operator-0",
+                Duration::from_secs(15),
+                now,
+                Duration::from_secs(5)
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn expiry_ignores_the_holders_clock() {
+        // The holder's clock runs a minute behind, so its renew time looks
+        // long expired on this replica's clock
+        let now = Utc::now();
+        let current = held_by("operator-1", now - chrono::Duration::minutes(1));
+        assert_eq!(
+            claim(
+                Some(&current),
+                "operator-0",
+                Duration::from_secs(15),
+                now,
+                Duration::ZERO
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn lease_age_counts_from_its_last_observed_change() {
+        let mut observer = LeaseObserver::default();
+        let start = Instant::now();
+        let renewed = Utc::now();
+        let current = held_by("operator-1", renewed);
+
+        assert_eq!(observer.observe(Some(&current), start), Duration::ZERO);
+        assert_eq!(
+            observer.observe(Some(&current), start + Duration::from_secs(10)),
+            Duration::from_secs(10)
+        );
+
+        // A renewal restarts the count
+        let renewed_again = held_by("operator-1", renewed + chrono::Duration::seconds(2));
+        let later = start + Duration::from_secs(12);
+        assert_eq!(
+            observer.observe(Some(&renewed_again), later),
+            Duration::ZERO
+        );
+        assert_eq!(
+            observer.observe(Some(&renewed_again), later + Duration::from_secs(16)),
+            Duration::from_secs(16)
+        );
+
+        // So does a new holder with the same renew time
+        let new_holder = LeaseSpec {
+            holder_identity: Some("operator-2".to_string()),
+            ..renewed_again
+        };
+        assert_eq!(
+            observer.observe(Some(&new_holder), later + Duration::from_secs(17)),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn own_lease_is_renewed_without_a_transition() {
+        let now = Utc::now();
+        let current = held_by("operator-0", now - chrono::Duration::seconds(5));
+        let spec = claim(
+            Some(&current),
+            "operator-0",
+            Duration::from_secs(15),
+            now,
+            Duration::ZERO,
+        )
+        .unwrap();
+        assert_eq!(spec.renew_time, Some(MicroTime(now)));
+        assert_eq!(spec.acquire_time, current.acquire_time);
+        assert_eq!(spec.lease_transitions, Some(2));
+    }
+
+    #[test]
+    fn expired_or_released_lease_is_taken_over() {
+        let now = Utc::now();
+        let expired = held_by("operator-1", now - chrono::Duration::seconds(20));
+        let spec = claim(
+            Some(&expired),
+            "operator-0",
+            Duration::from_secs(15),
+            now,
+            Duration::from_secs(15),
+        )
+        .unwrap();
+        assert_eq!(spec.holder_identity.as_deref(), Some("operator-0"));
+        assert_eq!(spec.acquire_time, Some(MicroTime(now)));
+        assert_eq!(spec.lease_transitions, Some(3));
+
+        let released = LeaseSpec {
+            holder_identity: None,
+            ..held_by("operator-1", now)
+        };
+        let spec = claim(
+            Some(&released),
+            "operator-0",
+            Duration::from_secs(15),
+            now,
+            Duration::ZERO,
+        )
+        .unwrap();
+        assert_eq!(spec.holder_identity.as_deref(), Some("operator-0"));
+        assert_eq!(spec.lease_transitions, Some(3));
+    }
+}
@@ -1,2 +1,9 @@
 pub mod backup;
+pub mod health;
+pub mod leader;
 pub mod restore;
+pub mod schedule;
+pub mod snapshot;
+pub mod storage_location;
+pub mod verification;
+pub mod watch;
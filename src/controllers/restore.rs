@@ -8,18 +8,26 @@ use kube::{
         controller::{Action, Controller},
         watcher::Config,
     },
-    Api, Client, ResourceExt,
+    Client, ResourceExt,
 };
 use tokio::time::Duration;
 use tracing::{error, info, instrument};
 
+use super::health::{ControllerHealth, ControllerProbe};
+use super::watch::{scoped_api, WatchNamespaces};
+use crate::config;
 use crate::crd::KafkaRestore;
 use crate::metrics::prometheus::MetricsState;
 use crate::reconcilers::restore::reconcile_restore;
+use crate::status::conditions::{
+    find_condition, CONDITION_TYPE_READY, REASON_WAITING_FOR_CLUSTER, REASON_WAITING_FOR_TOPICS,
+};
+use crate::status::events;
 
 struct Context {
     client: Client,
     metrics: Arc<MetricsState>,
+    probe: ControllerProbe,
 }
 
 #[instrument(skip(ctx))]
@@ -31,13 +39,32 @@ async fn reconcile(
     let namespace = restore.namespace().unwrap_or_default();
     info!(%name, %namespace, "Reconciling KafkaRestore");
 
+    // Strimzi resources are not watched, so poll while the restore waits for
+    // its cluster or topics. Patching the waiting status triggers the
+    // reconcile that first sees it.
+    let waiting = restore.status.as_ref().is_some_and(|status| {
+        find_condition(&status.conditions, CONDITION_TYPE_READY).is_some_and(|c| {
+            matches!(
+                c.reason.as_deref(),
+                Some(REASON_WAITING_FOR_CLUSTER | REASON_WAITING_FOR_TOPICS)
+            )
+        })
+    });
+
     let started = Instant::now();
-    let result = reconcile_restore(restore, ctx.client.clone(), &ctx.metrics).await;
+    let _active = ctx.probe.reconciling();
+    let recorder = events::recorder(ctx.client.clone(), restore.as_ref());
+    let result = reconcile_restore(restore, ctx.client.clone(), &recorder, &ctx.metrics).await;
     ctx.metrics
         .record_reconciliation("restore", result.is_ok(), started.elapsed());
     result?;
 
-    Ok(Action::requeue(Duration::from_secs(300)))
+    if waiting {
+        return Ok(Action::requeue(Duration::from_secs(10)));
+    }
+    Ok(Action::requeue(
+        config::current().reconcile.requeue_interval,
+    ))
 }
 
 fn error_policy(
@@ -47,36 +74,51 @@ fn error_policy(
 ) -> Action {
     let name = restore.name_any();
     error!(%name, %error, "Reconciliation error for KafkaRestore");
-    Action::requeue(Duration::from_secs(30))
+    Action::requeue(config::current().reconcile.error_requeue_interval)
 }
 
-pub async fn run(client: Client, metrics: Arc<MetricsState>) {
-    let restores = Api::<KafkaRestore>::all(client.clone());
-    let jobs = Api::<Job>::all(client.clone());
-
+pub async fn run(
+    client: Client,
+    metrics: Arc<MetricsState>,
+    health: Arc<ControllerHealth>,
+    namespaces: WatchNamespaces,
+) {
+    let probe = health.controller("KafkaRestore");
     let context = Arc::new(Context {
         client: client.clone(),
         metrics,
+        probe: probe.clone(),
     });
 
-    info!("Starting KafkaRestore controller");
+    info!(%namespaces, "Starting KafkaRestore controller");
 
-    Controller::new(restores, Config::default().any_semantic())
+    let controllers = namespaces.scopes().into_iter().map(|namespace| {
+        let controller = Controller::new(
+            scoped_api::<KafkaRestore>(&client, namespace),
+            Config::default().any_semantic(),
+        )
         // Watch owned Jobs so completion/failure updates the KafkaRestore
         // status immediately instead of waiting for the periodic requeue.
         .owns(
-            jobs,
+            scoped_api::<Job>(&client, namespace),
             Config::default().labels("kafkabackup.com/type=restore"),
-        )
-        .shutdown_on_signal()
-        .run(reconcile, error_policy, context)
-        .for_each(|res| async move {
-            match res {
-                Ok(o) => info!("Reconciled KafkaRestore: {:?}", o),
-                Err(e) => error!("Reconcile failed: {:?}", e),
-            }
-        })
-        .await;
+        );
+        let synced = probe.watch(namespace, controller.store());
+        let run = controller
+            .with_config(config::current().reconcile.controller_config())
+            .shutdown_on_signal()
+            .run(reconcile, error_policy, Arc::clone(&context))
+            .inspect(|res| probe.record(res))
+            .for_each(|res| async move {
+                match res {
+                    Ok(o) => info!("Reconciled KafkaRestore: {:?}", o),
+                    Err(e) => error!("Reconcile failed: {:?}", e),
+                }
+            });
+        futures::future::join(synced, run)
+    });
+    futures::future::join_all(controllers).await;
+    probe.stopped();
 
     info!("KafkaRestore controller shut down");
 }
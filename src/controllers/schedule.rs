@@ -0,0 +1,103 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use futures::StreamExt;
+use kube::{
+    runtime::{
+        controller::{Action, Controller},
+        watcher::Config,
+    },
+    Client, ResourceExt,
+};
+use tokio::time::Duration;
+use tracing::{error, info, instrument};
+
+use super::health::{ControllerHealth, ControllerProbe};
+use super::watch::{scoped_api, WatchNamespaces};
+use crate::config;
+use crate::crd::{KafkaBackup, KafkaBackupSchedule};
+use crate::metrics::prometheus::MetricsState;
+use crate::reconcilers::schedule::{reconcile_schedule, SCHEDULE_LABEL};
+
+struct Context {
+    client: Client,
+    metrics: Arc<MetricsState>,
+    probe: ControllerProbe,
+}
+
+#[instrument(skip(ctx))]
+async fn reconcile(
+    schedule: Arc<KafkaBackupSchedule>,
+    ctx: Arc<Context>,
+) -> Result<Action, crate::error::Error> {
+    let name = schedule.name_any();
+    let namespace = schedule.namespace().unwrap_or_default();
+    info!(%name, %namespace, "Reconciling KafkaBackupSchedule");
+
+    let started = Instant::now();
+    let _active = ctx.probe.reconciling();
+    let result = reconcile_schedule(schedule, ctx.client.clone(), &ctx.metrics).await;
+    ctx.metrics
+        .record_reconciliation("schedule", result.is_ok(), started.elapsed());
+    result?;
+
+    // Kafka CRs are not watched, so a shorter requeue picks up clusters that
+    // start or stop matching the selector.
+    Ok(Action::requeue(Duration::from_secs(60)))
+}
+
+fn error_policy(
+    schedule: Arc<KafkaBackupSchedule>,
+    error: &crate::error::Error,
+    _ctx: Arc<Context>,
+) -> Action {
+    let name = schedule.name_any();
+    error!(%name, %error, "Reconciliation error for KafkaBackupSchedule");
+    Action::requeue(config::current().reconcile.error_requeue_interval)
+}
+
+pub async fn run(
+    client: Client,
+    metrics: Arc<MetricsState>,
+    health: Arc<ControllerHealth>,
+    namespaces: WatchNamespaces,
+) {
+    let probe = health.controller("KafkaBackupSchedule");
+    let context = Arc::new(Context {
+        client: client.clone(),
+        metrics,
+        probe: probe.clone(),
+    });
+
+    info!(%namespaces, "Starting KafkaBackupSchedule controller");
+
+    let controllers = namespaces.scopes().into_iter().map(|namespace| {
+        let controller = Controller::new(
+            scoped_api::<KafkaBackupSchedule>(&client, namespace),
+            Config::default().any_semantic(),
+        )
+        // Watch generated KafkaBackups so their readiness is reflected in the
+        // schedule status as soon as it changes.
+        .owns(
+            scoped_api::<KafkaBackup>(&client, namespace),
+            Config::default().labels(SCHEDULE_LABEL),
+        );
+        let synced = probe.watch(namespace, controller.store());
+        let run = controller
+            .with_config(config::current().reconcile.controller_config())
+            .shutdown_on_signal()
+            .run(reconcile, error_policy, Arc::clone(&context))
+            .inspect(|res| probe.record(res))
+            .for_each(|res| async move {
+                match res {
+                    Ok(o) => info!("Reconciled KafkaBackupSchedule: {:?}", o),
+                    Err(e) => error!("Reconcile failed: {:?}", e),
+                }
+            });
+        futures::future::join(synced, run)
+    });
+    futures::future::join_all(controllers).await;
+    probe.stopped();
+
+    info!("KafkaBackupSchedule controller shut down");
+}
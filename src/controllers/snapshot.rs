@@ -0,0 +1,95 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use futures::StreamExt;
+use kube::{
+    runtime::{
+        controller::{Action, Controller},
+        watcher::Config,
+    },
+    Client, ResourceExt,
+};
+use tracing::{error, info, instrument};
+
+use super::health::{ControllerHealth, ControllerProbe};
+use super::watch::{scoped_api, WatchNamespaces};
+use crate::config;
+use crate::crd::KafkaBackupSnapshot;
+use crate::metrics::prometheus::MetricsState;
+use crate::reconcilers::snapshot::reconcile_snapshot;
+
+struct Context {
+    client: Client,
+    metrics: Arc<MetricsState>,
+    probe: ControllerProbe,
+}
+
+#[instrument(skip(ctx))]
+async fn reconcile(
+    snapshot: Arc<KafkaBackupSnapshot>,
+    ctx: Arc<Context>,
+) -> Result<Action, crate::error::Error> {
+    let name = snapshot.name_any();
+    let namespace = snapshot.namespace().unwrap_or_default();
+    info!(%name, %namespace, "Reconciling KafkaBackupSnapshot");
+
+    let started = Instant::now();
+    let _active = ctx.probe.reconciling();
+    let result = reconcile_snapshot(snapshot, ctx.client.clone(), &ctx.metrics).await;
+    ctx.metrics
+        .record_reconciliation("snapshot", result.is_ok(), started.elapsed());
+    result?;
+
+    // Snapshots only need work when they are deleted, which the watch delivers.
+    Ok(Action::await_change())
+}
+
+fn error_policy(
+    snapshot: Arc<KafkaBackupSnapshot>,
+    error: &crate::error::Error,
+    _ctx: Arc<Context>,
+) -> Action {
+    let name = snapshot.name_any();
+    error!(%name, %error, "Reconciliation error for KafkaBackupSnapshot");
+    Action::requeue(config::current().reconcile.error_requeue_interval)
+}
+
+pub async fn run(
+    client: Client,
+    metrics: Arc<MetricsState>,
+    health: Arc<ControllerHealth>,
+    namespaces: WatchNamespaces,
+) {
+    let probe = health.controller("KafkaBackupSnapshot");
+    let context = Arc::new(Context {
+        client: client.clone(),
+        metrics,
+        probe: probe.clone(),
+    });
+
+    info!(%namespaces, "Starting KafkaBackupSnapshot controller");
+
+    let controllers = namespaces.scopes().into_iter().map(|namespace| {
+        let controller = Controller::new(
+            scoped_api::<KafkaBackupSnapshot>(&client, namespace),
+            Config::default().any_semantic(),
+        );
+        let synced = probe.watch(namespace, controller.store());
+        let run = controller
+            .with_config(config::current().reconcile.controller_config())
+            .shutdown_on_signal()
+            .run(reconcile, error_policy, Arc::clone(&context))
+            .inspect(|res| probe.record(res))
+            .for_each(|res| async move {
+                match res {
+                    Ok(o) => info!("Reconciled KafkaBackupSnapshot: {:?}", o),
+                    Err(e) => error!("Reconcile failed: {:?}", e),
+                }
+            });
+        futures::future::join(synced, run)
+    });
+    futures::future::join_all(controllers).await;
+    probe.stopped();
+
+    info!("KafkaBackupSnapshot controller shut down");
+}
@@ -0,0 +1,168 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use futures::StreamExt;
+use kube::{
+    runtime::{
+        controller::{Action, Controller},
+        watcher::Config,
+    },
+    Api, Client, ResourceExt,
+};
+use tracing::{error, info, instrument};
+
+use super::health::{ControllerHealth, ControllerProbe};
+use super::watch::{scoped_api, WatchNamespaces};
+use crate::config;
+use crate::crd::{BackupStorageLocation, ClusterBackupStorageLocation};
+use crate::metrics::prometheus::MetricsState;
+use crate::reconcilers::storage_location::{
+    reconcile_cluster_storage_location, reconcile_storage_location,
+};
+
+struct Context {
+    client: Client,
+    metrics: Arc<MetricsState>,
+    probe: ControllerProbe,
+    cluster_probe: ControllerProbe,
+}
+
+#[instrument(skip(ctx))]
+async fn reconcile(
+    location: Arc<BackupStorageLocation>,
+    ctx: Arc<Context>,
+) -> Result<Action, crate::error::Error> {
+    let name = location.name_any();
+    let namespace = location.namespace().unwrap_or_default();
+    info!(%name, %namespace, "Reconciling BackupStorageLocation");
+
+    let started = Instant::now();
+    let _active = ctx.probe.reconciling();
+    let result = reconcile_storage_location(location, ctx.client.clone(), &ctx.metrics).await;
+    ctx.metrics
+        .record_reconciliation("storage_location", result.is_ok(), started.elapsed());
+    result?;
+
+    // Requeue periodically to re-check reachability
+    Ok(Action::requeue(
+        config::current().reconcile.requeue_interval,
+    ))
+}
+
+#[instrument(skip(ctx))]
+async fn reconcile_cluster(
+    location: Arc<ClusterBackupStorageLocation>,
+    ctx: Arc<Context>,
+) -> Result<Action, crate::error::Error> {
+    let name = location.name_any();
+    info!(%name, "Reconciling ClusterBackupStorageLocation");
+
+    let started = Instant::now();
+    let _active = ctx.cluster_probe.reconciling();
+    let result =
+        reconcile_cluster_storage_location(location, ctx.client.clone(), &ctx.metrics).await;
+    ctx.metrics.record_reconciliation(
+        "cluster_storage_location",
+        result.is_ok(),
+        started.elapsed(),
+    );
+    result?;
+
+    // Requeue periodically to re-check reachability
+    Ok(Action::requeue(
+        config::current().reconcile.requeue_interval,
+    ))
+}
+
+fn error_policy(
+    location: Arc<BackupStorageLocation>,
+    error: &crate::error::Error,
+    _ctx: Arc<Context>,
+) -> Action {
+    let name = location.name_any();
+    error!(%name, %error, "Reconciliation error for BackupStorageLocation");
+    Action::requeue(config::current().reconcile.error_requeue_interval)
+}
+
+fn cluster_error_policy(
+    location: Arc<ClusterBackupStorageLocation>,
+    error: &crate::error::Error,
+    _ctx: Arc<Context>,
+) -> Action {
+    let name = location.name_any();
+    error!(%name, %error, "Reconciliation error for ClusterBackupStorageLocation");
+    Action::requeue(config::current().reconcile.error_requeue_interval)
+}
+
+pub async fn run(
+    client: Client,
+    metrics: Arc<MetricsState>,
+    health: Arc<ControllerHealth>,
+    namespaces: WatchNamespaces,
+) {
+    let probe = health.controller("BackupStorageLocation");
+    // Registered only when it runs, so that readiness does not wait for it
+    let cluster_probe = if namespaces.is_all() {
+        health.controller("ClusterBackupStorageLocation")
+    } else {
+        probe.clone()
+    };
+    let context = Arc::new(Context {
+        client: client.clone(),
+        metrics,
+        probe: probe.clone(),
+        cluster_probe: cluster_probe.clone(),
+    });
+
+    info!(%namespaces, "Starting BackupStorageLocation controllers");
+
+    let namespaced = namespaces.scopes().into_iter().map(|namespace| {
+        let controller = Controller::new(
+            scoped_api::<BackupStorageLocation>(&client, namespace),
+            Config::default().any_semantic(),
+        );
+        let synced = probe.watch(namespace, controller.store());
+        let run = controller
+            .with_config(config::current().reconcile.controller_config())
+            .shutdown_on_signal()
+            .run(reconcile, error_policy, Arc::clone(&context))
+            .inspect(|res| probe.record(res))
+            .for_each(|res| async move {
+                match res {
+                    Ok(o) => info!("Reconciled BackupStorageLocation: {:?}", o),
+                    Err(e) => error!("Reconcile failed: {:?}", e),
+                }
+            });
+        futures::future::join(synced, run)
+    });
+    let namespaced = futures::future::join_all(namespaced);
+
+    // Watching cluster-scoped locations needs cluster-wide permissions, which
+    // an operator restricted to some namespaces is not granted
+    if namespaces.is_all() {
+        let controller = Controller::new(
+            Api::<ClusterBackupStorageLocation>::all(client.clone()),
+            Config::default().any_semantic(),
+        );
+        let synced = cluster_probe.watch(None, controller.store());
+        let cluster_scoped = controller
+            .with_config(config::current().reconcile.controller_config())
+            .shutdown_on_signal()
+            .run(reconcile_cluster, cluster_error_policy, context)
+            .inspect(|res| cluster_probe.record(res))
+            .for_each(|res| async move {
+                match res {
+                    Ok(o) => info!("Reconciled ClusterBackupStorageLocation: {:?}", o),
+                    Err(e) => error!("Reconcile failed: {:?}", e),
+                }
+            });
+        futures::join!(namespaced, synced, cluster_scoped);
+    } else {
+        info!("Not watching ClusterBackupStorageLocations outside a cluster-wide install");
+        namespaced.await;
+    }
+    probe.stopped();
+    cluster_probe.stopped();
+
+    info!("BackupStorageLocation controllers shut down");
+}
@@ -0,0 +1,103 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use futures::StreamExt;
+use kube::{
+    runtime::{
+        controller::{Action, Controller},
+        watcher::Config,
+    },
+    Client, ResourceExt,
+};
+use tokio::time::Duration;
+use tracing::{error, info, instrument};
+
+use super::health::{ControllerHealth, ControllerProbe};
+use super::watch::{scoped_api, WatchNamespaces};
+use crate::config;
+use crate::crd::{KafkaBackupVerification, KafkaRestore};
+use crate::metrics::prometheus::MetricsState;
+use crate::reconcilers::verification::{reconcile_verification, VERIFICATION_LABEL};
+
+struct Context {
+    client: Client,
+    metrics: Arc<MetricsState>,
+    probe: ControllerProbe,
+}
+
+#[instrument(skip(ctx))]
+async fn reconcile(
+    verification: Arc<KafkaBackupVerification>,
+    ctx: Arc<Context>,
+) -> Result<Action, crate::error::Error> {
+    let name = verification.name_any();
+    let namespace = verification.namespace().unwrap_or_default();
+    info!(%name, %namespace, "Reconciling KafkaBackupVerification");
+
+    let started = Instant::now();
+    let _active = ctx.probe.reconciling();
+    let result = reconcile_verification(verification, ctx.client.clone(), &ctx.metrics).await;
+    ctx.metrics
+        .record_reconciliation("verification", result.is_ok(), started.elapsed());
+    result?;
+
+    // Cron schedules have minute granularity; requeue often enough to start
+    // each run on time.
+    Ok(Action::requeue(Duration::from_secs(60)))
+}
+
+fn error_policy(
+    verification: Arc<KafkaBackupVerification>,
+    error: &crate::error::Error,
+    _ctx: Arc<Context>,
+) -> Action {
+    let name = verification.name_any();
+    error!(%name, %error, "Reconciliation error for KafkaBackupVerification");
+    Action::requeue(config::current().reconcile.error_requeue_interval)
+}
+
+pub async fn run(
+    client: Client,
+    metrics: Arc<MetricsState>,
+    health: Arc<ControllerHealth>,
+    namespaces: WatchNamespaces,
+) {
+    let probe = health.controller("KafkaBackupVerification");
+    let context = Arc::new(Context {
+        client: client.clone(),
+        metrics,
+        probe: probe.clone(),
+    });
+
+    info!(%namespaces, "Starting KafkaBackupVerification controller");
+
+    let controllers = namespaces.scopes().into_iter().map(|namespace| {
+        let controller = Controller::new(
+            scoped_api::<KafkaBackupVerification>(&client, namespace),
+            Config::default().any_semantic(),
+        )
+        // Watch verification KafkaRestores so a finished run is recorded as
+        // soon as the restore completes or fails.
+        .owns(
+            scoped_api::<KafkaRestore>(&client, namespace),
+            Config::default().labels(VERIFICATION_LABEL),
+        );
+        let synced = probe.watch(namespace, controller.store());
+        let run = controller
+            .with_config(config::current().reconcile.controller_config())
+            .shutdown_on_signal()
+            .run(reconcile, error_policy, Arc::clone(&context))
+            .inspect(|res| probe.record(res))
+            .for_each(|res| async move {
+                match res {
+                    Ok(o) => info!("Reconciled KafkaBackupVerification: {:?}", o),
+                    Err(e) => error!("Reconcile failed: {:?}", e),
+                }
+            });
+        futures::future::join(synced, run)
+    });
+    futures::future::join_all(controllers).await;
+    probe.stopped();
+
+    info!("KafkaBackupVerification controller shut down");
+}
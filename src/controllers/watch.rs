@@ -0,0 +1,95 @@
+use k8s_openapi::NamespaceResourceScope;
+use kube::{Api, Client, Resource};
+
+/// Environment variable holding the comma-separated namespaces the operator
+/// watches. All namespaces are watched when it is unset, empty or `*`.
+pub const WATCH_NAMESPACES_ENV: &str = "STRIMZI_BACKUP_NAMESPACE";
+
+/// Namespaces the controllers watch
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum WatchNamespaces {
+    /// Every namespace, with cluster-wide watches
+    #[default]
+    All,
+    /// Only these namespaces, with one watch per namespace so that Roles in
+    /// each of them are enough
+    Only(Vec<String>),
+}
+
+impl WatchNamespaces {
+    /// Parse a comma-separated namespace list
+    pub fn parse(value: &str) -> Self {
+        let mut namespaces: Vec<String> = value
+            .split(',')
+            .map(str::trim)
+            .filter(|ns| !ns.is_empty())
+            .map(str::to_string)
+            .collect();
+        if namespaces.is_empty() || namespaces.iter().any(|ns| ns == "*") {
+            return Self::All;
+        }
+        namespaces.sort();
+        namespaces.dedup();
+        Self::Only(namespaces)
+    }
+
+    /// Whether every namespace is watched
+    pub fn is_all(&self) -> bool {
+        matches!(self, Self::All)
+    }
+
+    /// The scope of each watcher to run: `None` for a cluster-wide one
+    pub fn scopes(&self) -> Vec<Option<&str>> {
+        match self {
+            Self::All => vec![None],
+            Self::Only(namespaces) => namespaces.iter().map(|ns| Some(ns.as_str())).collect(),
+        }
+    }
+}
+
+impl std::fmt::Display for WatchNamespaces {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::All => f.write_str("all namespaces"),
+            Self::Only(namespaces) => f.write_str(&namespaces.join(",")),
+        }
+    }
+}
+
+/// Api for `K` in `namespace`, or in all namespaces for `None`
+pub fn scoped_api<K>(client: &Client, namespace: Option<&str>) -> Api<K>
+where
+    K: Resource<Scope = NamespaceResourceScope, DynamicType = ()>,
+{
+    match namespace {
+        Some(namespace) => Api::namespaced(client.clone(), namespace),
+        None => Api::all(client.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unset_or_wildcard_watches_all_namespaces() {
+        assert_eq!(WatchNamespaces::parse(""), WatchNamespaces::All);
+        assert_eq!(WatchNamespaces::parse(" , "), WatchNamespaces::All);
+        assert_eq!(WatchNamespaces::parse("kafka,*"), WatchNamespaces::All);
+        assert_eq!(WatchNamespaces::All.scopes(), vec![None]);
+    }
+
+    #[test]
+    fn namespace_list_is_trimmed_and_deduplicated() {
+        let namespaces = WatchNamespaces::parse("kafka-staging, kafka,kafka-staging");
+        assert_eq!(
+            namespaces,
+            WatchNamespaces::Only(vec!["kafka".to_string(), "kafka-staging".to_string()])
+        );
+        assert_eq!(
+            namespaces.scopes(),
+            vec![Some("kafka"), Some("kafka-staging")]
+        );
+        assert_eq!(namespaces.to_string(), "kafka,kafka-staging");
+    }
+}
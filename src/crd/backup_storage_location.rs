@@ -0,0 +1,68 @@
+use kube::CustomResource;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::common::{Condition, StorageSpec};
+
+/// BackupStorageLocation holds a storage destination that KafkaBackups in the
+/// same namespace reference by name through `spec.storageRef`.
+#[derive(CustomResource, Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[kube(
+    group = "kafkabackup.com",
+    version = "v1alpha1",
+    kind = "BackupStorageLocation",
+    plural = "backupstoragelocations",
+    shortname = "bsl",
+    status = "BackupStorageLocationStatus",
+    namespaced,
+    printcolumn = r#"{"name":"Type","type":"string","jsonPath":".spec.storage.type"}"#,
+    printcolumn = r#"{"name":"Reachable","type":"string","jsonPath":".status.conditions[?(@.type==\"Ready\")].status"}"#,
+    printcolumn = r#"{"name":"Age","type":"date","jsonPath":".metadata.creationTimestamp"}"#
+)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupStorageLocationSpec {
+    /// Storage destination configuration. Secret references resolve in this namespace.
+    #[schemars(schema_with = "super::cel::validated::<StorageSpec>")]
+    pub storage: StorageSpec,
+}
+
+/// ClusterBackupStorageLocation holds a storage destination that KafkaBackups
+/// in any namespace reference by name through `spec.storageRef`.
+#[derive(CustomResource, Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[kube(
+    group = "kafkabackup.com",
+    version = "v1alpha1",
+    kind = "ClusterBackupStorageLocation",
+    plural = "clusterbackupstoragelocations",
+    shortname = "cbsl",
+    status = "BackupStorageLocationStatus",
+    printcolumn = r#"{"name":"Type","type":"string","jsonPath":".spec.storage.type"}"#,
+    printcolumn = r#"{"name":"Reachable","type":"string","jsonPath":".status.conditions[?(@.type==\"Ready\")].status"}"#,
+    printcolumn = r#"{"name":"Age","type":"date","jsonPath":".metadata.creationTimestamp"}"#
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ClusterBackupStorageLocationSpec {
+    /// Storage destination configuration. Secret references resolve in the
+    /// namespace of the KafkaBackup or KafkaRestore using the location, so
+    /// each namespace provides its own credentials.
+    #[schemars(schema_with = "super::cel::validated::<StorageSpec>")]
+    pub storage: StorageSpec,
+
+    /// Namespace whose Secrets are used for the reachability check. Without it,
+    /// reachability is only checked for storage that needs no Secrets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub check_namespace: Option<String>,
+}
+
+/// Status of a BackupStorageLocation or ClusterBackupStorageLocation (follows Strimzi conventions)
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupStorageLocationStatus {
+    /// Strimzi-convention status conditions
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conditions: Vec<Condition>,
+
+    /// Generation observed by the operator
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observed_generation: Option<i64>,
+}
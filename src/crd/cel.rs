@@ -0,0 +1,122 @@
+//! CEL validation rules (`x-kubernetes-validations`) embedded in the generated
+//! CRDs, so the API server rejects invalid objects even without the admission
+//! webhook. Rules are attached to a field with
+//! `#[schemars(schema_with = "validated::<T>")]`, where `T` is the field type.
+
+use schemars::gen::SchemaGenerator;
+use schemars::schema::Schema;
+use schemars::JsonSchema;
+use serde_json::json;
+
+use super::common::{AuthenticationSpec, StorageSpec};
+use super::kafka_backup::RetentionSpec;
+use super::kafka_restore::{PointInTimeSpec, TopicMappingEntry};
+use super::v1beta1;
+
+/// A CEL rule and the message reported when it fails
+pub type Rule = (&'static str, &'static str);
+
+/// A type whose schema carries CEL validation rules
+pub trait ValidationRules {
+    /// Rules evaluated against an instance of the type as `self`
+    fn rules() -> Vec<Rule>;
+}
+
+impl<T: ValidationRules> ValidationRules for Option<T> {
+    fn rules() -> Vec<Rule> {
+        T::rules()
+    }
+}
+
+/// Schema of `T` with its validation rules attached
+pub fn validated<T: JsonSchema + ValidationRules>(gen: &mut SchemaGenerator) -> Schema {
+    let mut schema = gen.subschema_for::<T>();
+    if let Schema::Object(object) = &mut schema {
+        let rules: Vec<_> = T::rules()
+            .into_iter()
+            .map(|(rule, message)| json!({"rule": rule, "message": message}))
+            .collect();
+        object
+            .extensions
+            .insert("x-kubernetes-validations".to_string(), rules.into());
+    }
+    schema
+}
+
+impl ValidationRules for StorageSpec {
+    fn rules() -> Vec<Rule> {
+        vec![
+            (
+                "self.type != 's3' || has(self.s3)",
+                "s3 must be set when type is s3",
+            ),
+            (
+                "self.type != 'azure' || has(self.azure)",
+                "azure must be set when type is azure",
+            ),
+            (
+                "self.type != 'gcs' || has(self.gcs)",
+                "gcs must be set when type is gcs",
+            ),
+            (
+                "self.type != 'filesystem' || has(self.filesystem)",
+                "filesystem must be set when type is filesystem",
+            ),
+        ]
+    }
+}
+
+impl ValidationRules for AuthenticationSpec {
+    fn rules() -> Vec<Rule> {
+        vec![
+            (
+                "self.type != 'tls' || has(self.kafkaUserRef) || has(self.certificateAndKey)",
+                "TLS authentication requires either kafkaUserRef or certificateAndKey",
+            ),
+            (
+                "self.type != 'scram-sha-512' || has(self.kafkaUserRef) || has(self.username)",
+                "SCRAM authentication requires username",
+            ),
+            (
+                "self.type != 'scram-sha-512' || has(self.kafkaUserRef) || has(self.passwordSecret)",
+                "SCRAM authentication requires either kafkaUserRef or passwordSecret",
+            ),
+        ]
+    }
+}
+
+impl ValidationRules for RetentionSpec {
+    fn rules() -> Vec<Rule> {
+        vec![(
+            "has(self.maxBackups) || has(self.maxAge)",
+            "retention requires at least one of maxBackups or maxAge",
+        )]
+    }
+}
+
+impl ValidationRules for PointInTimeSpec {
+    fn rules() -> Vec<Rule> {
+        vec![(
+            "!has(self.startTimestamp) || !has(self.timestamp) || timestamp(self.startTimestamp) < timestamp(self.timestamp)",
+            "pointInTime.startTimestamp must be before pointInTime.timestamp",
+        )]
+    }
+}
+
+impl ValidationRules for v1beta1::PointInTimeSpec {
+    fn rules() -> Vec<Rule> {
+        PointInTimeSpec::rules()
+    }
+}
+
+/// The rule compares every pair of entries, so `topicMapping` also sets
+/// `maxItems` and `targetTopic` a `maxLength` to keep its estimated cost
+/// within the API server's budget
+impl ValidationRules for Vec<TopicMappingEntry> {
+    fn rules() -> Vec<Rule> {
+        vec![(
+            "self.all(m, self.exists_one(n, n.targetTopic == m.targetTopic))",
+            "topicMapping must not map two topics to the same targetTopic",
+        )]
+    }
+}
@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
 /// Resource requirements (CPU/memory) for pods — mirrors k8s ResourceRequirements with JsonSchema
-#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct ResourceRequirementsSpec {
     /// Resource requests
@@ -140,6 +140,134 @@ pub struct TopicSelection {
     pub exclude: Vec<String>,
 }
 
+impl TopicSelection {
+    /// Whether `topic` is selected, using the kafka-backup pattern syntax:
+    /// globs with `*` and `?`, or regexes prefixed with `~`. An empty include
+    /// list selects every topic.
+    pub fn matches(&self, topic: &str) -> Result<bool, regex::Error> {
+        patterns_select(&self.include, &self.exclude, topic)
+    }
+}
+
+/// KafkaUser selection with include/exclude glob patterns
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSelection {
+    /// Glob patterns for KafkaUser names to include
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<String>,
+    /// Glob patterns for KafkaUser names to exclude
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+}
+
+impl UserSelection {
+    /// Whether KafkaUser `name` is selected, with the same pattern syntax as
+    /// [`TopicSelection::matches`]
+    pub fn matches(&self, name: &str) -> Result<bool, regex::Error> {
+        patterns_select(&self.include, &self.exclude, name)
+    }
+}
+
+/// KafkaConnect selection with include/exclude glob patterns
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectSelection {
+    /// Glob patterns for KafkaConnect names to include
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<String>,
+    /// Glob patterns for KafkaConnect names to exclude
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+}
+
+impl ConnectSelection {
+    /// Whether KafkaConnect `name` is selected, with the same pattern syntax
+    /// as [`TopicSelection::matches`]
+    pub fn matches(&self, name: &str) -> Result<bool, regex::Error> {
+        patterns_select(&self.include, &self.exclude, name)
+    }
+}
+
+/// Schema Registry subject selection with include/exclude glob patterns
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SubjectSelection {
+    /// Glob patterns for subject names to include
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<String>,
+    /// Glob patterns for subject names to exclude
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+}
+
+impl SubjectSelection {
+    /// Whether subject `name` is selected, with the same pattern syntax as
+    /// [`TopicSelection::matches`]
+    pub fn matches(&self, name: &str) -> Result<bool, regex::Error> {
+        patterns_select(&self.include, &self.exclude, name)
+    }
+}
+
+/// A Confluent-compatible Schema Registry whose schemas are backed up or
+/// restored through its REST API
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaRegistrySpec {
+    /// Base URL of the REST API, e.g. http://schema-registry.kafka.svc:8081
+    pub url: String,
+
+    /// HTTP basic authentication credentials
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authentication: Option<SchemaRegistryAuthSpec>,
+
+    /// Subjects to include, selected by include/exclude glob (or
+    /// `~`-prefixed regex) patterns. All subjects are included when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subjects: Option<SubjectSelection>,
+}
+
+/// Basic authentication for a Schema Registry
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaRegistryAuthSpec {
+    /// Secret key holding the username
+    pub username_secret: SecretKeyRef,
+    /// Secret key holding the password
+    pub password_secret: SecretKeyRef,
+}
+
+fn patterns_select(
+    include: &[String],
+    exclude: &[String],
+    name: &str,
+) -> Result<bool, regex::Error> {
+    let any_match = |patterns: &[String]| -> Result<bool, regex::Error> {
+        for pattern in patterns {
+            if topic_pattern_regex(pattern)?.is_match(name) {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    };
+    Ok((include.is_empty() || any_match(include)?) && !any_match(exclude)?)
+}
+
+fn topic_pattern_regex(pattern: &str) -> Result<regex::Regex, regex::Error> {
+    if let Some(regex) = pattern.strip_prefix('~') {
+        return regex::Regex::new(&format!("^(?:{regex})$"));
+    }
+    let glob: String = pattern
+        .chars()
+        .map(|c| match c {
+            '*' => ".*".to_string(),
+            '?' => ".".to_string(),
+            c => regex::escape(&c.to_string()),
+        })
+        .collect();
+    regex::Regex::new(&format!("^{glob}$"))
+}
+
 /// Consumer group selection with include/exclude regex patterns
 #[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
@@ -275,6 +403,24 @@ pub struct StorageSpec {
     pub filesystem: Option<FilesystemStorageSpec>,
 }
 
+/// Reference to a BackupStorageLocation or ClusterBackupStorageLocation
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageLocationRef {
+    /// Kind of the referenced location: BackupStorageLocation (default) or ClusterBackupStorageLocation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<StorageLocationKind>,
+    /// Name of the storage location
+    pub name: String,
+}
+
+/// Kind of resource a StorageLocationRef points at
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq)]
+pub enum StorageLocationKind {
+    BackupStorageLocation,
+    ClusterBackupStorageLocation,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum StorageType {
@@ -534,6 +680,24 @@ pub struct BackupHistoryEntry {
     /// Number of partitions backed up
     #[serde(skip_serializing_if = "Option::is_none")]
     pub partitions_backed_up: Option<i32>,
+    /// Oldest record timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oldest_timestamp: Option<DateTime<Utc>>,
+    /// Newest record timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub newest_timestamp: Option<DateTime<Utc>>,
+}
+
+/// Strimzi resource snapshots and Schema Registry export stored next to a
+/// backup's manifest
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupSnapshotsInfo {
+    /// ID of the backup the files belong to
+    pub backup_id: String,
+    /// Files not written yet, retried on every reconcile
+    #[serde(default)]
+    pub missing: Vec<String>,
 }
 
 /// Last backup details
@@ -584,6 +748,10 @@ pub struct RestoreInfo {
     pub completion_time: Option<DateTime<Utc>>,
     /// Restore status
     pub status: RestoreStatus,
+    /// ID of the backup the restore Job restores, resolved when the Job was
+    /// created
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backup_id: Option<String>,
     /// Number of topics restored
     #[serde(skip_serializing_if = "Option::is_none")]
     pub restored_topics: Option<i32>,
@@ -1,11 +1,15 @@
-use kube::CustomResource;
+use chrono::{DateTime, Utc};
+use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition;
+use kube::core::crd::merge_crds;
+use kube::{CustomResource, CustomResourceExt};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::common::{
-    AuthenticationSpec, BackupHistoryEntry, Condition, ConsumerGroupSelection, KafkaConnectionSpec,
-    LastBackupInfo, LoggingSpec, MetricsSpec, OffsetStorageSpec, PodTemplateSpec,
-    ResourceRequirementsSpec, StorageSpec, StrimziClusterRef, TopicSelection,
+    AuthenticationSpec, BackupHistoryEntry, BackupSnapshotsInfo, Condition, ConnectSelection,
+    ConsumerGroupSelection, KafkaConnectionSpec, LastBackupInfo, LoggingSpec, MetricsSpec,
+    OffsetStorageSpec, PodTemplateSpec, ResourceRequirementsSpec, SchemaRegistrySpec,
+    StorageLocationRef, StorageSpec, StrimziClusterRef, TopicSelection, UserSelection,
 };
 
 /// KafkaBackup defines a backup configuration for a Strimzi-managed Kafka cluster.
@@ -22,6 +26,8 @@ use super::common::{
     printcolumn = r#"{"name":"Cluster","type":"string","jsonPath":".spec.strimziClusterRef.name"}"#,
     printcolumn = r#"{"name":"Schedule","type":"string","jsonPath":".spec.schedule.cron"}"#,
     printcolumn = r#"{"name":"Last Backup","type":"date","jsonPath":".status.lastBackup.completionTime"}"#,
+    printcolumn = r#"{"name":"Last Schedule","type":"date","jsonPath":".status.lastScheduleTime"}"#,
+    printcolumn = r#"{"name":"Next Backup","type":"string","jsonPath":".status.nextScheduledBackup"}"#,
     printcolumn = r#"{"name":"Ready","type":"string","jsonPath":".status.conditions[?(@.type==\"Ready\")].status"}"#,
     printcolumn = r#"{"name":"Age","type":"date","jsonPath":".metadata.creationTimestamp"}"#
 )]
@@ -31,7 +37,8 @@ pub struct KafkaBackupSpec {
     pub strimzi_cluster_ref: StrimziClusterRef,
 
     /// Authentication configuration for connecting to the Kafka cluster
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(schema_with = "super::cel::validated::<Option<AuthenticationSpec>>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub authentication: Option<AuthenticationSpec>,
 
     /// Topic selection with include/exclude glob patterns
@@ -46,6 +53,24 @@ pub struct KafkaBackupSpec {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub consumer_groups: Option<ConsumerGroupSelection>,
 
+    /// KafkaUsers of the cluster to back up with their ACLs and quotas,
+    /// selected by name. Users are not backed up when omitted; `{}` selects
+    /// all of them. Credentials are never stored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub users: Option<UserSelection>,
+
+    /// KafkaConnect clusters whose bootstrapServers point at the cluster, to
+    /// back up with their KafkaConnectors, selected by name. Their offset,
+    /// config and status topics are added to the backed-up topics. Connect
+    /// resources are not backed up when omitted; `{}` selects all of them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connect: Option<ConnectSelection>,
+
+    /// Schema Registry to export subjects from, with their schema versions,
+    /// compatibility levels and modes. Schemas are not backed up when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_registry: Option<SchemaRegistrySpec>,
+
     /// Logging configuration for backup job pods
     #[serde(skip_serializing_if = "Option::is_none")]
     pub logging: Option<LoggingSpec>,
@@ -55,8 +80,15 @@ pub struct KafkaBackupSpec {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub env: Vec<serde_json::Value>,
 
-    /// Storage destination configuration
-    pub storage: StorageSpec,
+    /// Storage destination configuration. Exactly one of storage or storageRef must be set.
+    #[schemars(schema_with = "super::cel::validated::<Option<StorageSpec>>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage: Option<StorageSpec>,
+
+    /// Reference to a BackupStorageLocation or ClusterBackupStorageLocation
+    /// holding the storage destination, instead of an inline storage block
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_ref: Option<StorageLocationRef>,
 
     /// Backup options (compression, parallelism, checkpointing)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -75,7 +107,8 @@ pub struct KafkaBackupSpec {
     pub schedule: Option<ScheduleSpec>,
 
     /// Retention policy for managing old backups
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(schema_with = "super::cel::validated::<Option<RetentionSpec>>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub retention: Option<RetentionSpec>,
 
     /// Resource requirements for backup pods
@@ -196,6 +229,16 @@ pub struct ScheduleSpec {
     /// Suspend scheduling
     #[serde(default)]
     pub suspend: bool,
+    /// Number of finished successful Jobs kept by a scheduled KafkaBackup's
+    /// CronJob (default: 3)
+    #[schemars(range(min = 0))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub successful_jobs_history_limit: Option<i32>,
+    /// Number of finished failed Jobs kept by a scheduled KafkaBackup's
+    /// CronJob (default: 3)
+    #[schemars(range(min = 0))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failed_jobs_history_limit: Option<i32>,
 }
 
 /// Retention policy for backup management
@@ -213,6 +256,15 @@ pub struct RetentionSpec {
     pub prune_on_schedule: bool,
 }
 
+/// The KafkaBackup CRD serving every version
+pub fn kafka_backup_crd() -> CustomResourceDefinition {
+    merge_crds(
+        vec![KafkaBackup::crd(), super::v1beta1::KafkaBackup::crd()],
+        super::STORAGE_VERSION,
+    )
+    .expect("KafkaBackup versions share their names and scope")
+}
+
 /// Status of a KafkaBackup resource (follows Strimzi conventions)
 #[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
@@ -229,20 +281,39 @@ pub struct KafkaBackupStatus {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub backup_history: Vec<BackupHistoryEntry>,
 
+    /// Strimzi resource snapshots and Schema Registry export of the last
+    /// backup
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshots: Option<BackupSnapshotsInfo>,
+
+    /// `lastBackup.id` when the backups in storage were last mirrored as
+    /// KafkaBackupSnapshot resources
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_synced_backup_id: Option<String>,
+
+    /// ID of the latest backup a KafkaBackupVerification restored successfully
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_verified_backup_id: Option<String>,
+
     /// Generation observed by the operator
     #[serde(skip_serializing_if = "Option::is_none")]
     pub observed_generation: Option<i64>,
 
-    /// Next scheduled backup time
+    /// Next time the backup schedule fires, as an RFC 3339 timestamp
+    /// (older operator versions wrote the cron expression here)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub next_scheduled_backup: Option<String>,
+
+    /// Last time the backup CronJob started a Job
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_schedule_time: Option<DateTime<Utc>>,
 }
 
 #[cfg(test)]
 mod tests {
     use kube::CustomResourceExt;
 
-    use super::KafkaBackup;
+    use super::{kafka_backup_crd, KafkaBackup};
 
     #[test]
     fn crd_does_not_expose_unsupported_encryption() {
@@ -256,4 +327,41 @@ mod tests {
             "the OSS Strimzi operator must not expose enterprise-only encryption"
         );
     }
+
+    #[test]
+    fn crd_validates_storage_type_against_its_block() {
+        let crd = serde_json::to_value(KafkaBackup::crd()).expect("CRD should serialize");
+        let storage = crd
+            .pointer("/spec/versions/0/schema/openAPIV3Schema/properties/spec/properties/storage")
+            .expect("storage must be in the schema");
+        let rules: Vec<&str> = storage["x-kubernetes-validations"]
+            .as_array()
+            .expect("storage must carry validation rules")
+            .iter()
+            .filter_map(|r| r["rule"].as_str())
+            .collect();
+        assert!(rules.contains(&"self.type != 's3' || has(self.s3)"));
+        // Wrapping the schema keeps the field optional and documented
+        assert_eq!(storage["nullable"], true);
+        assert!(storage["description"].is_string());
+    }
+
+    #[test]
+    fn crd_serves_v1beta1_and_stores_v1alpha1() {
+        let crd = serde_json::to_value(kafka_backup_crd()).expect("CRD should serialize");
+        let versions = crd["spec"]["versions"].as_array().expect("versions");
+        let served: Vec<(&str, bool)> = versions
+            .iter()
+            .map(|v| (v["name"].as_str().unwrap(), v["storage"] == true))
+            .collect();
+        assert_eq!(served, [("v1alpha1", true), ("v1beta1", false)]);
+
+        let backup = &versions[1]["schema"]["openAPIV3Schema"]["properties"]["spec"]["properties"]
+            ["backup"]["properties"];
+        assert_eq!(
+            backup["compression"]["enum"],
+            serde_json::json!(["none", "lz4", "zstd"])
+        );
+        assert!(backup.get("encryption").is_none());
+    }
 }
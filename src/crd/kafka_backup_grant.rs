@@ -0,0 +1,48 @@
+use kube::CustomResource;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::kafka_restore::BackupRefKind;
+
+/// KafkaBackupGrant allows KafkaRestores in other namespaces to reference
+/// KafkaBackups and KafkaBackupSnapshots in the grant's namespace, in the
+/// manner of the Gateway API ReferenceGrant. Without a grant, a backupRef may
+/// only point at resources in the KafkaRestore's own namespace.
+#[derive(CustomResource, Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[kube(
+    group = "kafkabackup.com",
+    version = "v1alpha1",
+    kind = "KafkaBackupGrant",
+    plural = "kafkabackupgrants",
+    shortname = "kbg",
+    namespaced,
+    printcolumn = r#"{"name":"Age","type":"date","jsonPath":".metadata.creationTimestamp"}"#
+)]
+#[serde(rename_all = "camelCase")]
+pub struct KafkaBackupGrantSpec {
+    /// Namespaces whose KafkaRestores may reference resources in this namespace
+    pub from: Vec<KafkaBackupGrantFrom>,
+
+    /// Resources in this namespace that may be referenced
+    pub to: Vec<KafkaBackupGrantTo>,
+}
+
+/// A namespace allowed to reference resources through a KafkaBackupGrant
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct KafkaBackupGrantFrom {
+    /// Namespace of the referencing KafkaRestores
+    pub namespace: String,
+}
+
+/// A resource that may be referenced through a KafkaBackupGrant
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct KafkaBackupGrantTo {
+    /// Kind of the referenced resource: KafkaBackup or KafkaBackupSnapshot
+    pub kind: BackupRefKind,
+
+    /// Name of the referenced resource; every resource of the kind when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
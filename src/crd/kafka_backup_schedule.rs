@@ -0,0 +1,133 @@
+use chrono::{DateTime, Utc};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
+use kube::CustomResource;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::common::{Condition, PodMetadata};
+use super::kafka_backup::{KafkaBackupSpec, ScheduleSpec};
+
+/// KafkaBackupSchedule applies one backup policy to every Strimzi Kafka cluster
+/// in its namespace that matches a label selector. The operator creates and
+/// garbage-collects one owned KafkaBackup per matched cluster.
+#[derive(CustomResource, Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[kube(
+    group = "kafkabackup.com",
+    version = "v1alpha1",
+    kind = "KafkaBackupSchedule",
+    plural = "kafkabackupschedules",
+    shortname = "kbsc",
+    status = "KafkaBackupScheduleStatus",
+    namespaced,
+    printcolumn = r#"{"name":"Schedule","type":"string","jsonPath":".spec.schedule.cron"}"#,
+    printcolumn = r#"{"name":"Clusters","type":"string","jsonPath":".status.readyClusters"}"#,
+    printcolumn = r#"{"name":"Ready","type":"string","jsonPath":".status.conditions[?(@.type==\"Ready\")].status"}"#,
+    printcolumn = r#"{"name":"Age","type":"date","jsonPath":".metadata.creationTimestamp"}"#
+)]
+#[serde(rename_all = "camelCase")]
+pub struct KafkaBackupScheduleSpec {
+    /// Label selector over Strimzi Kafka CRs in this namespace. An empty
+    /// selector matches every Kafka cluster in the namespace.
+    #[serde(default)]
+    pub cluster_selector: LabelSelector,
+
+    /// Template for the KafkaBackup created for each matched cluster
+    pub backup_template: KafkaBackupTemplate,
+
+    /// Cron schedule applied to every generated KafkaBackup. Overrides
+    /// backupTemplate.spec.schedule when set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<ScheduleSpec>,
+}
+
+/// Template for generated KafkaBackup resources
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct KafkaBackupTemplate {
+    /// Additional labels and annotations for generated KafkaBackups
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<PodMetadata>,
+
+    /// KafkaBackup spec applied to each matched cluster.
+    /// `strimziClusterRef.name` and `strimziClusterRef.namespace` are set by the
+    /// operator and may be omitted; `caSecret` and `listener` are kept.
+    #[schemars(schema_with = "backup_template_spec_schema")]
+    pub spec: serde_json::Value,
+}
+
+/// Schema helper: the KafkaBackup spec schema without a required
+/// `strimziClusterRef`, which the operator fills in per matched cluster.
+fn backup_template_spec_schema(
+    gen: &mut schemars::gen::SchemaGenerator,
+) -> schemars::schema::Schema {
+    let mut schema = gen.subschema_for::<KafkaBackupSpec>();
+    if let schemars::schema::Schema::Object(object) = &mut schema {
+        if let Some(validation) = object.object.as_mut() {
+            validation.required.remove("strimziClusterRef");
+        }
+    }
+    schema
+}
+
+/// Status of a KafkaBackupSchedule resource (follows Strimzi conventions)
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct KafkaBackupScheduleStatus {
+    /// Strimzi-convention status conditions
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conditions: Vec<Condition>,
+
+    /// Per-cluster state of the generated KafkaBackups
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub clusters: Vec<ScheduledClusterStatus>,
+
+    /// Ready clusters out of matched clusters, e.g. "2/3"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ready_clusters: Option<String>,
+
+    /// Generation observed by the operator
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observed_generation: Option<i64>,
+}
+
+/// State of the KafkaBackup generated for one matched cluster
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledClusterStatus {
+    /// Name of the matched Strimzi Kafka CR
+    pub cluster: String,
+    /// Name of the generated KafkaBackup
+    pub backup_name: String,
+    /// Whether the generated KafkaBackup reports Ready=True
+    pub ready: bool,
+    /// Reason of the generated KafkaBackup's Ready condition
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Completion time of the generated KafkaBackup's last backup
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_backup_time: Option<DateTime<Utc>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use kube::CustomResourceExt;
+
+    use super::KafkaBackupSchedule;
+
+    #[test]
+    fn crd_template_does_not_require_cluster_ref() {
+        let crd = serde_json::to_value(KafkaBackupSchedule::crd()).expect("CRD should serialize");
+        let template = crd
+            .pointer(
+                "/spec/versions/0/schema/openAPIV3Schema/properties/spec/properties/backupTemplate/properties/spec",
+            )
+            .expect("template spec schema should be inlined");
+
+        let required: Vec<&str> = template["required"]
+            .as_array()
+            .map(|required| required.iter().filter_map(|v| v.as_str()).collect())
+            .unwrap_or_default();
+        assert!(template["properties"]["strimziClusterRef"].is_object());
+        assert!(!required.contains(&"strimziClusterRef"));
+    }
+}
@@ -0,0 +1,63 @@
+use chrono::{DateTime, Utc};
+use kube::CustomResource;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::common::Condition;
+
+/// KafkaBackupSnapshot records one backup found in a KafkaBackup's storage.
+/// The operator creates one per discovered manifest; deleting a snapshot
+/// prunes the backup from storage.
+#[derive(CustomResource, Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[kube(
+    group = "kafkabackup.com",
+    version = "v1alpha1",
+    kind = "KafkaBackupSnapshot",
+    plural = "kafkabackupsnapshots",
+    shortname = "kbs",
+    status = "KafkaBackupSnapshotStatus",
+    namespaced,
+    printcolumn = r#"{"name":"Backup","type":"string","jsonPath":".spec.backupName"}"#,
+    printcolumn = r#"{"name":"Backup ID","type":"string","jsonPath":".spec.backupId"}"#,
+    printcolumn = r#"{"name":"Topics","type":"integer","jsonPath":".spec.topics"}"#,
+    printcolumn = r#"{"name":"Size","type":"integer","jsonPath":".spec.sizeBytes"}"#,
+    printcolumn = r#"{"name":"Started","type":"date","jsonPath":".spec.startTime"}"#,
+    printcolumn = r#"{"name":"Age","type":"date","jsonPath":".metadata.creationTimestamp"}"#
+)]
+#[serde(rename_all = "camelCase")]
+pub struct KafkaBackupSnapshotSpec {
+    /// Name of the KafkaBackup whose storage holds this backup
+    pub backup_name: String,
+
+    /// Backup ID (the manifest's directory in storage)
+    pub backup_id: String,
+
+    /// Time the backup started
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<DateTime<Utc>>,
+
+    /// Time the backup completed, when recorded by the operator
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completion_time: Option<DateTime<Utc>>,
+
+    /// Number of topics in the backup
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topics: Option<i32>,
+
+    /// Number of partitions in the backup
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partitions: Option<i32>,
+
+    /// Compressed size of the backup in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size_bytes: Option<i64>,
+}
+
+/// Status of a KafkaBackupSnapshot resource (follows Strimzi conventions)
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct KafkaBackupSnapshotStatus {
+    /// Strimzi-convention status conditions
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conditions: Vec<Condition>,
+}
@@ -0,0 +1,150 @@
+use chrono::{DateTime, Utc};
+use kube::CustomResource;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::common::{
+    AuthenticationSpec, Condition, ResourceRequirementsSpec, StrimziClusterRef, TopicSelection,
+};
+use super::kafka_backup::ScheduleSpec;
+
+/// KafkaBackupVerification periodically test-restores the latest backup of a
+/// KafkaBackup. Each run creates a KafkaRestore, either in dry-run mode or into
+/// a scratch cluster under prefixed topic names, and records the outcome as a
+/// `Verified` condition on the KafkaBackup.
+#[derive(CustomResource, Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[kube(
+    group = "kafkabackup.com",
+    version = "v1alpha1",
+    kind = "KafkaBackupVerification",
+    plural = "kafkabackupverifications",
+    shortname = "kbv",
+    status = "KafkaBackupVerificationStatus",
+    namespaced,
+    printcolumn = r#"{"name":"Backup","type":"string","jsonPath":".spec.backupName"}"#,
+    printcolumn = r#"{"name":"Schedule","type":"string","jsonPath":".spec.schedule.cron"}"#,
+    printcolumn = r#"{"name":"Last Result","type":"string","jsonPath":".status.lastVerification.result"}"#,
+    printcolumn = r#"{"name":"Last Verified","type":"date","jsonPath":".status.lastVerification.completionTime"}"#,
+    printcolumn = r#"{"name":"Ready","type":"string","jsonPath":".status.conditions[?(@.type==\"Ready\")].status"}"#,
+    printcolumn = r#"{"name":"Age","type":"date","jsonPath":".metadata.creationTimestamp"}"#
+)]
+#[serde(rename_all = "camelCase")]
+pub struct KafkaBackupVerificationSpec {
+    /// Name of the KafkaBackup in this namespace whose latest backup is verified
+    pub backup_name: String,
+
+    /// Cron schedule for verification runs
+    pub schedule: ScheduleSpec,
+
+    /// How backups are verified: DryRun (default) reads and validates the
+    /// backup without writing records; Scratch restores it into
+    /// strimziClusterRef under prefixed topic names that are deleted afterwards
+    #[serde(default)]
+    pub mode: VerificationMode,
+
+    /// Cluster to restore into. Required in Scratch mode; defaults to the
+    /// KafkaBackup's cluster in DryRun mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strimzi_cluster_ref: Option<StrimziClusterRef>,
+
+    /// Authentication for strimziClusterRef. Defaults to the KafkaBackup's
+    /// authentication when strimziClusterRef is omitted.
+    #[schemars(schema_with = "super::cel::validated::<Option<AuthenticationSpec>>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authentication: Option<AuthenticationSpec>,
+
+    /// Prefix for restored topic names in Scratch mode (default: "verify-")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topic_prefix: Option<String>,
+
+    /// Topics to verify, selected by include/exclude glob (or `~`-prefixed
+    /// regex) patterns. All topics in the backup are verified when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topics: Option<TopicSelection>,
+
+    /// Resource requirements for restore pods
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<ResourceRequirementsSpec>,
+
+    /// Container image for the restore job (default: osodevops/kafka-backup:v0.15.12)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+}
+
+/// How a KafkaBackupVerification restores backups
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq)]
+pub enum VerificationMode {
+    #[default]
+    DryRun,
+    Scratch,
+}
+
+/// Status of a KafkaBackupVerification resource (follows Strimzi conventions)
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct KafkaBackupVerificationStatus {
+    /// Strimzi-convention status conditions
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conditions: Vec<Condition>,
+
+    /// Verification run in progress
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_run: Option<VerificationRun>,
+
+    /// Outcome of the last completed verification run
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_verification: Option<VerificationResult>,
+
+    /// Time the last verification run was due
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_schedule_time: Option<DateTime<Utc>>,
+
+    /// Time of the next verification run
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_scheduled_verification: Option<DateTime<Utc>>,
+
+    /// Generation observed by the operator
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observed_generation: Option<i64>,
+}
+
+/// A verification run whose KafkaRestore has not finished yet
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationRun {
+    /// ID of the backup being restored
+    pub backup_id: String,
+    /// Name of the KafkaRestore performing the restore
+    pub restore_name: String,
+    /// Time the run started
+    pub start_time: DateTime<Utc>,
+    /// Namespace of the KafkaTopics created for a Scratch-mode run
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topics_namespace: Option<String>,
+}
+
+/// Outcome of a completed verification run
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationResult {
+    /// ID of the backup that was restored
+    pub backup_id: String,
+    /// Name of the KafkaRestore that performed the restore
+    pub restore_name: String,
+    /// Whether the restore succeeded
+    pub result: VerificationOutcome,
+    /// Time the run started
+    pub start_time: DateTime<Utc>,
+    /// Time the run finished
+    pub completion_time: DateTime<Utc>,
+    /// Failure details
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Result of a verification run
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq)]
+pub enum VerificationOutcome {
+    Passed,
+    Failed,
+}
@@ -1,10 +1,15 @@
-use kube::CustomResource;
+use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::{
+    CustomResourceDefinition, ValidationRule,
+};
+use kube::core::crd::merge_crds;
+use kube::{CustomResource, CustomResourceExt};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::common::{
-    AuthenticationSpec, Condition, KafkaConnectionSpec, LoggingSpec, MetricsSpec, PodTemplateSpec,
-    ResourceRequirementsSpec, RestoreInfo, StrimziClusterRef, TopicSelection,
+    AuthenticationSpec, Condition, ConnectSelection, KafkaConnectionSpec, LoggingSpec, MetricsSpec,
+    PodTemplateSpec, ResourceRequirementsSpec, RestoreInfo, SchemaRegistrySpec, StorageLocationRef,
+    StorageSpec, StrimziClusterRef, TopicSelection, UserSelection,
 };
 
 /// KafkaRestore defines a restore operation from a KafkaBackup to a Strimzi-managed Kafka cluster.
@@ -29,7 +34,8 @@ pub struct KafkaRestoreSpec {
     pub strimzi_cluster_ref: StrimziClusterRef,
 
     /// Authentication for the target cluster
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(schema_with = "super::cel::validated::<Option<AuthenticationSpec>>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub authentication: Option<AuthenticationSpec>,
 
     /// Reference to the source backup
@@ -42,7 +48,8 @@ pub struct KafkaRestoreSpec {
     pub topics: Option<TopicSelection>,
 
     /// Point-in-time recovery settings
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(schema_with = "super::cel::validated::<Option<PointInTimeSpec>>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub point_in_time: Option<PointInTimeSpec>,
 
     /// Kafka connection tuning for the target cluster
@@ -59,6 +66,10 @@ pub struct KafkaRestoreSpec {
     pub env: Vec<serde_json::Value>,
 
     /// Topic mapping for renaming topics during restore
+    #[schemars(
+        schema_with = "super::cel::validated::<Vec<TopicMappingEntry>>",
+        length(max = 256)
+    )]
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub topic_mapping: Vec<TopicMappingEntry>,
 
@@ -66,6 +77,26 @@ pub struct KafkaRestoreSpec {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub consumer_groups: Option<ConsumerGroupRestoreSpec>,
 
+    /// KafkaUsers stored with the backup to recreate in the target cluster's
+    /// namespace, selected by name. Topic ACLs are renamed by topicMapping.
+    /// Users are not restored when omitted; `{}` selects all of them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub users: Option<UserSelection>,
+
+    /// KafkaConnect clusters stored with the backup to recreate in the target
+    /// cluster's namespace, selected by name, once the data restore has
+    /// succeeded. Their KafkaConnectors are created paused. Connect resources
+    /// are not restored when omitted; `{}` selects all of them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connect: Option<ConnectSelection>,
+
+    /// Schema Registry to import the subjects stored with the backup into.
+    /// Subjects named after a topic (`<topic>-key`, `<topic>-value`) are
+    /// renamed by topicMapping. Schema IDs are preserved for subjects in
+    /// IMPORT mode. Schemas are not restored when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_registry: Option<SchemaRegistrySpec>,
+
     /// Restore behaviour options
     #[serde(skip_serializing_if = "Option::is_none")]
     pub restore: Option<RestoreOptionsSpec>,
@@ -96,15 +127,45 @@ pub struct KafkaRestoreSpec {
     pub backoff_limit: Option<i32>,
 }
 
-/// Reference to a KafkaBackup CR and optional specific backup snapshot
+/// Reference to the backup to restore: a KafkaBackup or KafkaBackupSnapshot
+/// CR, or a storage destination plus backup ID when no CR exists
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct BackupRef {
-    /// Name of the KafkaBackup CR
-    pub name: String,
-    /// Specific backup ID to restore from (latest if omitted)
+    /// Kind of the referenced resource: KafkaBackup (default) or KafkaBackupSnapshot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<BackupRefKind>,
+    /// Name of the KafkaBackup CR, or of the KafkaBackupSnapshot when kind is
+    /// KafkaBackupSnapshot. Required unless storage or storageRef is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Namespace of the referenced resource (defaults to the KafkaRestore's
+    /// namespace). Another namespace must allow the reference with a
+    /// KafkaBackupGrant; storage credentials are then copied into the
+    /// KafkaRestore's namespace for the restore Job.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    /// Specific backup ID to restore from (latest if omitted). Must be omitted
+    /// when kind is KafkaBackupSnapshot, which names the backup itself, and is
+    /// required with storage or storageRef.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub backup_id: Option<String>,
+    /// Storage holding the backup, for restoring without a KafkaBackup CR.
+    /// Secret references resolve in the KafkaRestore's namespace.
+    #[schemars(schema_with = "super::cel::validated::<Option<StorageSpec>>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage: Option<StorageSpec>,
+    /// Reference to a BackupStorageLocation or ClusterBackupStorageLocation
+    /// holding the backup, for restoring without a KafkaBackup CR
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_ref: Option<StorageLocationRef>,
+}
+
+/// Kind of resource a BackupRef points at
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq)]
+pub enum BackupRefKind {
+    KafkaBackup,
+    KafkaBackupSnapshot,
 }
 
 /// Point-in-time recovery specification
@@ -129,6 +190,7 @@ pub struct TopicMappingEntry {
     /// Source topic name in the backup
     pub source_topic: String,
     /// Target topic name in the destination cluster
+    #[schemars(length(max = 249))]
     pub target_topic: String,
 }
 
@@ -167,10 +229,12 @@ pub struct ConsumerGroupMapping {
 }
 
 /// Restore behaviour options
-#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct RestoreOptionsSpec {
-    /// Topic creation strategy: auto or manual
+    /// Topic creation strategy: auto, manual, or strimzi. strimzi recreates the
+    /// KafkaTopics stored with the backup on the target cluster, renamed by
+    /// topicMapping, and starts the restore once they are Ready.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub topic_creation: Option<TopicCreationPolicy>,
     /// Policy for existing topics: append or overwrite. fail is accepted for legacy CRs but rejected at runtime.
@@ -212,12 +276,19 @@ pub struct RestoreOptionsSpec {
     /// Checkpoint interval in seconds
     #[serde(skip_serializing_if = "Option::is_none")]
     pub checkpoint_interval_secs: Option<u64>,
-    /// Replication factor for auto-created topics
+    /// Replication factor for auto-created topics, and for KafkaTopics
+    /// recreated with topicCreation strimzi
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_replication_factor: Option<i16>,
     /// Per-topic repartitioning configuration
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub repartitioning: Vec<TopicRepartitioningSpec>,
+    /// Rebuild the target cluster when its Kafka CR does not exist: recreate
+    /// the Kafka CR and KafkaNodePools stored with the backup as
+    /// strimziClusterRef, and restore once the cluster is Ready. An existing
+    /// Kafka CR is never modified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_cluster: Option<bool>,
     /// Purge target topics before restoring
     #[serde(skip_serializing_if = "Option::is_none")]
     pub purge_topics: Option<bool>,
@@ -251,6 +322,7 @@ pub struct TopicRepartitioningSpec {
 pub enum TopicCreationPolicy {
     Auto,
     Manual,
+    Strimzi,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq)]
@@ -261,6 +333,45 @@ pub enum ExistingTopicPolicy {
     Overwrite,
 }
 
+impl KafkaRestore {
+    /// Whether the restore Job has been created, recorded as `status.restore`
+    pub fn has_started(&self) -> bool {
+        self.status.as_ref().is_some_and(|s| s.restore.is_some())
+    }
+}
+
+/// The KafkaRestore CRD serving every version, with its object-level
+/// validation: the spec cannot be changed once the restore has started, since
+/// the running Job would not pick up the change.
+pub fn kafka_restore_crd() -> CustomResourceDefinition {
+    let mut crd = merge_crds(
+        vec![KafkaRestore::crd(), super::v1beta1::KafkaRestore::crd()],
+        super::STORAGE_VERSION,
+    )
+    .expect("KafkaRestore versions share their names and scope");
+    for version in &mut crd.spec.versions {
+        if let Some(schema) = version
+            .schema
+            .as_mut()
+            .and_then(|s| s.open_api_v3_schema.as_mut())
+        {
+            schema
+                .x_kubernetes_validations
+                .get_or_insert_with(Vec::new)
+                .push(ValidationRule {
+                    rule: "!has(oldSelf.status) || !has(oldSelf.status.restore) || self.spec == oldSelf.spec"
+                        .to_string(),
+                    message: Some(
+                        "spec is immutable once the restore has started; create a new KafkaRestore instead"
+                            .to_string(),
+                    ),
+                    ..Default::default()
+                });
+        }
+    }
+    crd
+}
+
 /// Status of a KafkaRestore resource (follows Strimzi conventions)
 #[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
@@ -276,4 +387,47 @@ pub struct KafkaRestoreStatus {
     /// Generation observed by the operator
     #[serde(skip_serializing_if = "Option::is_none")]
     pub observed_generation: Option<i64>,
+
+    /// Whether the KafkaConnect clusters stored with the backup have been
+    /// recreated after the data restore
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kafka_connects_restored: Option<bool>,
+
+    /// Whether the Schema Registry subjects stored with the backup have been
+    /// imported
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schemas_imported: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::kafka_restore_crd;
+
+    #[test]
+    fn crd_freezes_spec_once_started() {
+        let crd = serde_json::to_value(kafka_restore_crd()).expect("CRD should serialize");
+        let rules = crd
+            .pointer("/spec/versions/0/schema/openAPIV3Schema/x-kubernetes-validations")
+            .and_then(|rules| rules.as_array())
+            .expect("the root schema must carry validation rules");
+        assert!(rules.iter().any(|r| r["rule"]
+            .as_str()
+            .is_some_and(|rule| rule.contains("self.spec == oldSelf.spec"))));
+    }
+
+    #[test]
+    fn crd_bounds_topic_mapping_rule_cost() {
+        let crd = serde_json::to_value(kafka_restore_crd()).expect("CRD should serialize");
+        let mapping = crd
+            .pointer(
+                "/spec/versions/0/schema/openAPIV3Schema/properties/spec/properties/topicMapping",
+            )
+            .expect("topicMapping must be in the schema");
+        assert_eq!(mapping["maxItems"], 256);
+        assert_eq!(
+            mapping["items"]["properties"]["targetTopic"]["maxLength"],
+            249
+        );
+        assert!(mapping["x-kubernetes-validations"].is_array());
+    }
 }
@@ -1,7 +1,23 @@
+pub mod backup_storage_location;
+pub mod cel;
 pub mod common;
 pub mod kafka_backup;
+pub mod kafka_backup_grant;
+pub mod kafka_backup_schedule;
+pub mod kafka_backup_snapshot;
+pub mod kafka_backup_verification;
 pub mod kafka_restore;
+pub mod v1beta1;
 
+/// Version of KafkaBackup and KafkaRestore stored in etcd and read by the
+/// operator; the other served versions are converted by the conversion webhook
+pub const STORAGE_VERSION: &str = "v1alpha1";
+
+pub use backup_storage_location::*;
 pub use common::*;
 pub use kafka_backup::*;
+pub use kafka_backup_grant::*;
+pub use kafka_backup_schedule::*;
+pub use kafka_backup_snapshot::*;
+pub use kafka_backup_verification::*;
 pub use kafka_restore::*;
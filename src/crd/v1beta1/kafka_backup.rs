@@ -0,0 +1,213 @@
+use kube::CustomResource;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::crd::common::{
+    AuthenticationSpec, ConnectSelection, ConsumerGroupSelection, KafkaConnectionSpec, LoggingSpec,
+    MetricsSpec, OffsetStorageSpec, PodTemplateSpec, ResourceRequirementsSpec, SchemaRegistrySpec,
+    StorageLocationRef, StorageSpec, StrimziClusterRef, TopicSelection, UserSelection,
+};
+use crate::crd::kafka_backup::{KafkaBackupStatus, RetentionSpec, ScheduleSpec};
+
+/// KafkaBackup defines a backup configuration for a Strimzi-managed Kafka cluster.
+/// The operator creates Kubernetes Jobs that run the kafka-backup CLI to perform backups.
+#[derive(CustomResource, Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[kube(
+    group = "kafkabackup.com",
+    version = "v1beta1",
+    kind = "KafkaBackup",
+    plural = "kafkabackups",
+    shortname = "kb",
+    status = "KafkaBackupStatus",
+    namespaced,
+    printcolumn = r#"{"name":"Cluster","type":"string","jsonPath":".spec.strimziClusterRef.name"}"#,
+    printcolumn = r#"{"name":"Schedule","type":"string","jsonPath":".spec.schedule.cron"}"#,
+    printcolumn = r#"{"name":"Last Backup","type":"date","jsonPath":".status.lastBackup.completionTime"}"#,
+    printcolumn = r#"{"name":"Last Schedule","type":"date","jsonPath":".status.lastScheduleTime"}"#,
+    printcolumn = r#"{"name":"Next Backup","type":"string","jsonPath":".status.nextScheduledBackup"}"#,
+    printcolumn = r#"{"name":"Ready","type":"string","jsonPath":".status.conditions[?(@.type==\"Ready\")].status"}"#,
+    printcolumn = r#"{"name":"Age","type":"date","jsonPath":".metadata.creationTimestamp"}"#
+)]
+#[serde(rename_all = "camelCase")]
+pub struct KafkaBackupSpec {
+    /// Reference to the Strimzi Kafka cluster CR
+    pub strimzi_cluster_ref: StrimziClusterRef,
+
+    /// Authentication configuration for connecting to the Kafka cluster
+    #[schemars(schema_with = "crate::crd::cel::validated::<Option<AuthenticationSpec>>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authentication: Option<AuthenticationSpec>,
+
+    /// Topic selection with include/exclude glob patterns
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topics: Option<TopicSelection>,
+
+    /// Kafka connection tuning for the source cluster
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection: Option<KafkaConnectionSpec>,
+
+    /// Consumer group selection
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consumer_groups: Option<ConsumerGroupSelection>,
+
+    /// KafkaUsers of the cluster to back up with their ACLs and quotas,
+    /// selected by name. Users are not backed up when omitted; `{}` selects
+    /// all of them. Credentials are never stored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub users: Option<UserSelection>,
+
+    /// KafkaConnect clusters whose bootstrapServers point at the cluster, to
+    /// back up with their KafkaConnectors, selected by name. Their offset,
+    /// config and status topics are added to the backed-up topics. Connect
+    /// resources are not backed up when omitted; `{}` selects all of them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connect: Option<ConnectSelection>,
+
+    /// Schema Registry to export subjects from, with their schema versions,
+    /// compatibility levels and modes. Schemas are not backed up when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_registry: Option<SchemaRegistrySpec>,
+
+    /// Logging configuration for backup job pods
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logging: Option<LoggingSpec>,
+
+    /// Additional environment variables for backup job pods
+    #[schemars(schema_with = "crate::crd::common::free_form_object_array")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub env: Vec<serde_json::Value>,
+
+    /// Storage destination configuration. Exactly one of storage or storageRef must be set.
+    #[schemars(schema_with = "crate::crd::cel::validated::<Option<StorageSpec>>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage: Option<StorageSpec>,
+
+    /// Reference to a BackupStorageLocation or ClusterBackupStorageLocation
+    /// holding the storage destination, instead of an inline storage block
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_ref: Option<StorageLocationRef>,
+
+    /// Backup options (compression, parallelism, checkpointing)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backup: Option<BackupOptionsSpec>,
+
+    /// Metrics configuration for backup job pods
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<MetricsSpec>,
+
+    /// Offset storage configuration for continuous backup progress
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset_storage: Option<OffsetStorageSpec>,
+
+    /// Cron schedule configuration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<ScheduleSpec>,
+
+    /// Retention policy for managing old backups
+    #[schemars(schema_with = "crate::crd::cel::validated::<Option<RetentionSpec>>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retention: Option<RetentionSpec>,
+
+    /// Resource requirements for backup pods
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<ResourceRequirementsSpec>,
+
+    /// Template for customizing backup pods
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<PodTemplateSpec>,
+
+    /// Container image for the backup job (default: osodevops/kafka-backup:v0.15.12)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+
+    /// Number of pod retries before a backup Job is marked failed
+    /// (`spec.backoffLimit` on generated Jobs, including scheduled CronJob
+    /// runs). Defaults to 3.
+    #[schemars(range(min = 0))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backoff_limit: Option<i32>,
+}
+
+/// Backup-specific options
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupOptionsSpec {
+    /// Compression algorithm
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compression: Option<Compression>,
+
+    /// Compression level (zstd: 1-22)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compression_level: Option<i32>,
+
+    /// Maximum segment file size in bytes before rotation (default: 256MB)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub segment_size: Option<i64>,
+
+    /// Maximum segment time interval in milliseconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub segment_max_interval_ms: Option<u64>,
+
+    /// Number of concurrent partition backup threads (default: 4)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parallelism: Option<i32>,
+
+    /// Starting offset for backups
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_offset: Option<StartOffset>,
+
+    /// Run continuously instead of one-shot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub continuous: Option<bool>,
+
+    /// Include internal Kafka topics
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_internal_topics: Option<bool>,
+
+    /// Internal topics to include
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub internal_topics: Vec<String>,
+
+    /// Checkpoint interval in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checkpoint_interval_secs: Option<u64>,
+
+    /// Remote offset database sync interval in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_interval_secs: Option<u64>,
+
+    /// Include original Kafka offset headers in backup records
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_offset_headers: Option<bool>,
+
+    /// Source cluster identifier written to record headers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_cluster_id: Option<String>,
+
+    /// Snapshot current high watermarks and exit when caught up
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_at_current_offsets: Option<bool>,
+
+    /// Continuous-mode poll interval in milliseconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poll_interval_ms: Option<u64>,
+
+    /// Snapshot consumer group offsets after each backup cycle
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consumer_group_snapshot: Option<bool>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Compression {
+    None,
+    Lz4,
+    Zstd,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum StartOffset {
+    Earliest,
+    Latest,
+}
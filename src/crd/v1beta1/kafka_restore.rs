@@ -0,0 +1,267 @@
+use kube::CustomResource;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::crd::common::{
+    AuthenticationSpec, ConnectSelection, KafkaConnectionSpec, LoggingSpec, MetricsSpec,
+    PodTemplateSpec, ResourceRequirementsSpec, SchemaRegistrySpec, StrimziClusterRef,
+    TopicSelection, UserSelection,
+};
+use crate::crd::kafka_restore::{
+    BackupRef, ConsumerGroupMapping, KafkaRestoreStatus, PartitionMappingEntry,
+    TopicCreationPolicy, TopicMappingEntry,
+};
+
+/// KafkaRestore defines a restore operation from a KafkaBackup to a Strimzi-managed Kafka cluster.
+/// Supports point-in-time recovery, topic mapping, and consumer group offset restore.
+#[derive(CustomResource, Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[kube(
+    group = "kafkabackup.com",
+    version = "v1beta1",
+    kind = "KafkaRestore",
+    plural = "kafkarestores",
+    shortname = "kr",
+    status = "KafkaRestoreStatus",
+    namespaced,
+    printcolumn = r#"{"name":"Cluster","type":"string","jsonPath":".spec.strimziClusterRef.name"}"#,
+    printcolumn = r#"{"name":"Backup","type":"string","jsonPath":".spec.backupRef.name"}"#,
+    printcolumn = r#"{"name":"Status","type":"string","jsonPath":".status.conditions[?(@.type==\"Ready\")].reason"}"#,
+    printcolumn = r#"{"name":"Age","type":"date","jsonPath":".metadata.creationTimestamp"}"#
+)]
+#[serde(rename_all = "camelCase")]
+pub struct KafkaRestoreSpec {
+    /// Reference to the target Strimzi Kafka cluster CR
+    pub strimzi_cluster_ref: StrimziClusterRef,
+
+    /// Authentication for the target cluster
+    #[schemars(schema_with = "crate::crd::cel::validated::<Option<AuthenticationSpec>>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authentication: Option<AuthenticationSpec>,
+
+    /// Reference to the source backup
+    pub backup_ref: BackupRef,
+
+    /// Topics to restore, selected by include/exclude glob (or `~`-prefixed regex)
+    /// patterns matched against source topic names in the backup. All topics are
+    /// restored when omitted. Filtering is applied before topicMapping renames.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topics: Option<TopicSelection>,
+
+    /// Point-in-time recovery settings
+    #[schemars(schema_with = "crate::crd::cel::validated::<Option<PointInTimeSpec>>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub point_in_time: Option<PointInTimeSpec>,
+
+    /// Kafka connection tuning for the target cluster
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection: Option<KafkaConnectionSpec>,
+
+    /// Logging configuration for restore job pods
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logging: Option<LoggingSpec>,
+
+    /// Additional environment variables for restore job pods
+    #[schemars(schema_with = "crate::crd::common::free_form_object_array")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub env: Vec<serde_json::Value>,
+
+    /// Topic mapping for renaming topics during restore
+    #[schemars(
+        schema_with = "crate::crd::cel::validated::<Vec<TopicMappingEntry>>",
+        length(max = 256)
+    )]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub topic_mapping: Vec<TopicMappingEntry>,
+
+    /// Consumer group restore configuration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consumer_groups: Option<ConsumerGroupRestoreSpec>,
+
+    /// KafkaUsers stored with the backup to recreate in the target cluster's
+    /// namespace, selected by name. Topic ACLs are renamed by topicMapping.
+    /// Users are not restored when omitted; `{}` selects all of them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub users: Option<UserSelection>,
+
+    /// KafkaConnect clusters stored with the backup to recreate in the target
+    /// cluster's namespace, selected by name. Their KafkaConnectors are
+    /// created paused. Connect resources are not restored when omitted; `{}`
+    /// selects all of them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connect: Option<ConnectSelection>,
+
+    /// Schema Registry to import the subjects stored with the backup into.
+    /// Subjects named after a topic (`<topic>-key`, `<topic>-value`) are
+    /// renamed by topicMapping. Schema IDs are preserved for subjects in
+    /// IMPORT mode. Schemas are not restored when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_registry: Option<SchemaRegistrySpec>,
+
+    /// Restore behaviour options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restore: Option<RestoreOptionsSpec>,
+
+    /// Metrics configuration for restore job pods
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<MetricsSpec>,
+
+    /// Resource requirements for restore pods
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<ResourceRequirementsSpec>,
+
+    /// Template for customizing restore pods
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<PodTemplateSpec>,
+
+    /// Container image for the restore job (default: osodevops/kafka-backup:v0.15.12)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+
+    /// Number of pod retries before the restore Job is marked failed
+    /// (`spec.backoffLimit` on the generated Job). Defaults to 0 so a restore
+    /// runs exactly once: restores append to or purge target topics, so a
+    /// retry of a partially completed attempt can duplicate data and must be
+    /// opted into deliberately.
+    #[schemars(range(min = 0))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backoff_limit: Option<i32>,
+}
+
+/// Point-in-time recovery specification
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PointInTimeSpec {
+    /// Start timestamp for the restore window (ISO 8601 with millisecond precision)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_timestamp: Option<String>,
+    /// Exact timestamp to restore to (ISO 8601 with millisecond precision)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<String>,
+}
+
+/// Consumer group restore configuration
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsumerGroupRestoreSpec {
+    /// Whether to restore consumer group offsets
+    #[serde(default)]
+    pub restore: bool,
+    /// Automatically load the consumer group snapshot from backup storage
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto: Option<bool>,
+    /// Consumer groups to reset
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub groups: Vec<String>,
+    /// Offset handling strategy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy: Option<OffsetStrategy>,
+    /// Output file path for offset mapping report
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset_report: Option<String>,
+    /// Consumer group mappings (source → target)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mapping: Vec<ConsumerGroupMapping>,
+}
+
+/// Restore behaviour options
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreOptionsSpec {
+    /// Topic creation strategy: auto, manual, or strimzi. strimzi recreates the
+    /// KafkaTopics stored with the backup on the target cluster, renamed by
+    /// topicMapping, and starts the restore once they are Ready.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topic_creation: Option<TopicCreationPolicy>,
+    /// Policy for existing topics
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub existing_topic_policy: Option<ExistingTopicPolicy>,
+    /// Dry-run mode: validate without writing records
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dry_run: Option<bool>,
+    /// Include original offsets as headers on restored records
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_original_offset_header: Option<bool>,
+    /// Source partitions to restore
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub source_partitions: Vec<i32>,
+    /// Partition mapping entries
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub partition_mapping: Vec<PartitionMappingEntry>,
+    /// Number of concurrent restore threads (default: 4)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parallelism: Option<i32>,
+    /// Rate limit in records per second
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_limit_records_per_sec: Option<u64>,
+    /// Rate limit in bytes per second
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_limit_bytes_per_sec: Option<u64>,
+    /// Produce batch size
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub produce_batch_size: Option<usize>,
+    /// Producer acks setting (-1, 0, or 1)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub produce_acks: Option<i16>,
+    /// Broker-side produce timeout in milliseconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub produce_timeout_ms: Option<i32>,
+    /// Checkpoint state file path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checkpoint_state: Option<String>,
+    /// Checkpoint interval in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checkpoint_interval_secs: Option<u64>,
+    /// Replication factor for auto-created topics, and for KafkaTopics
+    /// recreated with topicCreation strimzi
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_replication_factor: Option<i16>,
+    /// Per-topic repartitioning configuration
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub repartitioning: Vec<TopicRepartitioningSpec>,
+    /// Rebuild the target cluster when its Kafka CR does not exist: recreate
+    /// the Kafka CR and KafkaNodePools stored with the backup as
+    /// strimziClusterRef, and restore once the cluster is Ready. An existing
+    /// Kafka CR is never modified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_cluster: Option<bool>,
+    /// Purge target topics before restoring
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub purge_topics: Option<bool>,
+}
+
+/// Per-topic repartitioning configuration keyed by target topic name.
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TopicRepartitioningSpec {
+    /// Target topic name
+    pub topic: String,
+    /// Partitioning strategy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy: Option<PartitioningStrategy>,
+    /// Target partition count
+    pub target_partitions: i32,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ExistingTopicPolicy {
+    Append,
+    Overwrite,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum OffsetStrategy {
+    Skip,
+    HeaderBased,
+    TimestampBased,
+    ClusterScan,
+    Manual,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum PartitioningStrategy {
+    Murmur2,
+    Automatic,
+}
@@ -0,0 +1,10 @@
+//! `v1beta1` versions of KafkaBackup and KafkaRestore. They replace the
+//! free-form strings of `v1alpha1` with enums and drop the legacy fields the
+//! operator rejects at runtime. `v1alpha1` remains the storage version and the
+//! version the operator reads; the conversion webhook translates between them.
+
+pub mod kafka_backup;
+pub mod kafka_restore;
+
+pub use kafka_backup::*;
+pub use kafka_restore::*;
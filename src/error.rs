@@ -37,6 +37,17 @@ pub enum Error {
     #[error("Backup '{name}' not found")]
     BackupNotFound { name: String },
 
+    #[error("{kind} '{name}' in namespace '{namespace}' is not granted to namespace '{from}'; create a KafkaBackupGrant in '{namespace}'")]
+    BackupRefNotPermitted {
+        kind: String,
+        name: String,
+        namespace: String,
+        from: String,
+    },
+
+    #[error("Schema Registry error: {0}")]
+    SchemaRegistry(String),
+
     #[error("Job creation failed: {0}")]
     JobCreationFailed(String),
 
@@ -66,6 +77,8 @@ impl Error {
             Error::NoCompatibleListener { .. } => "NoCompatibleListener",
             Error::Storage(_) => "StorageError",
             Error::BackupNotFound { .. } => "BackupNotFound",
+            Error::BackupRefNotPermitted { .. } => "BackupRefNotPermitted",
+            Error::SchemaRegistry(_) => "SchemaRegistryError",
             Error::JobCreationFailed(_) => "JobCreationFailed",
             Error::Finalizer(_) => "FinalizerError",
             Error::MissingObjectKey(_) => "MissingObjectKey",
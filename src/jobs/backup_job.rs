@@ -3,11 +3,14 @@ use k8s_openapi::api::core::v1::{Container, PodSpec, PodTemplateSpec};
 use k8s_openapi::apimachinery::pkg::apis::meta::v1::{ObjectMeta, OwnerReference};
 use kube::ResourceExt;
 
+use crate::adapters::storage_location::backup_storage;
+use crate::config;
 use crate::crd::KafkaBackup;
 use crate::error::Result;
-use crate::reconcilers::DEFAULT_BACKUP_IMAGE;
+use crate::reconcilers::DEFAULT_BACKUP_BACKOFF_LIMIT;
 use crate::strimzi::kafka_cr::ResolvedKafkaCluster;
 use crate::strimzi::kafka_user::ResolvedAuth;
+use crate::telemetry;
 
 use super::templates::{
     add_metrics_discovery_label, append_env_overrides, apply_pod_template, build_annotations,
@@ -26,7 +29,8 @@ pub fn build_backup_job(
 ) -> Result<Job> {
     let cr_name = backup.name_any();
     let namespace = backup.namespace().unwrap_or_default();
-    let image = backup.spec.image.as_deref().unwrap_or(DEFAULT_BACKUP_IMAGE);
+    let defaults = config::current();
+    let image = backup.spec.image.as_deref().unwrap_or(&defaults.jobs.image);
 
     // Build labels
     let mut labels = build_labels(&cr_name, &cluster.name, "backup");
@@ -42,10 +46,11 @@ pub fn build_backup_job(
         &cluster.name,
         cluster.tls_enabled,
         auth,
-        &backup.spec.storage,
+        backup_storage(backup)?,
         backup.spec.strimzi_cluster_ref.ca_secret.as_ref(),
     );
     env.push(job_name_env_var("BACKUP_ID"));
+    env.extend(telemetry::traceparent_env_var());
 
     // Build container
     let mut container = Container {
@@ -60,7 +65,12 @@ pub fn build_backup_job(
         env: if env.is_empty() { None } else { Some(env) },
         ports: job_metrics_ports(backup.spec.metrics.as_ref()),
         volume_mounts: Some(volume_mounts),
-        resources: backup.spec.resources.as_ref().map(|r| r.to_k8s()),
+        resources: backup
+            .spec
+            .resources
+            .as_ref()
+            .or(defaults.jobs.resources.as_ref())
+            .map(|r| r.to_k8s()),
         ..Default::default()
     };
     append_env_overrides(&mut container, &backup.spec.env);
@@ -104,7 +114,12 @@ pub fn build_backup_job(
             ..Default::default()
         },
         spec: Some(JobSpec {
-            backoff_limit: Some(backup.spec.backoff_limit.unwrap_or(3)),
+            backoff_limit: Some(
+                backup
+                    .spec
+                    .backoff_limit
+                    .unwrap_or(DEFAULT_BACKUP_BACKOFF_LIMIT),
+            ),
             template: PodTemplateSpec {
                 metadata: Some(ObjectMeta {
                     labels: Some(pod_labels),
@@ -125,6 +140,7 @@ mod tests {
     use super::*;
     use crate::crd::common::*;
     use crate::crd::kafka_backup::*;
+    use crate::reconcilers::DEFAULT_BACKUP_IMAGE;
 
     #[test]
     fn test_build_backup_job_basic() {
@@ -139,9 +155,12 @@ mod tests {
             topics: None,
             connection: None,
             consumer_groups: None,
+            users: None,
+            connect: None,
+            schema_registry: None,
             logging: None,
             env: Vec::new(),
-            storage: StorageSpec {
+            storage: Some(StorageSpec {
                 storage_type: StorageType::S3,
                 s3: Some(S3StorageSpec {
                     bucket: "test-bucket".to_string(),
@@ -160,7 +179,8 @@ mod tests {
                 azure: None,
                 gcs: None,
                 filesystem: None,
-            },
+            }),
+            storage_ref: None,
             backup: None,
             metrics: None,
             offset_storage: None,
@@ -3,9 +3,11 @@ use k8s_openapi::api::core::v1::{Container, PodSpec, PodTemplateSpec};
 use k8s_openapi::apimachinery::pkg::apis::meta::v1::{ObjectMeta, OwnerReference};
 use kube::ResourceExt;
 
+use crate::adapters::storage_location::backup_storage;
+use crate::config;
 use crate::crd::KafkaBackup;
 use crate::error::Result;
-use crate::reconcilers::DEFAULT_BACKUP_IMAGE;
+use crate::reconcilers::{DEFAULT_BACKUP_BACKOFF_LIMIT, DEFAULT_JOBS_HISTORY_LIMIT};
 use crate::strimzi::kafka_cr::ResolvedKafkaCluster;
 use crate::strimzi::kafka_user::ResolvedAuth;
 
@@ -24,7 +26,8 @@ pub fn build_backup_cronjob(
 ) -> Result<CronJob> {
     let cr_name = backup.name_any();
     let namespace = backup.namespace().unwrap_or_default();
-    let image = backup.spec.image.as_deref().unwrap_or(DEFAULT_BACKUP_IMAGE);
+    let defaults = config::current();
+    let image = backup.spec.image.as_deref().unwrap_or(&defaults.jobs.image);
 
     let schedule = backup
         .spec
@@ -43,7 +46,7 @@ pub fn build_backup_cronjob(
         &cluster.name,
         cluster.tls_enabled,
         auth,
-        &backup.spec.storage,
+        backup_storage(backup)?,
         backup.spec.strimzi_cluster_ref.ca_secret.as_ref(),
     );
     env.push(job_name_env_var("BACKUP_ID"));
@@ -61,7 +64,12 @@ pub fn build_backup_cronjob(
         env: if env.is_empty() { None } else { Some(env) },
         ports: job_metrics_ports(backup.spec.metrics.as_ref()),
         volume_mounts: Some(volume_mounts),
-        resources: backup.spec.resources.as_ref().map(|r| r.to_k8s()),
+        resources: backup
+            .spec
+            .resources
+            .as_ref()
+            .or(defaults.jobs.resources.as_ref())
+            .map(|r| r.to_k8s()),
         ..Default::default()
     };
     append_env_overrides(&mut container, &backup.spec.env);
@@ -104,15 +112,28 @@ pub fn build_backup_cronjob(
             time_zone: schedule.timezone.clone(),
             suspend: Some(schedule.suspend),
             concurrency_policy: Some("Forbid".to_string()),
-            successful_jobs_history_limit: Some(3),
-            failed_jobs_history_limit: Some(3),
+            successful_jobs_history_limit: Some(
+                schedule
+                    .successful_jobs_history_limit
+                    .unwrap_or(DEFAULT_JOBS_HISTORY_LIMIT),
+            ),
+            failed_jobs_history_limit: Some(
+                schedule
+                    .failed_jobs_history_limit
+                    .unwrap_or(DEFAULT_JOBS_HISTORY_LIMIT),
+            ),
             job_template: JobTemplateSpec {
                 metadata: Some(ObjectMeta {
                     labels: Some(labels.clone()),
                     ..Default::default()
                 }),
                 spec: Some(JobSpec {
-                    backoff_limit: Some(backup.spec.backoff_limit.unwrap_or(3)),
+                    backoff_limit: Some(
+                        backup
+                            .spec
+                            .backoff_limit
+                            .unwrap_or(DEFAULT_BACKUP_BACKOFF_LIMIT),
+                    ),
                     template: PodTemplateSpec {
                         metadata: Some(ObjectMeta {
                             labels: Some(pod_labels),
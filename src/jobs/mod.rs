@@ -2,4 +2,6 @@ pub mod backup_job;
 pub mod cronjob;
 pub mod job_state;
 pub mod restore_job;
+pub mod result;
+pub mod schedule;
 pub mod templates;
@@ -3,11 +3,14 @@ use k8s_openapi::api::core::v1::{Container, PodSpec, PodTemplateSpec};
 use k8s_openapi::apimachinery::pkg::apis::meta::v1::{ObjectMeta, OwnerReference};
 use kube::ResourceExt;
 
-use crate::crd::{KafkaBackup, KafkaRestore};
+use crate::adapters::restore_config::RestoreSource;
+use crate::config;
+use crate::crd::KafkaRestore;
 use crate::error::Result;
-use crate::reconcilers::DEFAULT_BACKUP_IMAGE;
+use crate::reconcilers::DEFAULT_RESTORE_BACKOFF_LIMIT;
 use crate::strimzi::kafka_cr::ResolvedKafkaCluster;
 use crate::strimzi::kafka_user::ResolvedAuth;
+use crate::telemetry;
 
 use super::templates::{
     add_metrics_discovery_label, append_env_overrides, apply_pod_template, build_annotations,
@@ -21,16 +24,17 @@ pub fn build_restore_job(
     config_map_name: &str,
     cluster: &ResolvedKafkaCluster,
     auth: &ResolvedAuth,
-    source_backup: &KafkaBackup,
+    source: &RestoreSource,
     service_account_name: Option<&str>,
 ) -> Result<Job> {
     let cr_name = restore.name_any();
     let namespace = restore.namespace().unwrap_or_default();
+    let defaults = config::current();
     let image = restore
         .spec
         .image
         .as_deref()
-        .unwrap_or(DEFAULT_BACKUP_IMAGE);
+        .unwrap_or(&defaults.jobs.image);
 
     // Build labels
     let mut labels = build_labels(&cr_name, &cluster.name, "restore");
@@ -39,16 +43,17 @@ pub fn build_restore_job(
     // Build annotations
     let annotations = build_annotations(restore.spec.template.as_ref());
 
-    // Build volumes and mounts — use source backup's storage config for credentials
-    let (volumes, volume_mounts, env) = build_volumes_and_mounts(
+    // Build volumes and mounts — use the backup source's storage config for credentials
+    let (volumes, volume_mounts, mut env) = build_volumes_and_mounts(
         config_map_name,
         "restore.yaml",
         &cluster.name,
         cluster.tls_enabled,
         auth,
-        &source_backup.spec.storage,
+        &source.storage,
         restore.spec.strimzi_cluster_ref.ca_secret.as_ref(),
     );
+    env.extend(telemetry::traceparent_env_var());
 
     // Build container
     let mut container = Container {
@@ -63,7 +68,12 @@ pub fn build_restore_job(
         env: if env.is_empty() { None } else { Some(env) },
         ports: job_metrics_ports(restore.spec.metrics.as_ref()),
         volume_mounts: Some(volume_mounts),
-        resources: restore.spec.resources.as_ref().map(|r| r.to_k8s()),
+        resources: restore
+            .spec
+            .resources
+            .as_ref()
+            .or(defaults.jobs.resources.as_ref())
+            .map(|r| r.to_k8s()),
         ..Default::default()
     };
     append_env_overrides(&mut container, &restore.spec.env);
@@ -109,7 +119,12 @@ pub fn build_restore_job(
         spec: Some(JobSpec {
             // Restores append/purge data: default to a single attempt so a
             // partially completed restore is never re-applied implicitly.
-            backoff_limit: Some(restore.spec.backoff_limit.unwrap_or(0)),
+            backoff_limit: Some(
+                restore
+                    .spec
+                    .backoff_limit
+                    .unwrap_or(DEFAULT_RESTORE_BACKOFF_LIMIT),
+            ),
             template: PodTemplateSpec {
                 metadata: Some(ObjectMeta {
                     labels: Some(pod_labels),
@@ -0,0 +1,279 @@
+use chrono::{DateTime, Utc};
+use k8s_openapi::api::core::v1::Pod;
+use serde::{Deserialize, Serialize};
+
+/// Result document a backup or restore container may write to
+/// `/dev/termination-log`, which the kubelet copies into the container's
+/// `terminationMessage`, truncated at 4096 bytes. Every field is optional.
+/// The kafka-backup CLI writes none today, so the operator fills in the exit
+/// code and error class from the container's state and the counts from the
+/// backup manifest.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct JobResult {
+    /// Exit code of the CLI
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+    /// Records backed up or restored
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub records: Option<u64>,
+    /// Bytes backed up or restored
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bytes: Option<u64>,
+    /// Per-topic counts
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub topics: Vec<TopicResult>,
+    /// Timestamp of the last record restored, when restoring to a point in
+    /// time
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub point_in_time: Option<DateTime<Utc>>,
+    /// Class of the error a failed run ended with
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_class: Option<ErrorClass>,
+}
+
+/// Counts for one topic of a [`JobResult`]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TopicResult {
+    /// Topic name
+    pub name: String,
+    /// Partitions backed up or restored
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub partitions: Option<i32>,
+    /// Records backed up or restored
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub records: Option<u64>,
+    /// Bytes backed up or restored
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bytes: Option<u64>,
+}
+
+/// Why a backup or restore run failed
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum ErrorClass {
+    /// The configuration was rejected, or the CLI could not be started
+    Configuration,
+    /// The cluster rejected the credentials or the principal's ACLs
+    Authentication,
+    /// The cluster could not be reached
+    Connection,
+    /// Backup storage could not be read or written
+    Storage,
+    /// The CLI was killed by a signal, for example when out of memory
+    Killed,
+    /// Any other error, including classes this operator does not know
+    #[serde(other)]
+    Unknown,
+}
+
+impl std::fmt::Display for ErrorClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+impl ErrorClass {
+    /// Class of a run that exited with `code`, `None` when it succeeded.
+    /// Exit codes 126 and 127 mean the CLI could not be started, and codes
+    /// above 128 that it was killed by a signal.
+    pub fn from_exit_code(code: i32) -> Option<Self> {
+        match code {
+            0 => None,
+            126 | 127 => Some(Self::Configuration),
+            code if code > 128 => Some(Self::Killed),
+            _ => Some(Self::Unknown),
+        }
+    }
+}
+
+impl JobResult {
+    /// Parse a termination message, `None` unless it is a result document
+    pub fn parse(message: &str) -> Option<Self> {
+        serde_json::from_str(message.trim()).ok()
+    }
+
+    /// Fill the counts and point in time this result lacks from `other`,
+    /// for example counts derived from the backup manifest
+    pub fn fill_from(&mut self, other: JobResult) {
+        self.records = self.records.or(other.records);
+        self.bytes = self.bytes.or(other.bytes);
+        if self.topics.is_empty() {
+            self.topics = other.topics;
+        }
+        self.point_in_time = self.point_in_time.or(other.point_in_time);
+    }
+
+    /// Number of topics, `None` when the document lists none
+    pub fn topic_count(&self) -> Option<i32> {
+        (!self.topics.is_empty())
+            .then(|| i32::try_from(self.topics.len()).ok())
+            .flatten()
+    }
+
+    /// Number of bytes as stored in status fields
+    pub fn byte_count(&self) -> Option<i64> {
+        self.bytes.and_then(|b| i64::try_from(b).ok())
+    }
+
+    /// Number of partitions over all topics, `None` when no topic has one
+    pub fn partitions(&self) -> Option<i32> {
+        self.topics
+            .iter()
+            .filter_map(|t| t.partitions)
+            .reduce(|a, b| a + b)
+    }
+
+    /// Failure detail to append to a failed Job's message, such as
+    /// `Killed, exit code 137`
+    pub fn failure_detail(&self) -> Option<String> {
+        match (&self.error_class, self.exit_code) {
+            (Some(class), Some(code)) => Some(format!("{class}, exit code {code}")),
+            (Some(class), None) => Some(class.to_string()),
+            (None, Some(code)) if code != 0 => Some(format!("exit code {code}")),
+            _ => None,
+        }
+    }
+}
+
+/// The result of `container` in the most recently terminated of `pods`, the
+/// pods of one Job: the result document it wrote, if any, with the exit code
+/// and error class taken from the container's state when it has none
+pub fn job_result_from_pods(pods: &[Pod], container: &str) -> Option<JobResult> {
+    let terminated = pods
+        .iter()
+        .filter_map(|pod| pod.status.as_ref()?.container_statuses.as_ref())
+        .flatten()
+        .filter(|status| status.name == container)
+        .filter_map(|status| status.state.as_ref()?.terminated.as_ref())
+        .max_by_key(|terminated| terminated.finished_at.as_ref().map(|t| t.0))?;
+    let mut result = terminated
+        .message
+        .as_deref()
+        .and_then(JobResult::parse)
+        .unwrap_or_default();
+    let exit_code = *result.exit_code.get_or_insert(terminated.exit_code);
+    if result.error_class.is_none() {
+        result.error_class = if terminated.reason.as_deref() == Some("OOMKilled") {
+            Some(ErrorClass::Killed)
+        } else {
+            ErrorClass::from_exit_code(exit_code)
+        };
+    }
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn pod(container: &str, finished_at: &str, message: &str) -> Pod {
+        terminated_pod(container, finished_at, 0, message)
+    }
+
+    fn terminated_pod(container: &str, finished_at: &str, exit_code: i32, message: &str) -> Pod {
+        serde_json::from_value(json!({
+            "metadata": {"name": format!("job-{finished_at}")},
+            "status": {"containerStatuses": [{
+                "name": container,
+                "image": "osodevops/kafka-backup:v0.15.12",
+                "imageID": "",
+                "ready": false,
+                "restartCount": 0,
+                "state": {"terminated": {
+                    "exitCode": exit_code,
+                    "finishedAt": finished_at,
+                    "message": message
+                }}
+            }]}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn parses_a_full_result_document() {
+        let result = JobResult::parse(
+            r#"{"exitCode":0,"records":1200,"bytes":52428800,
+                "topics":[{"name":"orders","partitions":6,"records":1000,"bytes":40000000},
+                          {"name":"payments","partitions":3,"records":200,"bytes":12428800}],
+                "pointInTime":"2026-02-13T01:59:59.250Z"}"#,
+        )
+        .unwrap();
+        assert_eq!(result.records, Some(1200));
+        assert_eq!(result.topics.len(), 2);
+        assert_eq!(result.partitions(), Some(9));
+        assert_eq!(
+            result.point_in_time.unwrap().to_rfc3339(),
+            "2026-02-13T01:59:59.250+00:00"
+        );
+        assert_eq!(result.failure_detail(), None);
+    }
+
+    #[test]
+    fn unknown_error_classes_and_fields_are_tolerated() {
+        let result = JobResult::parse(r#"{"errorClass":"QuotaExceeded","retries":3}"#).unwrap();
+        assert_eq!(result.error_class, Some(ErrorClass::Unknown));
+        assert_eq!(result.failure_detail().as_deref(), Some("Unknown"));
+    }
+
+    #[test]
+    fn exit_code_describes_a_failure_without_a_document() {
+        let pods = vec![terminated_pod(
+            "restore",
+            "2026-02-13T02:00:00Z",
+            137,
+            "Error: broker unreachable",
+        )];
+        let result = job_result_from_pods(&pods, "restore").unwrap();
+        assert_eq!(
+            result.failure_detail().as_deref(),
+            Some("Killed, exit code 137")
+        );
+        assert_eq!(result.partitions(), None);
+        assert_eq!(
+            ErrorClass::from_exit_code(127),
+            Some(ErrorClass::Configuration)
+        );
+        assert_eq!(ErrorClass::from_exit_code(0), None);
+    }
+
+    #[test]
+    fn document_counts_take_precedence_over_derived_ones() {
+        let mut result = JobResult::parse(r#"{"exitCode":0,"bytes":10}"#).unwrap();
+        result.fill_from(JobResult {
+            bytes: Some(20),
+            records: Some(5),
+            topics: vec![TopicResult {
+                name: "orders".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+        assert_eq!(result.bytes, Some(10));
+        assert_eq!(result.records, Some(5));
+        assert_eq!(result.topic_count(), Some(1));
+    }
+
+    #[test]
+    fn log_output_is_not_a_result() {
+        assert_eq!(JobResult::parse(""), None);
+        assert_eq!(JobResult::parse("Error: broker unreachable"), None);
+    }
+
+    #[test]
+    fn result_comes_from_the_latest_terminated_container() {
+        let pods = vec![
+            pod("restore", "2026-02-13T02:00:00Z", r#"{"exitCode":1}"#),
+            pod(
+                "restore",
+                "2026-02-13T02:05:00Z",
+                r#"{"exitCode":0,"bytes":10}"#,
+            ),
+            pod("sidecar", "2026-02-13T02:10:00Z", r#"{"exitCode":2}"#),
+        ];
+        let result = job_result_from_pods(&pods, "restore").unwrap();
+        assert_eq!(result.bytes, Some(10));
+        assert_eq!(job_result_from_pods(&pods, "backup"), None);
+    }
+}
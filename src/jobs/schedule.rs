@@ -0,0 +1,76 @@
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use croner::Cron;
+
+use crate::crd::kafka_backup::ScheduleSpec;
+use crate::error::{Error, Result};
+
+/// The first time strictly after `after` at which `schedule` fires, evaluated
+/// in the schedule's timezone (UTC when unset) like a Kubernetes CronJob.
+pub fn next_run(schedule: &ScheduleSpec, after: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    let cron = Cron::new(&schedule.cron).parse().map_err(|e| {
+        Error::InvalidConfig(format!("invalid cron expression '{}': {e}", schedule.cron))
+    })?;
+    let timezone: Tz = match schedule.timezone.as_deref() {
+        Some(timezone) => timezone
+            .parse()
+            .map_err(|_| Error::InvalidConfig(format!("unknown timezone '{timezone}'")))?,
+        None => Tz::UTC,
+    };
+
+    cron.find_next_occurrence(&after.with_timezone(&timezone), false)
+        .map(|next| next.with_timezone(&Utc))
+        .map_err(|e| {
+            Error::InvalidConfig(format!(
+                "cron expression '{}' has no next run: {e}",
+                schedule.cron
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule(cron: &str, timezone: Option<&str>) -> ScheduleSpec {
+        ScheduleSpec {
+            cron: cron.to_string(),
+            timezone: timezone.map(str::to_string),
+            suspend: false,
+            successful_jobs_history_limit: None,
+            failed_jobs_history_limit: None,
+        }
+    }
+
+    #[test]
+    fn next_run_is_strictly_after_start() {
+        let at_two: DateTime<Utc> = "2026-02-13T02:00:00Z".parse().unwrap();
+
+        let next = next_run(&schedule("0 2 * * *", None), at_two).unwrap();
+
+        assert_eq!(
+            next,
+            "2026-02-14T02:00:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+    }
+
+    #[test]
+    fn next_run_honours_timezone() {
+        let after: DateTime<Utc> = "2026-02-13T00:00:00Z".parse().unwrap();
+
+        let next = next_run(&schedule("0 2 * * *", Some("Europe/Berlin")), after).unwrap();
+
+        assert_eq!(
+            next,
+            "2026-02-13T01:00:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+    }
+
+    #[test]
+    fn next_run_rejects_invalid_schedule() {
+        let now = Utc::now();
+
+        assert!(next_run(&schedule("not a cron", None), now).is_err());
+        assert!(next_run(&schedule("0 2 * * *", Some("Mars/Olympus")), now).is_err());
+    }
+}
@@ -36,6 +36,9 @@ pub fn build_labels(cr_name: &str, cluster_name: &str, job_type: &str) -> BTreeM
     labels
 }
 
+/// Port of the kafka-backup runtime metrics server when `metrics.port` is unset
+pub const DEFAULT_METRICS_PORT: u16 = 8080;
+
 /// Whether the kafka-backup runtime metrics server is enabled for this pod.
 /// The runtime defaults metrics to enabled when the section is omitted.
 pub fn job_metrics_enabled(metrics: Option<&MetricsSpec>) -> bool {
@@ -47,7 +50,11 @@ pub fn job_metrics_ports(metrics: Option<&MetricsSpec>) -> Option<Vec<ContainerP
     job_metrics_enabled(metrics).then(|| {
         vec![ContainerPort {
             name: Some("metrics".to_string()),
-            container_port: i32::from(metrics.and_then(|config| config.port).unwrap_or(8080)),
+            container_port: i32::from(
+                metrics
+                    .and_then(|config| config.port)
+                    .unwrap_or(DEFAULT_METRICS_PORT),
+            ),
             protocol: Some("TCP".to_string()),
             ..Default::default()
         }]
@@ -1,4 +1,5 @@
 pub mod adapters;
+pub mod config;
 pub mod controllers;
 pub mod crd;
 pub mod error;
@@ -8,3 +9,5 @@ pub mod reconcilers;
 pub mod retention;
 pub mod status;
 pub mod strimzi;
+pub mod telemetry;
+pub mod webhook;
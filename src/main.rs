@@ -1,55 +1,87 @@
 use std::net::SocketAddr;
 use std::sync::Arc;
 
-use axum::{routing::get, Router};
-use futures::future::join3;
+use axum::routing::get;
 use kube::Client;
 use tracing::{error, info};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
 
-use kafka_backup_operator::controllers::{backup, restore};
+use kafka_backup_operator::config::{self, Cli, OperatorConfig};
+use kafka_backup_operator::controllers::health::{self, ControllerHealth};
+use kafka_backup_operator::controllers::leader::{self, LeaderElectionConfig, LeaderElector};
+use kafka_backup_operator::controllers::watch::WatchNamespaces;
+use kafka_backup_operator::controllers::{
+    backup, restore, schedule, snapshot, storage_location, verification,
+};
 use kafka_backup_operator::metrics::prometheus::MetricsState;
+use kafka_backup_operator::telemetry::{self, TracingConfig};
+use kafka_backup_operator::webhook::{self, tls::TlsListener};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    tracing_subscriber::fmt()
-        .with_env_filter(
+    let cli = Cli::parse(std::env::args().skip(1))?;
+    if cli.help {
+        print!("{}", config::usage());
+        return Ok(());
+    }
+    if cli.version {
+        println!("kafka-backup-operator {}", env!("CARGO_PKG_VERSION"));
+        return Ok(());
+    }
+
+    // Spans are exported over OTLP only when a collector endpoint is set
+    let tracing_config = TracingConfig::from_env();
+    let tracer_provider = tracing_config
+        .as_ref()
+        .map(telemetry::tracer_provider)
+        .transpose()?;
+    tracing_subscriber::registry()
+        .with(
             tracing_subscriber::EnvFilter::try_from_default_env()
                 .unwrap_or_else(|_| "info,kube=info".into()),
         )
-        .json()
+        .with(tracing_subscriber::fmt::layer().json())
+        .with(tracer_provider.as_ref().map(telemetry::layer))
         .init();
 
     info!(
         version = env!("CARGO_PKG_VERSION"),
         "Starting kafka-backup-operator"
     );
+    if let Some(tracing_config) = &tracing_config {
+        info!(endpoint = %tracing_config.endpoint, "Exporting spans over OTLP");
+    }
+
+    let operator_config = OperatorConfig::load(&cli)?;
+    info!(config = ?cli.config_path(), "Loaded operator configuration");
+    config::install(operator_config.clone());
 
     let client = Client::try_default().await?;
     info!("Connected to Kubernetes API server");
 
     let metrics_state = Arc::new(MetricsState::new());
+    let controller_health = Arc::new(ControllerHealth::default());
 
     // Health and metrics server
     let health_metrics_server = {
         let metrics_state = Arc::clone(&metrics_state);
+        let health = health::router(Arc::clone(&controller_health));
         async move {
-            let app = Router::new()
-                .route("/healthz", get(|| async { "ok" }))
-                .route("/readyz", get(|| async { "ok" }))
-                .route(
-                    "/metrics",
-                    get(move || {
-                        let state = Arc::clone(&metrics_state);
-                        async move {
-                            (
-                                [("content-type", "text/plain; version=0.0.4; charset=utf-8")],
-                                state.gather(),
-                            )
-                        }
-                    }),
-                );
-
-            let addr = SocketAddr::from(([0, 0, 0, 0], 9090));
+            let app = health.route(
+                "/metrics",
+                get(move || {
+                    let state = Arc::clone(&metrics_state);
+                    async move {
+                        (
+                            [("content-type", "text/plain; version=0.0.4; charset=utf-8")],
+                            state.gather(),
+                        )
+                    }
+                }),
+            );
+
+            let addr = operator_config.metrics_address;
             info!(%addr, "Starting health/metrics server");
             let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
             if let Err(e) = axum::serve(listener, app)
@@ -61,18 +93,145 @@ async fn main() -> anyhow::Result<()> {
         }
     };
 
-    // Run both controllers and the health server concurrently
-    let backup_controller = backup::run(client.clone(), Arc::clone(&metrics_state));
-    let restore_controller = restore::run(client.clone(), Arc::clone(&metrics_state));
+    // Admission and conversion webhooks, served over TLS when a certificate
+    // directory is configured
+    let webhook_server = async {
+        let Ok(cert_dir) = std::env::var("WEBHOOK_CERT_DIR") else {
+            return;
+        };
+        let port = std::env::var("WEBHOOK_PORT")
+            .ok()
+            .and_then(|p| p.parse().ok())
+            .unwrap_or(9443);
+        let addr = SocketAddr::from(([0, 0, 0, 0], port));
+        info!(%addr, %cert_dir, "Starting admission webhook server");
+        let listener = match TlsListener::bind(addr, cert_dir.as_ref()).await {
+            Ok(listener) => listener,
+            Err(e) => {
+                error!(error = %e, "Failed to start admission webhook server");
+                return;
+            }
+        };
+        controller_health.serving_webhooks();
+        if let Err(e) = axum::serve(listener, webhook::router())
+            .with_graceful_shutdown(shutdown_signal())
+            .await
+        {
+            error!(error = %e, "Admission webhook server error");
+        }
+    };
+
+    // Run the controllers, the health server and the webhook concurrently.
+    // With leader election, only the replica holding the Lease runs the
+    // controllers; every replica serves health, metrics and webhooks.
+    let namespaces = operator_config.watch_namespaces();
+    let leader_election = LeaderElectionConfig::from_env()?;
+    let controllers = async {
+        let lead = || {
+            let leading = controller_health.leading();
+            let controllers = run_controllers(
+                client.clone(),
+                Arc::clone(&metrics_state),
+                Arc::clone(&controller_health),
+                namespaces.clone(),
+            );
+            async move {
+                let _leading = leading;
+                controllers.await;
+            }
+        };
+        match leader_election {
+            Some(config) => {
+                let elector = LeaderElector::new(client.clone(), config);
+                leader::run(elector, &metrics_state, lead, shutdown_signal()).await;
+            }
+            None => {
+                metrics_state.operator_leader.set(1);
+                lead().await;
+            }
+        }
+    };
+
+    // Settings that are safe to change while running are reloaded from the
+    // configuration file
+    let config_reload = async {
+        tokio::select! {
+            _ = config::watch(cli.clone()) => {}
+            _ = shutdown_signal() => {}
+        }
+    };
 
-    info!("Controllers started, watching for KafkaBackup and KafkaRestore resources");
+    // The probes read the result of a periodic API server check
+    let api_check = async {
+        tokio::select! {
+            _ = controller_health.watch_api(client.clone()) => {}
+            _ = shutdown_signal() => {}
+        }
+    };
 
-    join3(backup_controller, restore_controller, health_metrics_server).await;
+    futures::join!(
+        controllers,
+        health_metrics_server,
+        webhook_server,
+        config_reload,
+        api_check
+    );
 
     info!("Operator shutting down");
+    if let Some(provider) = tracer_provider {
+        // Flushing the last batch blocks on the exporter's HTTP client
+        match tokio::task::spawn_blocking(move || provider.shutdown()).await {
+            Ok(Err(e)) => error!(error = %e, "Failed to flush exported spans"),
+            Err(e) => error!(error = %e, "Failed to flush exported spans"),
+            Ok(Ok(())) => {}
+        }
+    }
     Ok(())
 }
 
+async fn run_controllers(
+    client: Client,
+    metrics_state: Arc<MetricsState>,
+    health: Arc<ControllerHealth>,
+    namespaces: WatchNamespaces,
+) {
+    info!(%namespaces, "Controllers started, watching for KafkaBackup, KafkaRestore, KafkaBackupSchedule, KafkaBackupSnapshot, KafkaBackupVerification and storage location resources");
+
+    futures::join!(
+        backup::run(
+            client.clone(),
+            Arc::clone(&metrics_state),
+            Arc::clone(&health),
+            namespaces.clone(),
+        ),
+        restore::run(
+            client.clone(),
+            Arc::clone(&metrics_state),
+            Arc::clone(&health),
+            namespaces.clone(),
+        ),
+        schedule::run(
+            client.clone(),
+            Arc::clone(&metrics_state),
+            Arc::clone(&health),
+            namespaces.clone(),
+        ),
+        snapshot::run(
+            client.clone(),
+            Arc::clone(&metrics_state),
+            Arc::clone(&health),
+            namespaces.clone(),
+        ),
+        storage_location::run(
+            client.clone(),
+            Arc::clone(&metrics_state),
+            Arc::clone(&health),
+            namespaces.clone(),
+        ),
+        verification::run(client, metrics_state, health, namespaces),
+    );
+}
+
 async fn shutdown_signal() {
     let ctrl_c = async {
         tokio::signal::ctrl_c()
@@ -1,6 +1,7 @@
+use chrono::{DateTime, Utc};
 use prometheus::{
-    Encoder, GaugeVec, HistogramOpts, HistogramVec, IntCounterVec, IntGaugeVec, Opts, Registry,
-    TextEncoder,
+    Encoder, GaugeVec, HistogramOpts, HistogramVec, IntCounterVec, IntGauge, IntGaugeVec, Opts,
+    Registry, TextEncoder,
 };
 use std::time::Duration;
 
@@ -8,6 +9,7 @@ use std::time::Duration;
 pub struct MetricsState {
     registry: Registry,
     pub operator_build_info: IntGaugeVec,
+    pub operator_leader: IntGauge,
     pub operator_reconciliations_total: IntCounterVec,
     pub operator_reconciliation_duration_seconds: HistogramVec,
     pub backup_records_total: IntCounterVec,
@@ -49,6 +51,17 @@ impl MetricsState {
             .with_label_values(&[env!("CARGO_PKG_VERSION")])
             .set(1);
 
+        // 1 while this replica runs the controllers; without leader election
+        // the only replica always does
+        let operator_leader = IntGauge::new(
+            "strimzi_backup_operator_leader",
+            "Whether this operator replica holds the leader Lease",
+        )
+        .expect("metric creation");
+        registry
+            .register(Box::new(operator_leader.clone()))
+            .expect("metric registration");
+
         let operator_reconciliations_total = IntCounterVec::new(
             Opts::new(
                 "strimzi_backup_operator_reconciliations_total",
@@ -203,6 +216,7 @@ impl MetricsState {
         Self {
             registry,
             operator_build_info,
+            operator_leader,
             operator_reconciliations_total,
             operator_reconciliation_duration_seconds,
             backup_records_total,
@@ -239,18 +253,22 @@ impl MetricsState {
             .observe(duration.as_secs_f64());
     }
 
-    /// Record a successful backup completion
+    /// Record a successful backup completion. Records are only counted when
+    /// the manifest reports them.
     pub fn record_backup_success(
         &self,
         backup_name: &str,
         cluster: &str,
-        records: u64,
+        records: Option<u64>,
         bytes: u64,
         duration_secs: f64,
+        completed_at: DateTime<Utc>,
     ) {
-        self.backup_records_total
-            .with_label_values(&[backup_name, cluster])
-            .inc_by(records);
+        if let Some(records) = records {
+            self.backup_records_total
+                .with_label_values(&[backup_name, cluster])
+                .inc_by(records);
+        }
         self.backup_bytes_total
             .with_label_values(&[backup_name, cluster])
             .inc_by(bytes);
@@ -259,31 +277,80 @@ impl MetricsState {
             .observe(duration_secs);
         self.backup_last_success_timestamp
             .with_label_values(&[backup_name, cluster])
-            .set(chrono::Utc::now().timestamp() as f64);
+            .set(completed_at.timestamp() as f64);
         self.backup_lag_seconds
             .with_label_values(&[backup_name, cluster])
-            .set(0.0);
+            .set(seconds_since(completed_at));
     }
 
     /// Record a backup failure
-    pub fn record_backup_failure(&self, backup_name: &str, cluster: &str) {
+    pub fn record_backup_failure(
+        &self,
+        backup_name: &str,
+        cluster: &str,
+        failed_at: DateTime<Utc>,
+    ) {
         self.backup_last_failure_timestamp
             .with_label_values(&[backup_name, cluster])
-            .set(chrono::Utc::now().timestamp() as f64);
+            .set(failed_at.timestamp() as f64);
     }
 
-    /// Record a successful restore completion
+    /// Refresh the series derived from a backup's status: the time since its
+    /// last successful backup, or since `created` when none has succeeded,
+    /// and the storage used by the backups in its history
+    pub fn record_backup_state(
+        &self,
+        backup_name: &str,
+        cluster: &str,
+        last_success: Option<DateTime<Utc>>,
+        created: DateTime<Utc>,
+        storage_bytes: u64,
+    ) {
+        let labels = &[backup_name, cluster];
+        if let Some(last_success) = last_success {
+            self.backup_last_success_timestamp
+                .with_label_values(labels)
+                .set(last_success.timestamp() as f64);
+        }
+        self.backup_lag_seconds
+            .with_label_values(labels)
+            .set(seconds_since(last_success.unwrap_or(created)));
+        self.backup_storage_bytes
+            .with_label_values(labels)
+            .set(storage_bytes as f64);
+    }
+
+    /// Drop the series of a deleted backup, so it no longer reads as stale
+    pub fn remove_backup(&self, backup_name: &str, cluster: &str) {
+        let labels = &[backup_name, cluster];
+        let _ = self.backup_records_total.remove_label_values(labels);
+        let _ = self.backup_bytes_total.remove_label_values(labels);
+        let _ = self.backup_duration_seconds.remove_label_values(labels);
+        let _ = self
+            .backup_last_success_timestamp
+            .remove_label_values(labels);
+        let _ = self
+            .backup_last_failure_timestamp
+            .remove_label_values(labels);
+        let _ = self.backup_storage_bytes.remove_label_values(labels);
+        let _ = self.backup_lag_seconds.remove_label_values(labels);
+    }
+
+    /// Record a successful restore completion. Records are only counted when
+    /// the manifest reports them.
     pub fn record_restore_success(
         &self,
         restore_name: &str,
         cluster: &str,
-        records: u64,
+        records: Option<u64>,
         bytes: u64,
         duration_secs: f64,
     ) {
-        self.restore_records_total
-            .with_label_values(&[restore_name, cluster])
-            .inc_by(records);
+        if let Some(records) = records {
+            self.restore_records_total
+                .with_label_values(&[restore_name, cluster])
+                .inc_by(records);
+        }
         self.restore_bytes_total
             .with_label_values(&[restore_name, cluster])
             .inc_by(bytes);
@@ -291,6 +358,18 @@ impl MetricsState {
             .with_label_values(&[restore_name, cluster])
             .observe(duration_secs);
     }
+
+    /// Drop the series of a deleted restore
+    pub fn remove_restore(&self, restore_name: &str, cluster: &str) {
+        let labels = &[restore_name, cluster];
+        let _ = self.restore_records_total.remove_label_values(labels);
+        let _ = self.restore_bytes_total.remove_label_values(labels);
+        let _ = self.restore_duration_seconds.remove_label_values(labels);
+    }
+}
+
+fn seconds_since(time: DateTime<Utc>) -> f64 {
+    (Utc::now() - time).num_milliseconds().max(0) as f64 / 1000.0
 }
 
 #[cfg(test)]
@@ -320,10 +399,53 @@ mod tests {
     #[test]
     fn test_record_backup_success() {
         let state = MetricsState::new();
-        state.record_backup_success("test-backup", "my-cluster", 1000, 1048576, 120.5);
+        let completed_at = Utc::now() - chrono::Duration::minutes(10);
+        state.record_backup_success(
+            "test-backup",
+            "my-cluster",
+            Some(1000),
+            1048576,
+            120.5,
+            completed_at,
+        );
 
         let output = state.gather();
         assert!(output.contains("strimzi_backup_records_total"));
         assert!(output.contains("strimzi_backup_bytes_total"));
+        assert!(output.contains(&format!(
+            "strimzi_backup_last_success_timestamp{{backup_name=\"test-backup\",cluster=\"my-cluster\"}} {}",
+            completed_at.timestamp()
+        )));
+        let lag = state
+            .backup_lag_seconds
+            .with_label_values(&["test-backup", "my-cluster"])
+            .get();
+        assert!((600.0..610.0).contains(&lag), "lag was {lag}");
+    }
+
+    #[test]
+    fn test_backup_lag_counts_from_creation_until_a_backup_succeeds() {
+        let state = MetricsState::new();
+        let created = Utc::now() - chrono::Duration::hours(2);
+        state.record_backup_state("test-backup", "my-cluster", None, created, 0);
+
+        let lag = state
+            .backup_lag_seconds
+            .with_label_values(&["test-backup", "my-cluster"])
+            .get();
+        assert!((7200.0..7210.0).contains(&lag), "lag was {lag}");
+        assert!(!state
+            .gather()
+            .contains("strimzi_backup_last_success_timestamp{"));
+    }
+
+    #[test]
+    fn test_removed_backup_has_no_series() {
+        let state = MetricsState::new();
+        state.record_backup_state("test-backup", "my-cluster", None, Utc::now(), 4096);
+        state.record_backup_failure("test-backup", "my-cluster", Utc::now());
+        state.remove_backup("test-backup", "my-cluster");
+
+        assert!(!state.gather().contains("test-backup"));
     }
 }
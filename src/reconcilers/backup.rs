@@ -1,38 +1,55 @@
 use std::sync::Arc;
 
-use chrono::Utc;
-use k8s_openapi::api::batch::v1::Job;
+use chrono::{DateTime, SecondsFormat, Utc};
+use k8s_openapi::api::batch::v1::{CronJob, Job};
 use k8s_openapi::api::core::v1::ConfigMap;
 use kube::{
     api::{Api, Patch, PatchParams, PostParams, ResourceExt},
+    runtime::events::Recorder,
     Client,
 };
 use std::collections::BTreeSet;
-use tracing::{debug, error, info, warn};
+use tracing::{debug, error, info, info_span, warn, Instrument};
 
-use crate::adapters::backup_config::build_backup_config_yaml;
-use crate::crd::common::{BackupHistoryEntry, BackupStatus, LastBackupInfo};
+use crate::adapters::backup_config::{backup_id_for, build_backup_config_yaml};
+use crate::adapters::schema_registry::SchemaRegistryClient;
+use crate::adapters::storage_location::{backup_storage, with_resolved_storage};
+use crate::crd::common::{BackupHistoryEntry, BackupSnapshotsInfo, BackupStatus, LastBackupInfo};
 use crate::crd::{KafkaBackup, KafkaBackupStatus};
 use crate::error::{Error, Result};
 use crate::jobs::backup_job::build_backup_job;
 use crate::jobs::cronjob::build_backup_cronjob;
 use crate::jobs::job_state::{classify_jobs, job_failed, job_succeeded, should_create_backup_job};
+use crate::jobs::schedule::next_run;
 use crate::metrics::prometheus::MetricsState;
+use crate::reconcilers::snapshot::sync_backup_snapshots;
 use crate::reconcilers::{
-    cleanup_delete_params, is_reconciliation_paused, job_service_account_name, FINALIZER,
-    TRIGGER_ANNOTATION, TRIGGER_VALUE_NOW,
+    cleanup_delete_params, is_reconciliation_paused, job_service_account_name, read_job_result,
+    FINALIZER, TRIGGER_ANNOTATION, TRIGGER_VALUE_NOW,
 };
 use crate::retention::policy::evaluate_retention;
-use crate::retention::storage::{discover_backup_history, prune_backup_ids};
+use crate::retention::storage::{
+    discover_backup_history, prune_backup_ids, read_backup_details, write_backup_file,
+    KAFKA_CLUSTER_FILE, KAFKA_CONNECTS_FILE, KAFKA_TOPICS_FILE, KAFKA_USERS_FILE, SCHEMAS_FILE,
+};
 use crate::status::conditions::*;
-use crate::strimzi::kafka_cr::resolve_kafka_cluster;
-use crate::strimzi::kafka_user::resolve_auth;
+use crate::status::events::{
+    self, ACTION_CREATE_JOB, ACTION_PRUNE, ACTION_RECONCILE, ACTION_RECORD_RESULT,
+};
+use crate::strimzi::kafka_connect::{
+    connect_storage_topics, list_kafka_connect_definitions, KafkaConnectDefinition,
+    KafkaConnectSnapshot,
+};
+use crate::strimzi::kafka_cr::{read_kafka_cluster_definition, resolve_kafka_cluster};
+use crate::strimzi::kafka_topic::{kafka_topic_name, list_kafka_topic_definitions};
+use crate::strimzi::kafka_user::{list_kafka_user_definitions, resolve_auth};
 use crate::strimzi::tls::resolve_cluster_ca;
 
 pub async fn reconcile_backup(
     backup: Arc<KafkaBackup>,
     client: Client,
-    _metrics: &MetricsState,
+    recorder: &Recorder,
+    metrics: &MetricsState,
 ) -> Result<()> {
     let name = backup.name_any();
     let namespace = backup
@@ -42,9 +59,13 @@ pub async fn reconcile_backup(
 
     // Check if being deleted
     if backup.metadata.deletion_timestamp.is_some() {
-        return handle_cleanup(&backup, &client, &namespace).await;
+        return handle_cleanup(&backup, &client, &namespace, metrics).await;
     }
 
+    // Refresh the lag on every reconcile, so a backup that stops succeeding
+    // shows a growing lag. A completion recorded below updates it again.
+    record_backup_state(metrics, &backup);
+
     // Match Strimzi's pause semantics: a newly created paused resource is
     // status-only and must not gain a finalizer, ConfigMap, Job, or CronJob.
     // Deletion is intentionally handled first so an existing finalizer cannot
@@ -68,6 +89,16 @@ pub async fn reconcile_backup(
     // Update observed generation
     let generation = backup.metadata.generation.unwrap_or(0);
 
+    // Resolve the storage destination, inline or through spec.storageRef.
+    // Everything below reads the resolved `spec.storage`.
+    let backup = match with_resolved_storage(&client, &backup).await {
+        Ok(resolved) => resolved,
+        Err(e) => {
+            update_status_error(&backup_api, recorder, &name, generation, &e).await?;
+            return Err(e);
+        }
+    };
+
     // Step 1: Resolve Strimzi Kafka cluster
     let kafka_cluster = match resolve_kafka_cluster(
         &client,
@@ -75,11 +106,14 @@ pub async fn reconcile_backup(
         &namespace,
         backup.spec.authentication.as_ref().map(|a| &a.auth_type),
     )
+    .instrument(
+        info_span!("resolve_kafka_cluster", cluster = %backup.spec.strimzi_cluster_ref.name),
+    )
     .await
     {
         Ok(cluster) => cluster,
         Err(e) => {
-            update_status_error(&backup_api, &name, generation, &e).await?;
+            update_status_error(&backup_api, recorder, &name, generation, &e).await?;
             return Err(e);
         }
     };
@@ -91,6 +125,7 @@ pub async fn reconcile_backup(
         backup.spec.strimzi_cluster_ref.ca_secret.as_ref(),
         &namespace,
     )
+    .instrument(info_span!("resolve_cluster_ca"))
     .await
     {
         Ok(certs) => Some(certs),
@@ -101,14 +136,23 @@ pub async fn reconcile_backup(
     };
 
     // Step 3: Resolve authentication
-    let resolved_auth =
-        resolve_auth(&client, backup.spec.authentication.as_ref(), &namespace).await?;
+    let resolved_auth = resolve_auth(&client, backup.spec.authentication.as_ref(), &namespace)
+        .instrument(info_span!("resolve_auth"))
+        .await?;
 
     // Step 4: Build config YAML and create ConfigMap
+    let config_backup = match with_connect_topics(&client, &backup).await {
+        Ok(config_backup) => config_backup,
+        Err(e) => {
+            update_status_error(&backup_api, recorder, &name, generation, &e).await?;
+            return Err(e);
+        }
+    };
     let config_yaml =
-        build_backup_config_yaml(&backup, &kafka_cluster, &tls_certs, &resolved_auth)?;
+        build_backup_config_yaml(&config_backup, &kafka_cluster, &tls_certs, &resolved_auth)?;
     let config_map_name = format!("{name}-config");
     create_or_update_config_map(&client, &namespace, &config_map_name, &config_yaml, &backup)
+        .instrument(info_span!("apply_config_map", config_map = %config_map_name))
         .await?;
 
     // Step 5: Check for scheduled vs one-shot
@@ -124,20 +168,32 @@ pub async fn reconcile_backup(
             &resolved_auth,
             job_service_account.as_deref(),
         )?;
-        let cronjob_api: Api<k8s_openapi::api::batch::v1::CronJob> =
-            Api::namespaced(client.clone(), &namespace);
+        let cronjob_api: Api<CronJob> = Api::namespaced(client.clone(), &namespace);
         let cronjob_name = format!("{name}-scheduled");
 
-        apply_resource(&cronjob_api, &cronjob_name, &cronjob).await?;
+        let applied = apply_resource(&cronjob_api, &cronjob_name, &cronjob)
+            .instrument(info_span!("apply_cronjob", cronjob = %cronjob_name))
+            .await?;
+        let last_schedule = applied
+            .status
+            .and_then(|s| s.last_schedule_time)
+            .map(|t| t.0);
 
         // Update status
         if schedule.suspend {
-            update_status_suspended(&backup_api, &name, generation).await?;
+            update_status_suspended(&backup_api, &name, generation, last_schedule).await?;
             info!(%name, "CronJob suspended for scheduled backup");
         } else {
-            let next_backup = schedule.cron.clone();
-            update_status_scheduled(&backup_api, &name, generation, &next_backup).await?;
-            info!(%name, cron = %schedule.cron, "CronJob created/updated for scheduled backup");
+            let next_backup = match next_run(schedule, Utc::now()) {
+                Ok(next_backup) => next_backup,
+                Err(e) => {
+                    update_status_error(&backup_api, recorder, &name, generation, &e).await?;
+                    return Err(e);
+                }
+            };
+            update_status_scheduled(&backup_api, &name, generation, next_backup, last_schedule)
+                .await?;
+            info!(%name, cron = %schedule.cron, %next_backup, "CronJob created/updated for scheduled backup");
         }
     }
 
@@ -161,21 +217,34 @@ pub async fn reconcile_backup(
 
         if should_create_backup_job(&classify_jobs(&jobs.items), triggered) {
             let job_name = format!("{name}-{}", Utc::now().format("%Y%m%d-%H%M%S"));
-            let job = build_backup_job(
-                &backup,
-                &job_name,
-                &config_map_name,
-                &kafka_cluster,
-                &resolved_auth,
-                job_service_account.as_deref(),
-            )?;
+            // The Job is built inside the span so that it carries the span's
+            // trace context
+            let span = info_span!("create_job", job = %job_name);
+            let job = span.in_scope(|| {
+                build_backup_job(
+                    &backup,
+                    &job_name,
+                    &config_map_name,
+                    &kafka_cluster,
+                    &resolved_auth,
+                    job_service_account.as_deref(),
+                )
+            })?;
 
             jobs_api
                 .create(&PostParams::default(), &job)
+                .instrument(span)
                 .await
                 .map_err(|e| Error::JobCreationFailed(e.to_string()))?;
 
             info!(%job_name, "Created backup job");
+            events::normal(
+                recorder,
+                REASON_BACKUP_RUNNING,
+                ACTION_CREATE_JOB,
+                format!("Created backup Job {job_name}"),
+            )
+            .await;
             update_status_running(&backup_api, &name, generation).await?;
         } else {
             debug!(%name, "Backup job already exists, skipping creation");
@@ -188,13 +257,69 @@ pub async fn reconcile_backup(
     }
 
     // Step 8: Check running job status and update
-    check_job_completion(&client, &backup_api, &backup, generation).await?;
-    apply_retention_policy(&client, &backup_api, &backup, generation).await?;
+    let recorded =
+        check_job_completion(&client, recorder, metrics, &backup_api, &backup, generation)
+            .instrument(info_span!("check_job_completion"))
+            .await?;
+    let pruned = apply_retention_policy(&client, recorder, &backup_api, &backup, generation)
+        .instrument(info_span!("apply_retention_policy"))
+        .await?;
+
+    // Step 9: Store the Strimzi resource snapshots and Schema Registry export
+    // of the last backup, retrying those a previous reconcile failed to write
+    let first_attempt = recorded.is_some();
+    let snapshots = recorded.or_else(|| backup.status.as_ref().and_then(|s| s.snapshots.clone()));
+    if let Some(snapshots) = snapshots.filter(|s| !s.missing.is_empty()) {
+        write_missing_snapshots(
+            &client,
+            recorder,
+            &backup_api,
+            &backup,
+            snapshots,
+            first_attempt,
+        )
+        .instrument(info_span!("write_snapshots"))
+        .await?;
+    }
+
+    // Step 10: Mirror backups in storage as KafkaBackupSnapshot resources,
+    // when a backup finished or retention removed some since the last sync.
+    // Snapshots are a read model of storage; failing to list it must not
+    // fail the reconciliation of the backup itself.
+    let status = backup.status.as_ref();
+    let last_backup_id = status.and_then(|s| s.last_backup.as_ref().map(|b| b.id.clone()));
+    let synced_backup_id = status.and_then(|s| s.last_synced_backup_id.clone());
+    if pruned || last_backup_id != synced_backup_id {
+        match sync_backup_snapshots(&client, &backup)
+            .instrument(info_span!("sync_backup_snapshots"))
+            .await
+        {
+            Ok(()) if last_backup_id != synced_backup_id => {
+                let patch = serde_json::json!({
+                    "status": { "lastSyncedBackupId": last_backup_id }
+                });
+                backup_api
+                    .patch_status(
+                        &name,
+                        &PatchParams::apply("kafka-backup-operator"),
+                        &Patch::Merge(&patch),
+                    )
+                    .await?;
+            }
+            Ok(()) => {}
+            Err(e) => warn!(%name, error = %e, "Failed to sync KafkaBackupSnapshots"),
+        }
+    }
 
     Ok(())
 }
 
-async fn handle_cleanup(backup: &KafkaBackup, client: &Client, namespace: &str) -> Result<()> {
+async fn handle_cleanup(
+    backup: &KafkaBackup,
+    client: &Client,
+    namespace: &str,
+    metrics: &MetricsState,
+) -> Result<()> {
     let name = backup.name_any();
     info!(%name, "Cleaning up KafkaBackup resources");
 
@@ -226,6 +351,7 @@ async fn handle_cleanup(backup: &KafkaBackup, client: &Client, namespace: &str)
     // Remove finalizer
     let backup_api: Api<KafkaBackup> = Api::namespaced(client.clone(), namespace);
     remove_finalizer(&backup_api, &name).await?;
+    metrics.remove_backup(&name, &backup.spec.strimzi_cluster_ref.name);
 
     info!(%name, "Cleanup complete");
     Ok(())
@@ -328,17 +454,19 @@ fn backup_jobs_selector(backup_name: &str) -> kube::api::ListParams {
     ))
 }
 
+/// Prune backups outside `spec.retention`. Returns whether any was pruned.
 async fn apply_retention_policy(
     client: &Client,
+    recorder: &Recorder,
     backup_api: &Api<KafkaBackup>,
     backup: &KafkaBackup,
     generation: i64,
-) -> Result<()> {
+) -> Result<bool> {
     let Some(retention) = &backup.spec.retention else {
-        return Ok(());
+        return Ok(false);
     };
     if backup.spec.schedule.is_none() || !retention.prune_on_schedule {
-        return Ok(());
+        return Ok(false);
     }
 
     let name = backup.name_any();
@@ -348,7 +476,7 @@ async fn apply_retention_policy(
         .backup_history;
 
     let discovered =
-        discover_backup_history(client, &namespace, &backup.spec.storage, &name).await?;
+        discover_backup_history(client, &namespace, backup_storage(backup)?, &name).await?;
     merge_backup_history(&mut history, discovered);
 
     let active_backup_ids = active_backup_ids(client, &namespace, &name, backup).await?;
@@ -357,10 +485,10 @@ async fn apply_retention_policy(
 
     if to_prune.is_empty() {
         patch_backup_history(backup_api, &name, &history).await?;
-        return Ok(());
+        return Ok(false);
     }
 
-    let pruned = prune_backup_ids(client, &namespace, &backup.spec.storage, &to_prune).await?;
+    let pruned = prune_backup_ids(client, &namespace, backup_storage(backup)?, &to_prune).await?;
     history.retain(|entry| !pruned.contains(&entry.id));
     patch_backup_history(backup_api, &name, &history).await?;
 
@@ -370,8 +498,18 @@ async fn apply_retention_policy(
         pruned = pruned.len(),
         "Applied backup retention policy"
     );
+    if !pruned.is_empty() {
+        let ids: Vec<&str> = pruned.iter().map(String::as_str).collect();
+        events::normal(
+            recorder,
+            REASON_RETENTION_PRUNED,
+            ACTION_PRUNE,
+            format!("Pruned {} backups: {}", ids.len(), ids.join(", ")),
+        )
+        .await;
+    }
 
-    Ok(())
+    Ok(!pruned.is_empty())
 }
 
 async fn active_backup_ids(
@@ -404,12 +542,16 @@ async fn active_backup_ids(
     Ok(active)
 }
 
+/// Record the outcome of the latest terminal backup Job, once. Returns the
+/// snapshots still to be written for a backup recorded by this call.
 async fn check_job_completion(
     client: &Client,
+    recorder: &Recorder,
+    metrics: &MetricsState,
     backup_api: &Api<KafkaBackup>,
     backup: &KafkaBackup,
     generation: i64,
-) -> Result<()> {
+) -> Result<Option<BackupSnapshotsInfo>> {
     let name = backup.name_any();
     let namespace = backup.namespace().unwrap_or_default();
     let jobs_api: Api<Job> = Api::namespaced(client.clone(), &namespace);
@@ -429,7 +571,7 @@ async fn check_job_completion(
                 .map(|t| t.0)
         });
     let Some(job) = latest_terminal else {
-        return Ok(());
+        return Ok(None);
     };
     let job_name = job.metadata.name.as_deref().unwrap_or("");
 
@@ -440,13 +582,13 @@ async fn check_job_completion(
             .and_then(|s| s.last_backup.as_ref())
             .is_some_and(|lb| lb.id == job_name);
         if already_recorded {
-            return Ok(());
+            return Ok(None);
         }
 
         info!(%job_name, "Backup job completed successfully");
         let now = Utc::now();
         let job_status = job.status.as_ref();
-        let history_entry = BackupHistoryEntry {
+        let mut history_entry = BackupHistoryEntry {
             id: job_name.to_string(),
             status: BackupStatus::Completed,
             start_time: job_status
@@ -462,34 +604,420 @@ async fn check_job_completion(
             size_bytes: None,
             topics_backed_up: None,
             partitions_backed_up: None,
+            oldest_timestamp: None,
+            newest_timestamp: None,
         };
+        let result = read_job_result(client, &namespace, job_name, "backup")
+            .await
+            .unwrap_or_default();
+        // Incremental backups write every run under the same backup ID
+        let backup_id = backup_id_for(backup, job_name);
+        // Sizes, record counts and record time bounds come from the manifest
+        // the Job wrote, else from the Job's result; the backup is recorded
+        // without them when neither has them.
+        let mut records = result.records;
+        match read_backup_details(client, &namespace, backup_storage(backup)?, &backup_id).await {
+            Ok((details, manifest_records)) => {
+                records = manifest_records.or(records);
+                history_entry.size_bytes = details.size_bytes;
+                history_entry.topics_backed_up = details.topics_backed_up;
+                history_entry.partitions_backed_up = details.partitions_backed_up;
+                history_entry.oldest_timestamp = details.oldest_timestamp;
+                history_entry.newest_timestamp = details.newest_timestamp;
+            }
+            Err(e) => {
+                warn!(%name, %backup_id, error = %e, "Failed to read the backup manifest");
+                history_entry.size_bytes = result.byte_count();
+                history_entry.topics_backed_up = result.topic_count();
+                history_entry.partitions_backed_up = result.partitions();
+            }
+        }
 
-        update_status_completed(backup_api, &name, generation, &history_entry).await?;
+        let snapshots = BackupSnapshotsInfo {
+            backup_id: backup_id.clone(),
+            missing: snapshot_files(backup),
+        };
+        update_status_completed(backup_api, &name, generation, &history_entry, &snapshots).await?;
+        let completed_at = history_entry.completion_time.unwrap_or(now);
+        metrics.record_backup_success(
+            &name,
+            &backup.spec.strimzi_cluster_ref.name,
+            records,
+            history_entry
+                .size_bytes
+                .and_then(|b| u64::try_from(b).ok())
+                .unwrap_or(0),
+            (completed_at - history_entry.start_time).num_milliseconds() as f64 / 1000.0,
+            completed_at,
+        );
+        let mut note = format!("Backup {job_name} completed");
+        if let (Some(size), Some(topics)) =
+            (history_entry.size_bytes, history_entry.topics_backed_up)
+        {
+            note = format!("{note}: {size} bytes in {topics} topics");
+        }
+        events::normal(
+            recorder,
+            REASON_BACKUP_COMPLETED,
+            ACTION_RECORD_RESULT,
+            note,
+        )
+        .await;
+        return Ok(Some(snapshots));
     } else {
-        let message = format!("Backup job {job_name} failed");
+        let mut message = format!("Backup job {job_name} failed");
         let already_recorded = backup
             .status
             .as_ref()
             .map(|s| s.conditions.as_slice())
             .and_then(|c| find_condition(c, CONDITION_TYPE_ERROR))
-            .is_some_and(|c| c.message.as_deref() == Some(message.as_str()));
+            .and_then(|c| c.message.as_deref())
+            .is_some_and(|m| m == message || m.starts_with(&format!("{message}: ")));
         if already_recorded {
-            return Ok(());
+            return Ok(None);
         }
 
         error!(%job_name, "Backup job failed");
+        if let Some(detail) = read_job_result(client, &namespace, job_name, "backup")
+            .await
+            .and_then(|r| r.failure_detail())
+        {
+            message = format!("{message}: {detail}");
+        }
         let mut status = current_backup_status(backup_api, &name).await?;
-        status.conditions = error_conditions(REASON_BACKUP_FAILED, &message);
+        replace_conditions(
+            &mut status.conditions,
+            error_conditions(REASON_BACKUP_FAILED, &message),
+        );
         status.observed_generation = Some(generation);
         patch_status(backup_api, &name, &status).await?;
+        metrics.record_backup_failure(&name, &backup.spec.strimzi_cluster_ref.name, Utc::now());
+        events::warning(
+            recorder,
+            REASON_BACKUP_FAILED,
+            ACTION_RECORD_RESULT,
+            message,
+        )
+        .await;
+    }
+
+    Ok(None)
+}
+
+/// Files stored next to the manifest of every backup of `backup`
+fn snapshot_files(backup: &KafkaBackup) -> Vec<String> {
+    let mut files = vec![KAFKA_CLUSTER_FILE, KAFKA_TOPICS_FILE];
+    if backup.spec.users.is_some() {
+        files.push(KAFKA_USERS_FILE);
+    }
+    if backup.spec.connect.is_some() {
+        files.push(KAFKA_CONNECTS_FILE);
+    }
+    if backup.spec.schema_registry.is_some() {
+        files.push(SCHEMAS_FILE);
+    }
+    files.into_iter().map(str::to_string).collect()
+}
+
+/// Write the files `snapshots` records as missing and record those still
+/// missing. The backup itself has succeeded without them, but restores that
+/// recreate Strimzi resources or import schemas need them, so a Warning
+/// Event reports the files missing after the first attempt and whenever that
+/// set changes.
+async fn write_missing_snapshots(
+    client: &Client,
+    recorder: &Recorder,
+    backup_api: &Api<KafkaBackup>,
+    backup: &KafkaBackup,
+    snapshots: BackupSnapshotsInfo,
+    first_attempt: bool,
+) -> Result<()> {
+    let name = backup.name_any();
+    let backup_id = &snapshots.backup_id;
+    let mut missing = Vec::new();
+    let mut first_error = None;
+    for file in &snapshots.missing {
+        let written = match file.as_str() {
+            KAFKA_CLUSTER_FILE => snapshot_kafka_cluster(client, backup, backup_id).await,
+            KAFKA_TOPICS_FILE => snapshot_kafka_topics(client, backup, backup_id).await,
+            KAFKA_USERS_FILE => snapshot_kafka_users(client, backup, backup_id).await,
+            KAFKA_CONNECTS_FILE => snapshot_kafka_connects(client, backup, backup_id).await,
+            SCHEMAS_FILE => export_schemas(client, backup, backup_id).await,
+            _ => Ok(()),
+        };
+        if let Err(e) = written {
+            warn!(%name, %backup_id, %file, error = %e, "Failed to store backup snapshot");
+            first_error.get_or_insert(e);
+            missing.push(file.clone());
+        }
+    }
+    let changed = missing != snapshots.missing;
+    if !changed && !first_attempt {
+        return Ok(());
+    }
+
+    if changed {
+        let patch = serde_json::json!({
+            "status": {"snapshots": BackupSnapshotsInfo {
+                backup_id: backup_id.clone(),
+                missing: missing.clone(),
+            }}
+        });
+        backup_api
+            .patch_status(
+                &name,
+                &PatchParams::apply("kafka-backup-operator"),
+                &Patch::Merge(&patch),
+            )
+            .await?;
+    }
+    if let Some(e) = first_error {
+        events::warning(
+            recorder,
+            REASON_SNAPSHOTS_INCOMPLETE,
+            ACTION_RECORD_RESULT,
+            format!(
+                "Backup {backup_id} is missing {}, retrying: {e}",
+                missing.join(", ")
+            ),
+        )
+        .await;
+    }
+    Ok(())
+}
+
+/// Store the Kafka CR and KafkaNodePools of the backed-up cluster next to the
+/// manifest of backup `backup_id`, so a restore can rebuild the cluster.
+async fn snapshot_kafka_cluster(
+    client: &Client,
+    backup: &KafkaBackup,
+    backup_id: &str,
+) -> Result<()> {
+    let namespace = backup.namespace().unwrap_or_default();
+    let cluster_ref = &backup.spec.strimzi_cluster_ref;
+    let cluster_namespace = cluster_ref.namespace.as_deref().unwrap_or(&namespace);
+
+    let cluster =
+        read_kafka_cluster_definition(client, cluster_namespace, &cluster_ref.name).await?;
+    let bytes = serde_json::to_vec_pretty(&cluster)?;
+    write_backup_file(
+        client,
+        &namespace,
+        backup_storage(backup)?,
+        backup_id,
+        KAFKA_CLUSTER_FILE,
+        bytes,
+    )
+    .await?;
+    info!(
+        backup_id,
+        node_pools = cluster.node_pools.len(),
+        "Stored Kafka cluster snapshot"
+    );
+    Ok(())
+}
+
+/// Store the KafkaTopics of the backed-up cluster selected by `spec.topics`
+/// next to the manifest of backup `backup_id`, so a restore can recreate them
+/// with their partitions, replicas and configs.
+async fn snapshot_kafka_topics(
+    client: &Client,
+    backup: &KafkaBackup,
+    backup_id: &str,
+) -> Result<()> {
+    let namespace = backup.namespace().unwrap_or_default();
+    let cluster_ref = &backup.spec.strimzi_cluster_ref;
+    let cluster_namespace = cluster_ref.namespace.as_deref().unwrap_or(&namespace);
+
+    let mut topics = Vec::new();
+    for topic in list_kafka_topic_definitions(client, cluster_namespace, &cluster_ref.name).await? {
+        let selected = match &backup.spec.topics {
+            Some(selection) => selection.matches(kafka_topic_name(&topic))?,
+            None => true,
+        };
+        if selected {
+            topics.push(topic);
+        }
+    }
+
+    let bytes = serde_json::to_vec_pretty(&topics)?;
+    write_backup_file(
+        client,
+        &namespace,
+        backup_storage(backup)?,
+        backup_id,
+        KAFKA_TOPICS_FILE,
+        bytes,
+    )
+    .await?;
+    info!(
+        backup_id,
+        topics = topics.len(),
+        "Stored KafkaTopic snapshot"
+    );
+    Ok(())
+}
+
+/// Store the KafkaUsers of the backed-up cluster selected by `spec.users`
+/// next to the manifest of backup `backup_id`. Does nothing when
+/// `spec.users` is unset.
+async fn snapshot_kafka_users(
+    client: &Client,
+    backup: &KafkaBackup,
+    backup_id: &str,
+) -> Result<()> {
+    let Some(selection) = &backup.spec.users else {
+        return Ok(());
+    };
+    let namespace = backup.namespace().unwrap_or_default();
+    let cluster_ref = &backup.spec.strimzi_cluster_ref;
+    let cluster_namespace = cluster_ref.namespace.as_deref().unwrap_or(&namespace);
+
+    let mut users = Vec::new();
+    for user in list_kafka_user_definitions(client, cluster_namespace, &cluster_ref.name).await? {
+        if selection.matches(user.name())? {
+            users.push(user);
+        }
     }
 
+    let bytes = serde_json::to_vec_pretty(&users)?;
+    write_backup_file(
+        client,
+        &namespace,
+        backup_storage(backup)?,
+        backup_id,
+        KAFKA_USERS_FILE,
+        bytes,
+    )
+    .await?;
+    info!(backup_id, users = users.len(), "Stored KafkaUser snapshot");
+    Ok(())
+}
+
+/// Store the KafkaConnect clusters selected by `spec.connect` and their
+/// KafkaConnectors next to the manifest of backup `backup_id`. Does nothing
+/// when `spec.connect` is unset.
+async fn snapshot_kafka_connects(
+    client: &Client,
+    backup: &KafkaBackup,
+    backup_id: &str,
+) -> Result<()> {
+    if backup.spec.connect.is_none() {
+        return Ok(());
+    }
+    let namespace = backup.namespace().unwrap_or_default();
+
+    let snapshot = KafkaConnectSnapshot {
+        kafka_cluster: backup.spec.strimzi_cluster_ref.name.clone(),
+        kafka_connects: selected_kafka_connects(client, backup).await?,
+    };
+    let bytes = serde_json::to_vec_pretty(&snapshot)?;
+    write_backup_file(
+        client,
+        &namespace,
+        backup_storage(backup)?,
+        backup_id,
+        KAFKA_CONNECTS_FILE,
+        bytes,
+    )
+    .await?;
+    info!(
+        backup_id,
+        kafka_connects = snapshot.kafka_connects.len(),
+        "Stored KafkaConnect snapshot"
+    );
+    Ok(())
+}
+
+/// Export the Schema Registry subjects selected by `spec.schemaRegistry`
+/// next to the manifest of backup `backup_id`. Does nothing when
+/// `spec.schemaRegistry` is unset.
+async fn export_schemas(client: &Client, backup: &KafkaBackup, backup_id: &str) -> Result<()> {
+    let Some(registry) = &backup.spec.schema_registry else {
+        return Ok(());
+    };
+    let namespace = backup.namespace().unwrap_or_default();
+
+    let export = SchemaRegistryClient::from_spec(client, &namespace, registry)
+        .await?
+        .export(registry.subjects.as_ref())
+        .await?;
+    let bytes = serde_json::to_vec_pretty(&export)?;
+    write_backup_file(
+        client,
+        &namespace,
+        backup_storage(backup)?,
+        backup_id,
+        SCHEMAS_FILE,
+        bytes,
+    )
+    .await?;
+    info!(
+        backup_id,
+        subjects = export.subjects.len(),
+        "Stored Schema Registry export"
+    );
     Ok(())
 }
 
+/// The KafkaConnect clusters of the backed-up cluster selected by
+/// `spec.connect`, with their KafkaConnectors
+async fn selected_kafka_connects(
+    client: &Client,
+    backup: &KafkaBackup,
+) -> Result<Vec<KafkaConnectDefinition>> {
+    let Some(selection) = &backup.spec.connect else {
+        return Ok(Vec::new());
+    };
+    let namespace = backup.namespace().unwrap_or_default();
+    let cluster_ref = &backup.spec.strimzi_cluster_ref;
+    let cluster_namespace = cluster_ref.namespace.as_deref().unwrap_or(&namespace);
+
+    let mut connects = Vec::new();
+    for connect in
+        list_kafka_connect_definitions(client, cluster_namespace, &cluster_ref.name).await?
+    {
+        if selection.matches(connect.name())? {
+            connects.push(connect);
+        }
+    }
+    Ok(connects)
+}
+
+/// The KafkaBackup to build the backup Job config from. With `spec.connect`
+/// and an explicit `spec.topics.include` list, the offset, config and status
+/// topics of the selected KafkaConnect clusters are added to the list, so
+/// connectors resume from their restored offsets.
+async fn with_connect_topics(client: &Client, backup: &KafkaBackup) -> Result<KafkaBackup> {
+    let mut backup = backup.clone();
+    let includes_all_topics = backup
+        .spec
+        .topics
+        .as_ref()
+        .is_none_or(|topics| topics.include.is_empty());
+    if backup.spec.connect.is_none() || includes_all_topics {
+        return Ok(backup);
+    }
+
+    let connects = selected_kafka_connects(client, &backup).await?;
+    if let Some(topics) = backup.spec.topics.as_mut() {
+        for connect in &connects {
+            for (_, topic) in connect_storage_topics(&connect.kafka_connect) {
+                if !topics.include.contains(&topic) {
+                    topics.include.push(topic);
+                }
+            }
+        }
+    }
+    Ok(backup)
+}
+
 async fn update_status_running(api: &Api<KafkaBackup>, name: &str, generation: i64) -> Result<()> {
     let mut status = current_backup_status(api, name).await?;
-    status.conditions = vec![not_ready(REASON_BACKUP_RUNNING, "Backup job is running")];
+    replace_conditions(
+        &mut status.conditions,
+        vec![not_ready(REASON_BACKUP_RUNNING, "Backup job is running")],
+    );
     status.observed_generation = Some(generation);
     patch_status(api, name, &status).await
 }
@@ -508,7 +1036,7 @@ async fn update_status_reconciliation_paused(
         return Ok(());
     }
 
-    status.conditions = vec![reconciliation_paused()];
+    replace_conditions(&mut status.conditions, vec![reconciliation_paused()]);
     status.observed_generation = Some(generation);
     patch_status(api, &name, &status).await
 }
@@ -517,22 +1045,30 @@ async fn update_status_scheduled(
     api: &Api<KafkaBackup>,
     name: &str,
     generation: i64,
-    next_backup: &str,
+    next_backup: DateTime<Utc>,
+    last_schedule: Option<DateTime<Utc>>,
 ) -> Result<()> {
+    let next_backup = next_backup.to_rfc3339_opts(SecondsFormat::Secs, true);
     let mut status = current_backup_status(api, name).await?;
+    let last_schedule = last_schedule.or(status.last_schedule_time);
     let already_current = find_condition(&status.conditions, CONDITION_TYPE_READY)
         .is_some_and(|c| c.reason.as_deref() == Some(REASON_BACKUP_SCHEDULED))
-        && status.next_scheduled_backup.as_deref() == Some(next_backup)
+        && status.next_scheduled_backup.as_deref() == Some(next_backup.as_str())
+        && status.last_schedule_time == last_schedule
         && status.observed_generation == Some(generation);
     if already_current {
         return Ok(());
     }
-    status.conditions = vec![ready(
-        REASON_BACKUP_SCHEDULED,
-        &format!("Next backup scheduled: {next_backup}"),
-    )];
+    replace_conditions(
+        &mut status.conditions,
+        vec![ready(
+            REASON_BACKUP_SCHEDULED,
+            &format!("Next backup scheduled at {next_backup}"),
+        )],
+    );
     status.observed_generation = Some(generation);
-    status.next_scheduled_backup = Some(next_backup.to_string());
+    status.next_scheduled_backup = Some(next_backup);
+    status.last_schedule_time = last_schedule;
     patch_status(api, name, &status).await
 }
 
@@ -540,23 +1076,34 @@ async fn update_status_suspended(
     api: &Api<KafkaBackup>,
     name: &str,
     generation: i64,
+    last_schedule: Option<DateTime<Utc>>,
 ) -> Result<()> {
     let current = current_backup_status(api, name).await?;
+    let last_schedule = last_schedule.or(current.last_schedule_time);
     let already_current = find_condition(&current.conditions, CONDITION_TYPE_READY)
         .is_some_and(|c| c.reason.as_deref() == Some(REASON_BACKUP_SUSPENDED))
         && current.next_scheduled_backup.is_none()
+        && current.last_schedule_time == last_schedule
         && current.observed_generation == Some(generation);
     if already_current {
         return Ok(());
     }
     // Manual merge patch: `nextScheduledBackup` must be an explicit null to be
     // cleared, but `KafkaBackupStatus` skips `None` fields when serializing.
-    let condition = ready(REASON_BACKUP_SUSPENDED, "Backup schedule is suspended");
+    let mut conditions = current.conditions;
+    replace_conditions(
+        &mut conditions,
+        vec![ready(
+            REASON_BACKUP_SUSPENDED,
+            "Backup schedule is suspended",
+        )],
+    );
     let patch = serde_json::json!({
         "status": {
-            "conditions": [condition],
+            "conditions": conditions,
             "observedGeneration": generation,
-            "nextScheduledBackup": null
+            "nextScheduledBackup": null,
+            "lastScheduleTime": last_schedule
         }
     });
     api.patch_status(
@@ -573,6 +1120,7 @@ async fn update_status_completed(
     name: &str,
     generation: i64,
     entry: &BackupHistoryEntry,
+    snapshots: &BackupSnapshotsInfo,
 ) -> Result<()> {
     let mut status = current_backup_status(api, name).await?;
     let last_backup = LastBackupInfo {
@@ -583,30 +1131,73 @@ async fn update_status_completed(
         size_bytes: entry.size_bytes,
         topics_backed_up: entry.topics_backed_up,
         partitions_backed_up: entry.partitions_backed_up,
-        oldest_timestamp: None,
-        newest_timestamp: None,
+        oldest_timestamp: entry.oldest_timestamp,
+        newest_timestamp: entry.newest_timestamp,
     };
 
-    status.conditions = vec![ready(
-        REASON_BACKUP_COMPLETED,
-        "Backup completed successfully",
-    )];
+    replace_conditions(
+        &mut status.conditions,
+        vec![ready(
+            REASON_BACKUP_COMPLETED,
+            "Backup completed successfully",
+        )],
+    );
     status.last_backup = Some(last_backup);
+    status.snapshots = Some(snapshots.clone());
     status.observed_generation = Some(generation);
     upsert_history_entry(&mut status.backup_history, entry.clone());
     patch_status(api, name, &status).await
 }
 
+/// Refresh the metrics derived from the status of `backup`
+fn record_backup_state(metrics: &MetricsState, backup: &KafkaBackup) {
+    let status = backup.status.as_ref();
+    let last_success = status
+        .and_then(|s| s.last_backup.as_ref())
+        .filter(|lb| lb.status == BackupStatus::Completed)
+        .and_then(|lb| lb.completion_time);
+    let storage_bytes = status
+        .into_iter()
+        .flat_map(|s| &s.backup_history)
+        .filter_map(|entry| entry.size_bytes)
+        .map(|size| u64::try_from(size).unwrap_or(0))
+        .sum();
+    metrics.record_backup_state(
+        &backup.name_any(),
+        &backup.spec.strimzi_cluster_ref.name,
+        last_success,
+        backup
+            .metadata
+            .creation_timestamp
+            .as_ref()
+            .map_or_else(Utc::now, |t| t.0),
+        storage_bytes,
+    );
+}
+
+/// Record `error` in the status, with a Warning Event the first time it is
+/// reported so retries do not repeat it
 async fn update_status_error(
     api: &Api<KafkaBackup>,
+    recorder: &Recorder,
     name: &str,
     generation: i64,
     error: &Error,
 ) -> Result<()> {
     let mut status = current_backup_status(api, name).await?;
-    status.conditions = error_conditions(error.reason(), &error.to_string());
+    let message = error.to_string();
+    let already_reported = find_condition(&status.conditions, CONDITION_TYPE_ERROR)
+        .is_some_and(|c| c.message.as_deref() == Some(message.as_str()));
+    replace_conditions(
+        &mut status.conditions,
+        error_conditions(error.reason(), &message),
+    );
     status.observed_generation = Some(generation);
-    patch_status(api, name, &status).await
+    patch_status(api, name, &status).await?;
+    if !already_reported {
+        events::warning(recorder, error.reason(), ACTION_RECONCILE, message).await;
+    }
+    Ok(())
 }
 
 async fn current_backup_status(api: &Api<KafkaBackup>, name: &str) -> Result<KafkaBackupStatus> {
@@ -671,17 +1262,18 @@ async fn apply_resource<
     api: &Api<T>,
     name: &str,
     resource: &T,
-) -> Result<()> {
+) -> Result<T> {
     let patch = serde_json::to_value(resource).map_err(Error::Serialization)?;
-    api.patch(
-        name,
-        // This resource is fully generated and owned by the KafkaBackup. A
-        // mutating policy or manual edit may otherwise take ownership of pod
-        // template fields and make every later reconciliation fail with a
-        // server-side apply conflict until the resource is deleted.
-        &PatchParams::apply("kafka-backup-operator").force(),
-        &Patch::Apply(patch),
-    )
-    .await?;
-    Ok(())
+    let applied = api
+        .patch(
+            name,
+            // This resource is fully generated and owned by the KafkaBackup. A
+            // mutating policy or manual edit may otherwise take ownership of pod
+            // template fields and make every later reconciliation fail with a
+            // server-side apply conflict until the resource is deleted.
+            &PatchParams::apply("kafka-backup-operator").force(),
+            &Patch::Apply(patch),
+        )
+        .await?;
+    Ok(applied)
 }
@@ -1,5 +1,9 @@
 pub mod backup;
 pub mod restore;
+pub mod schedule;
+pub mod snapshot;
+pub mod storage_location;
+pub mod verification;
 
 /// Finalizer name used by this operator
 pub const FINALIZER: &str = "kafkabackup.com/cleanup";
@@ -24,6 +28,16 @@ pub fn is_reconciliation_paused<K: kube::ResourceExt>(resource: &K) -> bool {
 /// pullable by Kubernetes.
 pub const DEFAULT_BACKUP_IMAGE: &str = "osodevops/kafka-backup:v0.15.12";
 
+/// Default `backoffLimit` of backup Jobs
+pub const DEFAULT_BACKUP_BACKOFF_LIMIT: i32 = 3;
+
+/// Default `backoffLimit` of restore Jobs. Restores are not retried unless
+/// asked for, since a retry can duplicate records.
+pub const DEFAULT_RESTORE_BACKOFF_LIMIT: i32 = 0;
+
+/// Default number of successful and failed Jobs kept by backup CronJobs
+pub const DEFAULT_JOBS_HISTORY_LIMIT: i32 = 3;
+
 /// Environment variable used by the Helm chart to pass the service account that
 /// backup/restore job pods should run as.
 pub const JOB_SERVICE_ACCOUNT_ENV: &str = "BACKUP_JOB_SERVICE_ACCOUNT";
@@ -43,13 +57,57 @@ pub fn cleanup_delete_params() -> kube::api::DeleteParams {
     kube::api::DeleteParams::background()
 }
 
+/// Service account backup/restore job pods run as, from the operator
+/// configuration
 pub fn job_service_account_name() -> Option<String> {
-    let value = std::env::var(JOB_SERVICE_ACCOUNT_ENV)
-        .unwrap_or_else(|_| DEFAULT_JOB_SERVICE_ACCOUNT.to_string());
-    let value = value.trim();
-    if value.is_empty() {
-        None
-    } else {
-        Some(value.to_string())
+    crate::config::current().job_service_account()
+}
+
+/// Longest value Kubernetes accepts for a label, and for the names of most
+/// objects that end up in one
+pub const MAX_LABEL_VALUE_LENGTH: usize = 63;
+
+/// `value` shortened to at most `max_len` characters. Longer values keep a
+/// prefix and end in a hash of the whole value, so distinct values stay
+/// distinct.
+pub fn truncate_with_hash(value: &str, max_len: usize) -> String {
+    if value.len() <= max_len {
+        return value.to_string();
+    }
+    let prefix: String = value.chars().take(max_len.saturating_sub(9)).collect();
+    let prefix = prefix.trim_end_matches(|c: char| !c.is_ascii_alphanumeric());
+    format!("{prefix}-{:08x}", fnv1a(value.as_bytes()))
+}
+
+/// `value`, usually an object name, made short enough to be a label value
+pub fn label_value(value: &str) -> String {
+    truncate_with_hash(value, MAX_LABEL_VALUE_LENGTH)
+}
+
+/// 32-bit FNV-1a, stable across builds unlike the std hashers
+pub(crate) fn fnv1a(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0x811c_9dc5, |hash, byte| {
+        (hash ^ u32::from(*byte)).wrapping_mul(0x0100_0193)
+    })
+}
+
+/// The result document `container` wrote in the pods of Job `job_name`.
+/// Results only add detail to the Job's outcome, so a failure to read them
+/// is logged and yields `None`.
+pub async fn read_job_result(
+    client: &kube::Client,
+    namespace: &str,
+    job_name: &str,
+    container: &str,
+) -> Option<crate::jobs::result::JobResult> {
+    let pods: kube::Api<k8s_openapi::api::core::v1::Pod> =
+        kube::Api::namespaced(client.clone(), namespace);
+    let lp = kube::api::ListParams::default().labels(&format!("job-name={job_name}"));
+    match pods.list(&lp).await {
+        Ok(pods) => crate::jobs::result::job_result_from_pods(&pods.items, container),
+        Err(e) => {
+            tracing::warn!(%job_name, error = %e, "Failed to read the Job's result");
+            None
+        }
     }
 }
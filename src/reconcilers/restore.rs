@@ -1,33 +1,70 @@
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use k8s_openapi::api::batch::v1::Job;
-use k8s_openapi::api::core::v1::ConfigMap;
+use k8s_openapi::api::core::v1::{ConfigMap, Secret};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{ObjectMeta, OwnerReference};
+use k8s_openapi::ByteString;
 use kube::{
     api::{Api, Patch, PatchParams, PostParams, ResourceExt},
+    runtime::events::Recorder,
     Client,
 };
-use tracing::{debug, error, info, warn};
+use tracing::{debug, error, info, info_span, warn, Instrument};
 
-use crate::adapters::restore_config::build_restore_config_yaml;
-use crate::crd::common::{Condition, RestoreInfo, RestoreStatus};
-use crate::crd::{KafkaBackup, KafkaRestore, KafkaRestoreStatus};
+use crate::adapters::backup_grant::check_backup_grant;
+use crate::adapters::restore_config::{build_restore_config_yaml, RestoreSource};
+use crate::adapters::schema_registry::{
+    mapped_subject, SchemaRegistryClient, SchemaRegistryExport,
+};
+use crate::adapters::secrets::{extract_secret_bytes, get_secret};
+use crate::adapters::storage_config::storage_secret_refs_mut;
+use crate::adapters::storage_location::{resolve_storage, with_resolved_storage};
+use crate::crd::common::{Condition, RestoreInfo, RestoreStatus, StorageSpec};
+use crate::crd::kafka_restore::TopicCreationPolicy;
+use crate::crd::{
+    BackupRef, BackupRefKind, KafkaBackup, KafkaBackupSnapshot, KafkaRestore, KafkaRestoreStatus,
+};
 use crate::error::{Error, Result};
 use crate::jobs::job_state::{classify_jobs, JobsState};
 use crate::jobs::restore_job::build_restore_job;
+use crate::jobs::result::{JobResult, TopicResult};
 use crate::metrics::prometheus::MetricsState;
 use crate::reconcilers::{
-    cleanup_delete_params, is_reconciliation_paused, job_service_account_name, FINALIZER,
+    cleanup_delete_params, is_reconciliation_paused, job_service_account_name, read_job_result,
+    FINALIZER,
+};
+use crate::retention::storage::{
+    read_backup_file, read_backup_segments, BackupTopicSegments, KAFKA_CLUSTER_FILE,
+    KAFKA_CONNECTS_FILE, KAFKA_TOPICS_FILE, KAFKA_USERS_FILE, SCHEMAS_FILE,
 };
 use crate::status::conditions::*;
-use crate::strimzi::kafka_cr::resolve_kafka_cluster;
-use crate::strimzi::kafka_user::resolve_auth;
+use crate::status::events::{self, ACTION_CREATE_JOB, ACTION_RECONCILE, ACTION_RECORD_RESULT};
+use crate::strimzi::definition::ResourceDefinition;
+use crate::strimzi::kafka_connect::{
+    apply_kafka_connect_definition, connect_storage_topics, is_cluster_bootstrap,
+    KafkaConnectDefinition, KafkaConnectSnapshot,
+};
+use crate::strimzi::kafka_cr::{
+    create_kafka_cluster, kafka_cluster_ready, resolve_kafka_cluster, KafkaClusterDefinition,
+    ResolvedKafkaCluster,
+};
+use crate::strimzi::kafka_topic::{
+    apply_kafka_topic_definition, kafka_topic_name, kafka_topic_resource_name, unready_kafka_topics,
+};
+use crate::strimzi::kafka_user::{apply_kafka_user_definition, resolve_auth};
 use crate::strimzi::tls::resolve_cluster_ca;
 
+/// Label on storage Secrets copied into a KafkaRestore's namespace, naming the
+/// namespace they were copied from
+pub const PROJECTED_SECRET_SOURCE_LABEL: &str = "kafkabackup.com/source-namespace";
+
 pub async fn reconcile_restore(
     restore: Arc<KafkaRestore>,
     client: Client,
-    _metrics: &MetricsState,
+    recorder: &Recorder,
+    metrics: &MetricsState,
 ) -> Result<()> {
     let name = restore.name_any();
     let namespace = restore
@@ -37,7 +74,7 @@ pub async fn reconcile_restore(
 
     // Check if being deleted
     if restore.metadata.deletion_timestamp.is_some() {
-        return handle_cleanup(&restore, &client, &namespace).await;
+        return handle_cleanup(&restore, &client, &namespace, metrics).await;
     }
 
     // Keep deletion cleanup available while paused, but otherwise perform no
@@ -82,12 +119,46 @@ pub async fn reconcile_restore(
     match classify_jobs(&jobs.items) {
         JobsState::Succeeded { job_name } => {
             info!(%job_name, "Restore job completed successfully");
+            // Recreate the KafkaConnect clusters stored with the backup now
+            // that their offset topics hold the restored data. Dry runs
+            // restore no data, so they deploy nothing. The status records
+            // it, so requeues do not apply them again.
+            let connects_restored = restore
+                .status
+                .as_ref()
+                .and_then(|s| s.kafka_connects_restored)
+                .unwrap_or(false);
+            if restore.spec.connect.is_some() && !is_dry_run(&restore) && !connects_restored {
+                if let Err(e) = restore_kafka_connects(&client, &namespace, &restore).await {
+                    update_status_error(&restore_api, recorder, &restore, generation, &e).await?;
+                    return Err(e);
+                }
+                let status = KafkaRestoreStatus {
+                    kafka_connects_restored: Some(true),
+                    ..Default::default()
+                };
+                patch_status(&restore_api, &name, &status).await?;
+            }
             let job_status = jobs
                 .items
                 .iter()
                 .find(|j| j.metadata.name.as_deref() == Some(job_name.as_str()))
                 .and_then(|j| j.status.as_ref());
             let now = Utc::now();
+            let mut result = read_job_result(&client, &namespace, &job_name, "restore")
+                .await
+                .unwrap_or_default();
+            // Counts the CLI does not report come from the manifest of the
+            // restored backup; the restore is recorded without them when it
+            // cannot be read.
+            if result.bytes.is_none() || result.topics.is_empty() {
+                match manifest_result(&client, &namespace, &restore).await {
+                    Ok(derived) => result.fill_from(derived),
+                    Err(e) => {
+                        warn!(%name, error = %e, "Failed to read the restored backup's manifest");
+                    }
+                }
+            }
             let restore_info = RestoreInfo {
                 start_time: job_status
                     .and_then(|s| s.start_time.as_ref())
@@ -100,13 +171,30 @@ pub async fn reconcile_restore(
                         .unwrap_or(now),
                 ),
                 status: RestoreStatus::Completed,
-                restored_topics: None,
-                restored_partitions: None,
-                restored_bytes: None,
-                point_in_time_target: None,
-                actual_point_in_time: None,
+                backup_id: recorded_backup_id(&restore),
+                restored_topics: result.topic_count(),
+                restored_partitions: result.partitions(),
+                restored_bytes: result.byte_count(),
+                point_in_time_target: point_in_time_target(&restore),
+                actual_point_in_time: result.point_in_time,
             };
             update_status_completed(&restore_api, &name, generation, &restore_info).await?;
+            let completed_at = restore_info.completion_time.unwrap_or(now);
+            metrics.record_restore_success(
+                &name,
+                &restore.spec.strimzi_cluster_ref.name,
+                result.records,
+                result.bytes.unwrap_or(0),
+                (completed_at - restore_info.start_time).num_milliseconds() as f64 / 1000.0,
+            );
+            events::normal(
+                recorder,
+                REASON_RESTORE_COMPLETED,
+                ACTION_RECORD_RESULT,
+                format!("Restore {job_name} completed"),
+            )
+            .await;
+            delete_projected_secrets(&client, &namespace, &name).await;
             return Ok(());
         }
         JobsState::Failed { job_name } => {
@@ -118,7 +206,22 @@ pub async fn reconcile_restore(
                 REASON_RESTORE_FAILED,
             ) {
                 error!(%job_name, "Restore job failed");
-                update_status_failed(&restore_api, &name, generation, &job_name).await?;
+                let mut message = format!("Restore job {job_name} failed");
+                if let Some(detail) = read_job_result(&client, &namespace, &job_name, "restore")
+                    .await
+                    .and_then(|r| r.failure_detail())
+                {
+                    message = format!("{message}: {detail}");
+                }
+                update_status_failed(&restore_api, &restore, generation, &message).await?;
+                events::warning(
+                    recorder,
+                    REASON_RESTORE_FAILED,
+                    ACTION_RECORD_RESULT,
+                    message,
+                )
+                .await;
+                delete_projected_secrets(&client, &namespace, &name).await;
             }
             return Ok(());
         }
@@ -128,7 +231,7 @@ pub async fn reconcile_restore(
                 CONDITION_TYPE_READY,
                 REASON_RESTORE_RUNNING,
             ) {
-                update_status_running(&restore_api, &name, generation).await?;
+                update_status_running(&restore_api, &restore, generation, None).await?;
             }
             return Ok(());
         }
@@ -137,38 +240,141 @@ pub async fn reconcile_restore(
 
     // No Job exists yet — resolve dependencies and create one.
 
-    // Step 1: Resolve source backup CR
-    let backup_api: Api<KafkaBackup> = Api::namespaced(client.clone(), &namespace);
-    let source_backup = backup_api
-        .get(&restore.spec.backup_ref.name)
+    // Step 1: Resolve the backup to restore from
+    let (restore, source) = match resolve_backup_ref(&client, &namespace, &restore)
+        .instrument(info_span!("resolve_backup_ref"))
         .await
-        .map_err(|_| Error::BackupNotFound {
-            name: restore.spec.backup_ref.name.clone(),
-        })?;
+    {
+        Ok(resolved) => resolved,
+        Err(e) => {
+            update_status_error(&restore_api, recorder, &restore, generation, &e).await?;
+            return Err(e);
+        }
+    };
+
+    // Step 2: With `createCluster`, rebuild a missing target cluster from the
+    // Kafka CR and KafkaNodePools stored with the backup, and hold the
+    // restore back until the Cluster Operator reports it Ready.
+    if creates_cluster(&restore) {
+        let cluster_ref = &restore.spec.strimzi_cluster_ref;
+        let cluster_namespace = cluster_ref.namespace.as_deref().unwrap_or(&namespace);
+        let waiting = match ensure_kafka_cluster(&client, &restore, &source).await {
+            Ok(ClusterState::Ready) => None,
+            Ok(ClusterState::NotReady) => Some(format!(
+                "Waiting for Kafka cluster {} in namespace {cluster_namespace} to be Ready",
+                cluster_ref.name
+            )),
+            Ok(ClusterState::WouldCreate) => Some(format!(
+                "Dry run: Kafka cluster {} does not exist in namespace {cluster_namespace} and would be created from backup {}",
+                cluster_ref.name, source.backup_id
+            )),
+            Err(e) => {
+                update_status_error(&restore_api, recorder, &restore, generation, &e).await?;
+                return Err(e);
+            }
+        };
+        if let Some(message) = waiting {
+            update_status_waiting(
+                &restore_api,
+                &restore,
+                generation,
+                REASON_WAITING_FOR_CLUSTER,
+                &message,
+            )
+            .await?;
+            debug!(%name, cluster = %cluster_ref.name, "Waiting for Kafka cluster to be Ready");
+            return Ok(());
+        }
+    }
 
-    // Step 2: Resolve target Strimzi Kafka cluster
+    // Step 3: Resolve target Strimzi Kafka cluster
     let kafka_cluster = match resolve_kafka_cluster(
         &client,
         &restore.spec.strimzi_cluster_ref,
         &namespace,
         restore.spec.authentication.as_ref().map(|a| &a.auth_type),
     )
+    .instrument(
+        info_span!("resolve_kafka_cluster", cluster = %restore.spec.strimzi_cluster_ref.name),
+    )
     .await
     {
         Ok(cluster) => cluster,
         Err(e) => {
-            update_status_error(&restore_api, &name, generation, &e).await?;
+            update_status_error(&restore_api, recorder, &restore, generation, &e).await?;
             return Err(e);
         }
     };
 
-    // Step 3: Resolve TLS certificates
+    // Step 4: Recreate the KafkaUsers stored with the backup, so clients
+    // regain their ACLs and quotas on the target cluster. Dry runs write
+    // nothing.
+    if restore.spec.users.is_some() && !is_dry_run(&restore) {
+        if let Err(e) = apply_kafka_users(&client, &restore, &source, &kafka_cluster).await {
+            update_status_error(&restore_api, recorder, &restore, generation, &e).await?;
+            return Err(e);
+        }
+    }
+
+    // Step 5: Import the Schema Registry subjects stored with the backup, so
+    // restored records can be deserialized. Dry runs write nothing. The
+    // status records the import, so requeues while waiting for topics do
+    // not repeat it.
+    let schemas_imported = restore
+        .status
+        .as_ref()
+        .and_then(|s| s.schemas_imported)
+        .unwrap_or(false);
+    if restore.spec.schema_registry.is_some() && !is_dry_run(&restore) && !schemas_imported {
+        if let Err(e) = import_schemas(&client, &restore, &source).await {
+            update_status_error(&restore_api, recorder, &restore, generation, &e).await?;
+            return Err(e);
+        }
+        let status = KafkaRestoreStatus {
+            schemas_imported: Some(true),
+            ..Default::default()
+        };
+        patch_status(&restore_api, &name, &status).await?;
+    }
+
+    // Step 6: With `topicCreation: strimzi`, recreate the backed-up
+    // KafkaTopics and hold the Job back until the Topic Operator reports
+    // them Ready, so records land in topics with their original layout.
+    // Dry runs neither create the topics nor wait for them.
+    if uses_strimzi_topic_creation(&restore) && !is_dry_run(&restore) {
+        match ensure_kafka_topics(&client, &restore, &source, &kafka_cluster).await {
+            Ok(unready) if unready.is_empty() => {}
+            Ok(unready) => {
+                let message = format!(
+                    "Waiting for KafkaTopics to be Ready: {}",
+                    unready.join(", ")
+                );
+                update_status_waiting(
+                    &restore_api,
+                    &restore,
+                    generation,
+                    REASON_WAITING_FOR_TOPICS,
+                    &message,
+                )
+                .await?;
+                debug!(%name, topics = unready.len(), "Waiting for KafkaTopics to be Ready");
+                return Ok(());
+            }
+            Err(e) => {
+                update_status_error(&restore_api, recorder, &restore, generation, &e).await?;
+                return Err(e);
+            }
+        }
+    }
+
+    // Step 7: Resolve TLS certificates
     let tls_certs = match resolve_cluster_ca(
         &client,
         &kafka_cluster.name,
         restore.spec.strimzi_cluster_ref.ca_secret.as_ref(),
         &namespace,
     )
+    .instrument(info_span!("resolve_cluster_ca"))
     .await
     {
         Ok(certs) => Some(certs),
@@ -178,14 +384,15 @@ pub async fn reconcile_restore(
         }
     };
 
-    // Step 4: Resolve authentication
-    let resolved_auth =
-        resolve_auth(&client, restore.spec.authentication.as_ref(), &namespace).await?;
+    // Step 8: Resolve authentication
+    let resolved_auth = resolve_auth(&client, restore.spec.authentication.as_ref(), &namespace)
+        .instrument(info_span!("resolve_auth"))
+        .await?;
 
-    // Step 5: Build restore config YAML and create ConfigMap
+    // Step 9: Build restore config YAML and create ConfigMap
     let config_yaml = build_restore_config_yaml(
         &restore,
-        &source_backup,
+        &source,
         &kafka_cluster,
         &tls_certs,
         &resolved_auth,
@@ -198,32 +405,875 @@ pub async fn reconcile_restore(
         &config_yaml,
         &restore,
     )
+    .instrument(info_span!("apply_config_map", config_map = %config_map_name))
     .await?;
 
-    // Step 6: Create the restore Job
+    // Step 10: Create the restore Job
     let job_name = format!("{name}-{}", Utc::now().format("%Y%m%d-%H%M%S"));
     let job_service_account = job_service_account_name();
-    let job = build_restore_job(
-        &restore,
-        &job_name,
-        &config_map_name,
-        &kafka_cluster,
-        &resolved_auth,
-        &source_backup,
-        job_service_account.as_deref(),
-    )?;
+    // The Job is built inside the span so that it carries the span's trace
+    // context
+    let span = info_span!("create_job", job = %job_name);
+    let job = span.in_scope(|| {
+        build_restore_job(
+            &restore,
+            &job_name,
+            &config_map_name,
+            &kafka_cluster,
+            &resolved_auth,
+            &source,
+            job_service_account.as_deref(),
+        )
+    })?;
 
     jobs_api
         .create(&PostParams::default(), &job)
+        .instrument(span)
         .await
         .map_err(|e| Error::JobCreationFailed(e.to_string()))?;
 
     info!(%job_name, "Created restore job");
-    update_status_running(&restore_api, &name, generation).await?;
+    events::normal(
+        recorder,
+        REASON_RESTORE_RUNNING,
+        ACTION_CREATE_JOB,
+        format!("Created restore Job {job_name}"),
+    )
+    .await;
+    update_status_running(&restore_api, &restore, generation, Some(&source.backup_id)).await?;
+
+    Ok(())
+}
+
+fn creates_cluster(restore: &KafkaRestore) -> bool {
+    restore
+        .spec
+        .restore
+        .as_ref()
+        .and_then(|opts| opts.create_cluster)
+        .unwrap_or(false)
+}
+
+/// The target cluster of a restore with `createCluster`
+enum ClusterState {
+    Ready,
+    NotReady,
+    /// Missing, and a dry run leaves it to be created by the real restore
+    WouldCreate,
+}
+
+/// Create the target cluster from the definitions stored with the backup
+/// when its Kafka CR does not exist, and report whether it is Ready. Dry
+/// runs only check that the backup holds a usable cluster snapshot.
+async fn ensure_kafka_cluster(
+    client: &Client,
+    restore: &KafkaRestore,
+    source: &RestoreSource,
+) -> Result<ClusterState> {
+    let namespace = restore
+        .namespace()
+        .ok_or(Error::MissingObjectKey(".metadata.namespace"))?;
+    let cluster_ref = &restore.spec.strimzi_cluster_ref;
+    let cluster_namespace = cluster_ref.namespace.as_deref().unwrap_or(&namespace);
+
+    match kafka_cluster_ready(client, cluster_namespace, &cluster_ref.name).await? {
+        Some(true) => Ok(ClusterState::Ready),
+        Some(false) => Ok(ClusterState::NotReady),
+        None => {
+            let bytes = read_backup_file(
+                client,
+                &namespace,
+                &source.storage,
+                &source.backup_id,
+                KAFKA_CLUSTER_FILE,
+            )
+            .await?
+            .ok_or_else(|| {
+                Error::InvalidConfig(format!(
+                    "backup {} has no Kafka cluster snapshot to recreate {} from",
+                    source.backup_id, cluster_ref.name
+                ))
+            })?;
+            let definition: KafkaClusterDefinition =
+                serde_json::from_slice(&bytes).map_err(|e| {
+                    Error::Storage(format!(
+                        "invalid Kafka cluster snapshot for backup {}: {e}",
+                        source.backup_id
+                    ))
+                })?;
+            if is_dry_run(restore) {
+                return Ok(ClusterState::WouldCreate);
+            }
+            create_kafka_cluster(client, cluster_namespace, &cluster_ref.name, &definition).await?;
+            Ok(ClusterState::NotReady)
+        }
+    }
+}
+
+fn uses_strimzi_topic_creation(restore: &KafkaRestore) -> bool {
+    restore
+        .spec
+        .restore
+        .as_ref()
+        .and_then(|opts| opts.topic_creation.as_ref())
+        == Some(&TopicCreationPolicy::Strimzi)
+}
+
+/// Apply the KafkaTopics stored with the backup to the target cluster and
+/// return the names of those not yet Ready
+async fn ensure_kafka_topics(
+    client: &Client,
+    restore: &KafkaRestore,
+    source: &RestoreSource,
+    kafka_cluster: &ResolvedKafkaCluster,
+) -> Result<Vec<String>> {
+    let name = restore.name_any();
+    let namespace = restore
+        .namespace()
+        .ok_or(Error::MissingObjectKey(".metadata.namespace"))?;
+
+    let backed_up = read_backed_up_definitions(
+        client,
+        &namespace,
+        source,
+        KAFKA_TOPICS_FILE,
+        "KafkaTopic",
+        "use topicCreation auto or manual to restore it",
+    )
+    .await?;
+
+    let topics = restored_kafka_topics(restore, backed_up)?;
+    for topic in &topics {
+        apply_kafka_topic_definition(client, &kafka_cluster.namespace, &kafka_cluster.name, topic)
+            .await?;
+    }
+    if !topics.is_empty() {
+        info!(%name, topics = topics.len(), cluster = %kafka_cluster.name, "Applied KafkaTopics from backup");
+    }
+
+    let names: Vec<String> = topics
+        .iter()
+        .filter_map(|topic| topic.metadata.name.clone())
+        .collect();
+    let selector =
+        kube::api::ListParams::default().labels(&format!("kafkabackup.com/restore={name}"));
+    unready_kafka_topics(client, &kafka_cluster.namespace, &selector, &names).await
+}
+
+/// Apply the KafkaUsers stored with the backup and selected by `spec.users`
+/// to the target cluster
+async fn apply_kafka_users(
+    client: &Client,
+    restore: &KafkaRestore,
+    source: &RestoreSource,
+    kafka_cluster: &ResolvedKafkaCluster,
+) -> Result<()> {
+    let name = restore.name_any();
+    let namespace = restore
+        .namespace()
+        .ok_or(Error::MissingObjectKey(".metadata.namespace"))?;
+
+    let backed_up = read_backed_up_definitions(
+        client,
+        &namespace,
+        source,
+        KAFKA_USERS_FILE,
+        "KafkaUser",
+        "set spec.users on the KafkaBackup to store them",
+    )
+    .await?;
+    let users = restored_kafka_users(restore, backed_up)?;
+    for user in &users {
+        apply_kafka_user_definition(client, &kafka_cluster.namespace, &kafka_cluster.name, user)
+            .await?;
+    }
+    if !users.is_empty() {
+        info!(%name, users = users.len(), cluster = %kafka_cluster.name, "Applied KafkaUsers from backup");
+    }
+    Ok(())
+}
+
+/// Counts of a completed restore derived from the manifest of the backup its
+/// Job restored
+async fn manifest_result(
+    client: &Client,
+    namespace: &str,
+    restore: &KafkaRestore,
+) -> Result<JobResult> {
+    let (restore, source) = resolve_restored_backup(client, namespace, restore).await?;
+    let topics =
+        read_backup_segments(client, namespace, &source.storage, &source.backup_id).await?;
+    restored_from_manifest(&restore, topics)
+}
+
+/// What a restore writes of the backup whose manifest records `topics`: the
+/// topics selected by `spec.topics`, under their `topicMapping` names, and
+/// their segments within `spec.pointInTime`. Bytes are the compressed size of
+/// those segments. For point-in-time restores, the point in time reached is
+/// the newest record time of those segments, capped at `pointInTime.timestamp`.
+pub fn restored_from_manifest(
+    restore: &KafkaRestore,
+    topics: Vec<BackupTopicSegments>,
+) -> Result<JobResult> {
+    let window = restore.spec.point_in_time.as_ref();
+    let window_start = window
+        .and_then(|w| w.start_timestamp.as_deref())
+        .map(parse_timestamp)
+        .transpose()?;
+    let window_end = point_in_time_target(restore);
+    let in_window = |start: Option<DateTime<Utc>>, end: Option<DateTime<Utc>>| {
+        window_start.is_none_or(|ws| end.is_none_or(|end| end >= ws))
+            && window_end.is_none_or(|we| start.is_none_or(|start| start <= we))
+    };
+
+    let mut result = JobResult::default();
+    let mut reached = None;
+    for topic in topics {
+        if let Some(selection) = &restore.spec.topics {
+            if !selection.matches(&topic.name)? {
+                continue;
+            }
+        }
+        let segments: Vec<_> = topic
+            .segments
+            .iter()
+            .filter(|s| in_window(s.start_timestamp, s.end_timestamp))
+            .collect();
+        reached = reached.max(segments.iter().filter_map(|s| s.end_timestamp).max());
+        let name = restore
+            .spec
+            .topic_mapping
+            .iter()
+            .find(|m| m.source_topic == topic.name)
+            .map_or(topic.name, |m| m.target_topic.clone());
+        result.topics.push(TopicResult {
+            name,
+            partitions: i32::try_from(topic.partitions).ok(),
+            records: segments.iter().map(|s| s.record_count).sum(),
+            bytes: Some(segments.iter().map(|s| s.compressed_size).sum()),
+        });
+    }
+    result.records = result.topics.iter().map(|t| t.records).sum();
+    result.bytes = Some(result.topics.iter().filter_map(|t| t.bytes).sum());
+    if window.is_some() {
+        result.point_in_time = match window_end {
+            Some(end) => reached.map(|r| r.min(end)),
+            None => reached,
+        };
+    }
+    Ok(result)
+}
+
+/// `pointInTime.timestamp` of a restore, the time it restores up to
+fn point_in_time_target(restore: &KafkaRestore) -> Option<DateTime<Utc>> {
+    restore
+        .spec
+        .point_in_time
+        .as_ref()
+        .and_then(|p| p.timestamp.as_deref())
+        .and_then(|t| parse_timestamp(t).ok())
+}
+
+fn parse_timestamp(timestamp: &str) -> Result<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(timestamp)
+        .map(|t| t.with_timezone(&Utc))
+        .map_err(|_| Error::InvalidConfig(format!("Invalid timestamp format: {timestamp}")))
+}
+
+/// Recreate the KafkaConnect clusters stored with the backup once the
+/// restore Job has succeeded, resolving the target cluster again
+async fn restore_kafka_connects(
+    client: &Client,
+    namespace: &str,
+    restore: &KafkaRestore,
+) -> Result<()> {
+    let (restore, source) = resolve_restored_backup(client, namespace, restore).await?;
+    let kafka_cluster = resolve_kafka_cluster(
+        client,
+        &restore.spec.strimzi_cluster_ref,
+        namespace,
+        restore.spec.authentication.as_ref().map(|a| &a.auth_type),
+    )
+    .await?;
+    apply_kafka_connects(client, &restore, &source, &kafka_cluster).await
+}
+
+/// Apply the KafkaConnect clusters stored with the backup and selected by
+/// `spec.connect`, with their KafkaConnectors paused, next to the target
+/// cluster
+async fn apply_kafka_connects(
+    client: &Client,
+    restore: &KafkaRestore,
+    source: &RestoreSource,
+    kafka_cluster: &ResolvedKafkaCluster,
+) -> Result<()> {
+    let name = restore.name_any();
+    let namespace = restore
+        .namespace()
+        .ok_or(Error::MissingObjectKey(".metadata.namespace"))?;
+
+    let bytes = read_backup_file(
+        client,
+        &namespace,
+        &source.storage,
+        &source.backup_id,
+        KAFKA_CONNECTS_FILE,
+    )
+    .await?
+    .ok_or_else(|| {
+        Error::InvalidConfig(format!(
+            "backup {} has no KafkaConnect snapshot; set spec.connect on the KafkaBackup to store them",
+            source.backup_id
+        ))
+    })?;
+    let snapshot: KafkaConnectSnapshot = serde_json::from_slice(&bytes).map_err(|e| {
+        Error::Storage(format!(
+            "invalid KafkaConnect snapshot for backup {}: {e}",
+            source.backup_id
+        ))
+    })?;
+
+    let connects = restored_kafka_connects(restore, snapshot, &kafka_cluster.name)?;
+    for connect in &connects {
+        apply_kafka_connect_definition(client, &kafka_cluster.namespace, connect).await?;
+    }
+    if !connects.is_empty() {
+        info!(%name, kafka_connects = connects.len(), cluster = %kafka_cluster.name, "Applied KafkaConnects from backup");
+    }
+    Ok(())
+}
+
+fn is_dry_run(restore: &KafkaRestore) -> bool {
+    restore
+        .spec
+        .restore
+        .as_ref()
+        .and_then(|opts| opts.dry_run)
+        .unwrap_or(false)
+}
+
+/// Import the Schema Registry subjects stored with the backup and selected by
+/// `spec.schemaRegistry.subjects` into the registry of `spec.schemaRegistry`
+async fn import_schemas(
+    client: &Client,
+    restore: &KafkaRestore,
+    source: &RestoreSource,
+) -> Result<()> {
+    let Some(registry) = &restore.spec.schema_registry else {
+        return Ok(());
+    };
+    let name = restore.name_any();
+    let namespace = restore
+        .namespace()
+        .ok_or(Error::MissingObjectKey(".metadata.namespace"))?;
+
+    let bytes = read_backup_file(
+        client,
+        &namespace,
+        &source.storage,
+        &source.backup_id,
+        SCHEMAS_FILE,
+    )
+    .await?
+    .ok_or_else(|| {
+        Error::InvalidConfig(format!(
+            "backup {} has no Schema Registry export; set spec.schemaRegistry on the KafkaBackup to store one",
+            source.backup_id
+        ))
+    })?;
+    let export: SchemaRegistryExport = serde_json::from_slice(&bytes).map_err(|e| {
+        Error::Storage(format!(
+            "invalid Schema Registry export for backup {}: {e}",
+            source.backup_id
+        ))
+    })?;
 
+    let registry_client = SchemaRegistryClient::from_spec(client, &namespace, registry).await?;
+    let mut registered = 0;
+    for subject in &export.subjects {
+        if let Some(selection) = &registry.subjects {
+            if !selection.matches(&subject.subject)? {
+                continue;
+            }
+        }
+        let target = mapped_subject(&subject.subject, &restore.spec.topic_mapping);
+        registered += registry_client.import_subject(subject, &target).await?;
+    }
+    info!(%name, versions = registered, "Imported Schema Registry subjects from backup");
     Ok(())
 }
 
+/// Read the `kind` definitions stored in `file_name` with the backup being
+/// restored. `hint` tells the user what to do when the backup has none.
+async fn read_backed_up_definitions(
+    client: &Client,
+    namespace: &str,
+    source: &RestoreSource,
+    file_name: &str,
+    kind: &str,
+    hint: &str,
+) -> Result<Vec<ResourceDefinition>> {
+    let bytes = read_backup_file(
+        client,
+        namespace,
+        &source.storage,
+        &source.backup_id,
+        file_name,
+    )
+    .await?
+    .ok_or_else(|| {
+        Error::InvalidConfig(format!(
+            "backup {} has no {kind} snapshot; {hint}",
+            source.backup_id
+        ))
+    })?;
+    serde_json::from_slice(&bytes).map_err(|e| {
+        Error::Storage(format!(
+            "invalid {kind} snapshot for backup {}: {e}",
+            source.backup_id
+        ))
+    })
+}
+
+/// The KafkaUsers a restore with `spec.users` creates from those stored with
+/// the backup: filtered by `spec.users`, with literal topic ACLs renamed by
+/// `spec.topicMapping`, and labelled with the restore's name. Prefix ACLs are
+/// kept as they are.
+pub fn restored_kafka_users(
+    restore: &KafkaRestore,
+    backed_up: Vec<ResourceDefinition>,
+) -> Result<Vec<ResourceDefinition>> {
+    let Some(selection) = &restore.spec.users else {
+        return Ok(Vec::new());
+    };
+
+    let mut users = Vec::new();
+    for mut user in backed_up {
+        if !selection.matches(user.name())? {
+            continue;
+        }
+        let acls = user
+            .spec
+            .pointer_mut("/authorization/acls")
+            .and_then(|acls| acls.as_array_mut());
+        for acl in acls.into_iter().flatten() {
+            let Some(resource) = acl.get_mut("resource") else {
+                continue;
+            };
+            let is_literal_topic = resource.get("type").and_then(|t| t.as_str()) == Some("topic")
+                && resource
+                    .get("patternType")
+                    .and_then(|p| p.as_str())
+                    .is_none_or(|p| p == "literal");
+            if !is_literal_topic {
+                continue;
+            }
+            let mapped = resource
+                .get("name")
+                .and_then(|n| n.as_str())
+                .and_then(|topic| {
+                    restore
+                        .spec
+                        .topic_mapping
+                        .iter()
+                        .find(|m| m.source_topic == topic)
+                });
+            if let Some(mapping) = mapped {
+                resource["name"] = mapping.target_topic.clone().into();
+            }
+        }
+        user.set_label("kafkabackup.com/restore", &restore.name_any());
+        users.push(user);
+    }
+    Ok(users)
+}
+
+/// The KafkaConnect clusters a restore with `spec.connect` creates from those
+/// stored with the backup, filtered by `spec.connect`. Bootstrap servers and
+/// the cluster CA Secret of the backed-up cluster are pointed at
+/// `target_cluster`, internal topics are renamed by `spec.topicMapping`, and
+/// every KafkaConnector is paused. All resources are labelled with the
+/// restore's name.
+pub fn restored_kafka_connects(
+    restore: &KafkaRestore,
+    snapshot: KafkaConnectSnapshot,
+    target_cluster: &str,
+) -> Result<Vec<KafkaConnectDefinition>> {
+    let Some(selection) = &restore.spec.connect else {
+        return Ok(Vec::new());
+    };
+    let restore_name = restore.name_any();
+    let source_cluster = snapshot.kafka_cluster.as_str();
+
+    let mut connects = Vec::new();
+    for mut connect in snapshot.kafka_connects {
+        if !selection.matches(connect.name())? {
+            continue;
+        }
+        let spec = &mut connect.kafka_connect.spec;
+
+        let bootstrap_servers = spec
+            .get("bootstrapServers")
+            .and_then(|servers| servers.as_str())
+            .map(|servers| {
+                servers
+                    .split(',')
+                    .map(|server| {
+                        if !is_cluster_bootstrap(server, source_cluster) {
+                            return server.to_string();
+                        }
+                        match server.trim().split_once(':') {
+                            Some((_, port)) => format!("{target_cluster}-kafka-bootstrap:{port}"),
+                            None => format!("{target_cluster}-kafka-bootstrap"),
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join(",")
+            });
+        if let Some(bootstrap_servers) = bootstrap_servers {
+            spec["bootstrapServers"] = bootstrap_servers.into();
+        }
+
+        let source_ca = format!("{source_cluster}-cluster-ca-cert");
+        let trusted_certificates = spec
+            .pointer_mut("/tls/trustedCertificates")
+            .and_then(|certs| certs.as_array_mut());
+        for certificate in trusted_certificates.into_iter().flatten() {
+            if certificate.get("secretName").and_then(|s| s.as_str()) == Some(source_ca.as_str()) {
+                certificate["secretName"] = format!("{target_cluster}-cluster-ca-cert").into();
+            }
+        }
+
+        for (key, topic) in connect_storage_topics(&connect.kafka_connect) {
+            let mapped = restore
+                .spec
+                .topic_mapping
+                .iter()
+                .find(|m| m.source_topic == topic);
+            if let Some(mapping) = mapped {
+                let spec = &mut connect.kafka_connect.spec;
+                if !spec.get("config").is_some_and(|c| c.is_object()) {
+                    spec["config"] = serde_json::json!({});
+                }
+                spec["config"][key] = mapping.target_topic.clone().into();
+            }
+        }
+        connect
+            .kafka_connect
+            .set_label("kafkabackup.com/restore", &restore_name);
+
+        for connector in &mut connect.connectors {
+            if !connector.spec.is_object() {
+                connector.spec = serde_json::json!({});
+            }
+            if let Some(spec) = connector.spec.as_object_mut() {
+                spec.remove("pause");
+                spec.insert("state".to_string(), "paused".into());
+            }
+            connector.set_label("kafkabackup.com/restore", &restore_name);
+        }
+        connects.push(connect);
+    }
+    Ok(connects)
+}
+
+/// The KafkaTopics a `topicCreation: strimzi` restore creates from those
+/// stored with the backup: filtered by `spec.topics`, renamed by
+/// `spec.topicMapping`, and labelled with the restore's name.
+pub fn restored_kafka_topics(
+    restore: &KafkaRestore,
+    backed_up: Vec<ResourceDefinition>,
+) -> Result<Vec<ResourceDefinition>> {
+    let replicas = restore
+        .spec
+        .restore
+        .as_ref()
+        .and_then(|opts| opts.default_replication_factor);
+
+    let mut topics = Vec::new();
+    for mut topic in backed_up {
+        let source_topic = kafka_topic_name(&topic).to_string();
+        if let Some(selection) = &restore.spec.topics {
+            if !selection.matches(&source_topic)? {
+                continue;
+            }
+        }
+
+        let target_topic = restore
+            .spec
+            .topic_mapping
+            .iter()
+            .find(|m| m.source_topic == source_topic)
+            .map_or(source_topic.as_str(), |m| m.target_topic.as_str())
+            .to_string();
+        if target_topic != source_topic {
+            topic.metadata.name = Some(kafka_topic_resource_name(&target_topic));
+        }
+
+        if !topic.spec.is_object() {
+            topic.spec = serde_json::json!({});
+        }
+        topic.spec["topicName"] = target_topic.into();
+        if let Some(replicas) = replicas {
+            topic.spec["replicas"] = replicas.into();
+        }
+        topic.set_label("kafkabackup.com/restore", &restore.name_any());
+        topics.push(topic);
+    }
+    Ok(topics)
+}
+
+/// The backup ID of a backupRef that points at storage directly through
+/// `storage` or `storageRef`, or `None` for a reference to a KafkaBackup or
+/// KafkaBackupSnapshot
+pub(crate) fn direct_backup_id(backup_ref: &BackupRef) -> Result<Option<String>> {
+    if backup_ref.storage.is_none() && backup_ref.storage_ref.is_none() {
+        return Ok(None);
+    }
+    if backup_ref.kind.is_some() || backup_ref.name.is_some() || backup_ref.namespace.is_some() {
+        return Err(Error::InvalidConfig(
+            "backupRef.kind, backupRef.name and backupRef.namespace must be omitted when backupRef.storage or backupRef.storageRef is set"
+                .to_string(),
+        ));
+    }
+    backup_ref.backup_id.clone().map(Some).ok_or_else(|| {
+        Error::InvalidConfig(
+            "backupRef.backupId is required when backupRef.storage or backupRef.storageRef is set"
+                .to_string(),
+        )
+    })
+}
+
+/// Resolve `spec.backupRef` to the storage and backup ID to restore from.
+///
+/// A KafkaBackupSnapshot reference is rewritten into a reference to the
+/// KafkaBackup and backup ID the snapshot records. A reference carrying
+/// `storage` or `storageRef` restores straight from storage, with no
+/// KafkaBackup CR needed — for example on a freshly built DR cluster.
+///
+/// A reference into another namespace must be allowed by a KafkaBackupGrant
+/// there. The storage Secrets it needs are copied into the restore's
+/// namespace, since the restore Job can only mount Secrets from its own.
+async fn resolve_backup_ref(
+    client: &Client,
+    namespace: &str,
+    restore: &KafkaRestore,
+) -> Result<(KafkaRestore, RestoreSource)> {
+    resolve_source(client, namespace, restore, true).await
+}
+
+/// Resolve the backup a restore Job has restored: the backup ID recorded in
+/// `status.restore` when the Job was created, not the latest backup of the
+/// KafkaBackup, which may have changed since. The storage Secrets copied for
+/// the Job are reused rather than copied again.
+async fn resolve_restored_backup(
+    client: &Client,
+    namespace: &str,
+    restore: &KafkaRestore,
+) -> Result<(KafkaRestore, RestoreSource)> {
+    let mut pinned = restore.clone();
+    if let Some(backup_id) = recorded_backup_id(restore) {
+        pinned.spec.backup_ref.backup_id = Some(backup_id);
+    }
+    resolve_source(client, namespace, &pinned, false).await
+}
+
+/// The backup ID recorded in `status.restore` when the restore Job was created
+fn recorded_backup_id(restore: &KafkaRestore) -> Option<String> {
+    restore
+        .status
+        .as_ref()
+        .and_then(|s| s.restore.as_ref())
+        .and_then(|info| info.backup_id.clone())
+}
+
+/// [`resolve_backup_ref`], pointing cross-namespace storage at the copies of
+/// its Secrets, which are only created when `copy_secrets` is set
+async fn resolve_source(
+    client: &Client,
+    namespace: &str,
+    restore: &KafkaRestore,
+    copy_secrets: bool,
+) -> Result<(KafkaRestore, RestoreSource)> {
+    let backup_ref = &restore.spec.backup_ref;
+    let mut resolved = restore.clone();
+
+    if let Some(backup_id) = direct_backup_id(backup_ref)? {
+        let storage = resolve_storage(
+            client,
+            namespace,
+            backup_ref.storage.as_ref(),
+            backup_ref.storage_ref.as_ref(),
+        )
+        .await?;
+        return Ok((resolved, RestoreSource { storage, backup_id }));
+    }
+
+    let name = backup_ref.name.clone().ok_or_else(|| {
+        Error::InvalidConfig(
+            "backupRef.name is required unless backupRef.storage or backupRef.storageRef is set"
+                .to_string(),
+        )
+    })?;
+    let source_namespace = backup_ref.namespace.as_deref().unwrap_or(namespace);
+    let kind = backup_ref
+        .kind
+        .clone()
+        .unwrap_or(BackupRefKind::KafkaBackup);
+    check_backup_grant(client, namespace, source_namespace, &kind, &name).await?;
+
+    if kind == BackupRefKind::KafkaBackupSnapshot {
+        let snapshot_api: Api<KafkaBackupSnapshot> =
+            Api::namespaced(client.clone(), source_namespace);
+        let snapshot = snapshot_api
+            .get(&name)
+            .await
+            .map_err(|_| Error::BackupNotFound { name: name.clone() })?;
+        if backup_ref
+            .backup_id
+            .as_ref()
+            .is_some_and(|id| id != &snapshot.spec.backup_id)
+        {
+            return Err(Error::InvalidConfig(format!(
+                "backupRef.backupId must be omitted when referencing KafkaBackupSnapshot '{name}'"
+            )));
+        }
+        resolved.spec.backup_ref = BackupRef {
+            kind: None,
+            name: Some(snapshot.spec.backup_name.clone()),
+            namespace: backup_ref.namespace.clone(),
+            backup_id: Some(snapshot.spec.backup_id.clone()),
+            storage: None,
+            storage_ref: None,
+        };
+    }
+
+    let backup_name = resolved.spec.backup_ref.name.clone().unwrap_or(name);
+    let backup_api: Api<KafkaBackup> = Api::namespaced(client.clone(), source_namespace);
+    let source_backup = backup_api
+        .get(&backup_name)
+        .await
+        .map_err(|_| Error::BackupNotFound { name: backup_name })?;
+    let source_backup = with_resolved_storage(client, &source_backup).await?;
+    let mut source = RestoreSource::from_backup(&resolved, &source_backup)?;
+    if source_namespace != namespace {
+        if copy_secrets {
+            source.storage =
+                project_storage_secrets(client, &resolved, source_namespace, &source.storage)
+                    .await?;
+        } else {
+            for secret_ref in storage_secret_refs_mut(&mut source.storage) {
+                secret_ref.name = projected_secret_name(&resolved, &secret_ref.name);
+            }
+        }
+    }
+    Ok((resolved, source))
+}
+
+/// Copy the Secrets `storage` references from `source_namespace` into the
+/// restore's namespace and return `storage` pointing at the copies.
+///
+/// Only the referenced keys are copied. The copies are owned by the
+/// KafkaRestore and deleted as soon as the restore Job finishes.
+async fn project_storage_secrets(
+    client: &Client,
+    restore: &KafkaRestore,
+    source_namespace: &str,
+    storage: &StorageSpec,
+) -> Result<StorageSpec> {
+    let name = restore.name_any();
+    let namespace = restore
+        .namespace()
+        .ok_or(Error::MissingObjectKey(".metadata.namespace"))?;
+
+    let mut projected = storage.clone();
+    let mut copies: BTreeMap<String, BTreeMap<String, ByteString>> = BTreeMap::new();
+    for secret_ref in storage_secret_refs_mut(&mut projected) {
+        let secret = get_secret(client, &secret_ref.name, source_namespace).await?;
+        let value = extract_secret_bytes(&secret, &secret_ref.key)?;
+        let copy_name = projected_secret_name(restore, &secret_ref.name);
+        copies
+            .entry(copy_name.clone())
+            .or_default()
+            .insert(secret_ref.key.clone(), ByteString(value));
+        secret_ref.name = copy_name;
+    }
+
+    let secret_api: Api<Secret> = Api::namespaced(client.clone(), &namespace);
+    for (copy_name, data) in copies {
+        let secret = Secret {
+            metadata: ObjectMeta {
+                name: Some(copy_name.clone()),
+                namespace: Some(namespace.clone()),
+                labels: Some(BTreeMap::from([
+                    (
+                        "app.kubernetes.io/managed-by".to_string(),
+                        "kafka-backup-operator".to_string(),
+                    ),
+                    ("kafkabackup.com/restore".to_string(), name.clone()),
+                    (
+                        PROJECTED_SECRET_SOURCE_LABEL.to_string(),
+                        source_namespace.to_string(),
+                    ),
+                ])),
+                owner_references: Some(vec![OwnerReference {
+                    api_version: "kafkabackup.com/v1alpha1".to_string(),
+                    kind: "KafkaRestore".to_string(),
+                    name: name.clone(),
+                    uid: restore.metadata.uid.clone().unwrap_or_default(),
+                    controller: Some(true),
+                    block_owner_deletion: Some(true),
+                }]),
+                ..Default::default()
+            },
+            data: Some(data),
+            type_: Some("Opaque".to_string()),
+            ..Default::default()
+        };
+        let patch = serde_json::to_value(&secret).map_err(Error::Serialization)?;
+        secret_api
+            .patch(
+                &copy_name,
+                &PatchParams::apply("kafka-backup-operator").force(),
+                &Patch::Apply(patch),
+            )
+            .await?;
+        info!(%name, secret = %copy_name, %source_namespace, "Copied storage Secret for cross-namespace restore");
+    }
+
+    Ok(projected)
+}
+
+/// Name of the copy of storage Secret `secret` in the restore's namespace
+fn projected_secret_name(restore: &KafkaRestore, secret: &str) -> String {
+    format!("{}-storage-{secret}", restore.name_any())
+}
+
+/// Delete the storage Secrets copied for a cross-namespace restore. Failures
+/// are only logged: the copies are also garbage collected with the KafkaRestore.
+async fn delete_projected_secrets(client: &Client, namespace: &str, name: &str) {
+    let secret_api: Api<Secret> = Api::namespaced(client.clone(), namespace);
+    let lp = kube::api::ListParams::default().labels(&format!(
+        "app.kubernetes.io/managed-by=kafka-backup-operator,kafkabackup.com/restore={name},{PROJECTED_SECRET_SOURCE_LABEL}"
+    ));
+    let secrets = match secret_api.list_metadata(&lp).await {
+        Ok(secrets) => secrets,
+        Err(e) => {
+            warn!(%name, error = %e, "Failed to list copied storage Secrets");
+            return;
+        }
+    };
+    for secret in secrets {
+        let secret_name = secret.metadata.name.unwrap_or_default();
+        if let Err(e) = secret_api
+            .delete(&secret_name, &cleanup_delete_params())
+            .await
+        {
+            warn!(%name, secret = %secret_name, error = %e, "Failed to delete copied storage Secret");
+        }
+    }
+}
+
 /// Whether the current status has a condition of `condition_type` with the
 /// given reason. Used to avoid re-patching an identical status, which would
 /// churn `lastTransitionTime` and retrigger the watch.
@@ -236,7 +1286,12 @@ fn has_condition_reason(
     find_condition(conditions, condition_type).is_some_and(|c| c.reason.as_deref() == Some(reason))
 }
 
-async fn handle_cleanup(restore: &KafkaRestore, client: &Client, namespace: &str) -> Result<()> {
+async fn handle_cleanup(
+    restore: &KafkaRestore,
+    client: &Client,
+    namespace: &str,
+    metrics: &MetricsState,
+) -> Result<()> {
     let name = restore.name_any();
     info!(%name, "Cleaning up KafkaRestore resources");
 
@@ -257,9 +1312,13 @@ async fn handle_cleanup(restore: &KafkaRestore, client: &Client, namespace: &str
     let cm_name = format!("{name}-config");
     let _ = cm_api.delete(&cm_name, &cleanup_delete_params()).await;
 
+    // Delete storage Secrets copied for a cross-namespace backupRef
+    delete_projected_secrets(client, namespace, &name).await;
+
     // Remove finalizer
     let restore_api: Api<KafkaRestore> = Api::namespaced(client.clone(), namespace);
     remove_finalizer(&restore_api, &name).await?;
+    metrics.remove_restore(&name, &restore.spec.strimzi_cluster_ref.name);
 
     info!(%name, "Cleanup complete");
     Ok(())
@@ -339,13 +1398,72 @@ async fn create_or_update_config_map(
     Ok(())
 }
 
-async fn update_status_running(api: &Api<KafkaRestore>, name: &str, generation: i64) -> Result<()> {
+async fn update_status_running(
+    api: &Api<KafkaRestore>,
+    restore: &KafkaRestore,
+    generation: i64,
+    backup_id: Option<&str>,
+) -> Result<()> {
+    let mut info = restore_info(restore, RestoreStatus::Running);
+    if let Some(backup_id) = backup_id {
+        info.backup_id = Some(backup_id.to_string());
+    }
     let status = KafkaRestoreStatus {
         conditions: vec![not_ready(REASON_RESTORE_RUNNING, "Restore job is running")],
+        restore: Some(info),
         observed_generation: Some(generation),
         ..Default::default()
     };
-    patch_status(api, name, &status).await
+    patch_status(api, &restore.name_any(), &status).await
+}
+
+/// `status.restore` of a restore whose Job has been created, keeping the
+/// start time and backup ID already recorded. Once set, the CRD rejects changes to the spec.
+fn restore_info(restore: &KafkaRestore, status: RestoreStatus) -> RestoreInfo {
+    let now = Utc::now();
+    RestoreInfo {
+        start_time: restore
+            .status
+            .as_ref()
+            .and_then(|s| s.restore.as_ref())
+            .map_or(now, |info| info.start_time),
+        completion_time: (status != RestoreStatus::Running).then_some(now),
+        status,
+        backup_id: recorded_backup_id(restore),
+        restored_topics: None,
+        restored_partitions: None,
+        restored_bytes: None,
+        point_in_time_target: None,
+        actual_point_in_time: None,
+    }
+}
+
+/// Report that the restore Job is held back, for `reason`, until Strimzi
+/// resources become Ready
+async fn update_status_waiting(
+    api: &Api<KafkaRestore>,
+    restore: &KafkaRestore,
+    generation: i64,
+    reason: &str,
+    message: &str,
+) -> Result<()> {
+    let already_current = restore
+        .status
+        .as_ref()
+        .and_then(|s| find_condition(&s.conditions, CONDITION_TYPE_READY))
+        .is_some_and(|c| {
+            c.reason.as_deref() == Some(reason) && c.message.as_deref() == Some(message)
+        });
+    if already_current {
+        return Ok(());
+    }
+
+    let status = KafkaRestoreStatus {
+        conditions: vec![not_ready(reason, message)],
+        observed_generation: Some(generation),
+        ..Default::default()
+    };
+    patch_status(api, &restore.name_any(), &status).await
 }
 
 async fn update_status_reconciliation_paused(
@@ -385,39 +1503,51 @@ async fn update_status_completed(
         ],
         restore: Some(info.clone()),
         observed_generation: Some(generation),
+        ..Default::default()
     };
     patch_status(api, name, &status).await
 }
 
 async fn update_status_failed(
     api: &Api<KafkaRestore>,
-    name: &str,
+    restore: &KafkaRestore,
     generation: i64,
-    job_name: &str,
+    message: &str,
 ) -> Result<()> {
     let status = KafkaRestoreStatus {
-        conditions: error_conditions(
-            REASON_RESTORE_FAILED,
-            &format!("Restore job {job_name} failed"),
-        ),
+        conditions: error_conditions(REASON_RESTORE_FAILED, message),
+        restore: Some(restore_info(restore, RestoreStatus::Failed)),
         observed_generation: Some(generation),
         ..Default::default()
     };
-    patch_status(api, name, &status).await
+    patch_status(api, &restore.name_any(), &status).await
 }
 
+/// Record `error` in the status, with a Warning Event unless the restore
+/// already reports it, so retries do not repeat it
 async fn update_status_error(
     api: &Api<KafkaRestore>,
-    name: &str,
+    recorder: &Recorder,
+    restore: &KafkaRestore,
     generation: i64,
     error: &Error,
 ) -> Result<()> {
+    let message = error.to_string();
+    let already_reported = restore
+        .status
+        .as_ref()
+        .and_then(|s| find_condition(&s.conditions, CONDITION_TYPE_ERROR))
+        .is_some_and(|c| c.message.as_deref() == Some(message.as_str()));
     let status = KafkaRestoreStatus {
-        conditions: error_conditions(error.reason(), &error.to_string()),
+        conditions: error_conditions(error.reason(), &message),
         observed_generation: Some(generation),
         ..Default::default()
     };
-    patch_status(api, name, &status).await
+    patch_status(api, &restore.name_any(), &status).await?;
+    if !already_reported {
+        events::warning(recorder, error.reason(), ACTION_RECONCILE, message).await;
+    }
+    Ok(())
 }
 
 async fn patch_status(
@@ -0,0 +1,344 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference;
+use kube::{
+    api::{Api, ListParams, Patch, PatchParams, ResourceExt},
+    Client,
+};
+use tracing::{info, warn};
+
+use crate::crd::{
+    KafkaBackup, KafkaBackupSchedule, KafkaBackupScheduleStatus, KafkaBackupSpec,
+    ScheduledClusterStatus,
+};
+use crate::error::{Error, Result};
+use crate::metrics::prometheus::MetricsState;
+use crate::reconcilers::{
+    cleanup_delete_params, is_reconciliation_paused, label_value, truncate_with_hash,
+};
+use crate::status::conditions::*;
+use crate::strimzi::kafka_cr::list_kafka_clusters;
+
+/// Label set on every KafkaBackup generated by a KafkaBackupSchedule. Its
+/// value is the schedule name, passed through [`label_value`].
+pub const SCHEDULE_LABEL: &str = "kafkabackup.com/schedule";
+
+/// Longest name of a generated KafkaBackup. Its CronJob, `{name}-scheduled`,
+/// must fit in the 52 characters Kubernetes allows for CronJob names.
+pub const MAX_SCHEDULED_BACKUP_NAME_LENGTH: usize = 42;
+
+pub async fn reconcile_schedule(
+    schedule: Arc<KafkaBackupSchedule>,
+    client: Client,
+    _metrics: &MetricsState,
+) -> Result<()> {
+    let name = schedule.name_any();
+    let namespace = schedule
+        .namespace()
+        .ok_or(Error::MissingObjectKey(".metadata.namespace"))?;
+    let schedule_api: Api<KafkaBackupSchedule> = Api::namespaced(client.clone(), &namespace);
+
+    // Generated KafkaBackups carry an ownerReference, so the garbage collector
+    // removes them (and their own Jobs/CronJobs through their finalizers) once
+    // the schedule is gone. No finalizer is needed here.
+    if schedule.metadata.deletion_timestamp.is_some() {
+        return Ok(());
+    }
+
+    if is_reconciliation_paused(schedule.as_ref()) {
+        update_status_reconciliation_paused(&schedule_api, &schedule).await?;
+        info!(%name, "Reconciliation paused by annotation");
+        return Ok(());
+    }
+
+    let generation = schedule.metadata.generation.unwrap_or(0);
+
+    // Step 1: Find the Kafka clusters matching the selector
+    let clusters =
+        match list_kafka_clusters(&client, &namespace, &schedule.spec.cluster_selector).await {
+            Ok(clusters) => clusters,
+            Err(e) => {
+                update_status_error(&schedule_api, &schedule, generation, &e).await?;
+                return Err(e);
+            }
+        };
+
+    // Step 2: Apply one KafkaBackup per matched cluster. A KafkaBackup of the
+    // same name that this schedule does not control (hand-written, or another
+    // schedule's child) is left alone and reported once the rest are applied.
+    let backup_api: Api<KafkaBackup> = Api::namespaced(client.clone(), &namespace);
+    let mut desired = BTreeSet::new();
+    let mut conflicts = Vec::new();
+    for cluster in &clusters {
+        let backup = match build_scheduled_backup(&schedule, cluster) {
+            Ok(backup) => backup,
+            Err(e) => {
+                update_status_error(&schedule_api, &schedule, generation, &e).await?;
+                return Err(e);
+            }
+        };
+        let backup_name = backup.name_any();
+        if let Some(existing) = backup_api.get_opt(&backup_name).await? {
+            if !is_controlled_by(&existing, &schedule) {
+                warn!(%name, backup = %backup_name, "KafkaBackup exists and is not controlled by this schedule");
+                conflicts.push(backup_name);
+                continue;
+            }
+        }
+        apply_backup(&backup_api, &backup_name, &backup).await?;
+        desired.insert(backup_name);
+    }
+
+    // Step 3: Garbage-collect KafkaBackups for clusters that no longer match
+    let children: Vec<KafkaBackup> = backup_api
+        .list(&schedule_children_selector(&name))
+        .await?
+        .items
+        .into_iter()
+        .filter(|backup| is_controlled_by(backup, &schedule))
+        .collect();
+    for child in &children {
+        let child_name = child.name_any();
+        if !desired.contains(&child_name) {
+            info!(%name, backup = %child_name, "Deleting KafkaBackup for unmatched cluster");
+            if let Err(e) = backup_api
+                .delete(&child_name, &cleanup_delete_params())
+                .await
+            {
+                warn!(%name, backup = %child_name, error = %e, "Failed to delete KafkaBackup");
+            }
+        }
+    }
+
+    if !conflicts.is_empty() {
+        let e = Error::InvalidConfig(format!(
+            "KafkaBackup {} already exists and is not controlled by this schedule; rename or delete it",
+            conflicts.join(", ")
+        ));
+        update_status_error(&schedule_api, &schedule, generation, &e).await?;
+        return Err(e);
+    }
+
+    // Step 4: Aggregate per-cluster readiness
+    let cluster_statuses = clusters
+        .iter()
+        .map(|cluster| {
+            let backup_name = scheduled_backup_name(&name, cluster);
+            let child = children.iter().find(|c| c.name_any() == backup_name);
+            cluster_status(cluster, &backup_name, child)
+        })
+        .collect();
+    update_status_clusters(&schedule_api, &schedule, generation, cluster_statuses).await
+}
+
+/// Name of the KafkaBackup generated for `cluster` by schedule
+/// `schedule_name`: `{schedule}-{cluster}`, shortened with a hash suffix when
+/// longer than [`MAX_SCHEDULED_BACKUP_NAME_LENGTH`]
+pub fn scheduled_backup_name(schedule_name: &str, cluster: &str) -> String {
+    truncate_with_hash(
+        &format!("{schedule_name}-{cluster}"),
+        MAX_SCHEDULED_BACKUP_NAME_LENGTH,
+    )
+}
+
+/// Build the KafkaBackup a schedule generates for one matched Kafka cluster.
+///
+/// The template spec is taken as-is, with `strimziClusterRef.name` and
+/// `strimziClusterRef.namespace` pointed at the matched cluster and
+/// `spec.schedule` replaced by the schedule-level cron when one is set.
+pub fn build_scheduled_backup(
+    schedule: &KafkaBackupSchedule,
+    cluster: &str,
+) -> Result<KafkaBackup> {
+    let schedule_name = schedule.name_any();
+    let namespace = schedule
+        .namespace()
+        .ok_or(Error::MissingObjectKey(".metadata.namespace"))?;
+
+    let mut template = schedule.spec.backup_template.spec.clone();
+    let template_object = template
+        .as_object_mut()
+        .ok_or_else(|| Error::InvalidConfig("backupTemplate.spec must be an object".to_string()))?;
+    let cluster_ref = template_object
+        .entry("strimziClusterRef")
+        .or_insert_with(|| serde_json::json!({}));
+    let cluster_ref = cluster_ref.as_object_mut().ok_or_else(|| {
+        Error::InvalidConfig("backupTemplate.spec.strimziClusterRef must be an object".to_string())
+    })?;
+    cluster_ref.insert("name".to_string(), cluster.into());
+    cluster_ref.insert("namespace".to_string(), namespace.clone().into());
+
+    let mut spec: KafkaBackupSpec = serde_json::from_value(template)
+        .map_err(|e| Error::InvalidConfig(format!("invalid backupTemplate.spec: {e}")))?;
+    if let Some(cron) = &schedule.spec.schedule {
+        spec.schedule = Some(cron.clone());
+    }
+
+    let template_metadata = schedule.spec.backup_template.metadata.as_ref();
+    let mut labels: BTreeMap<String, String> = template_metadata
+        .map(|m| m.labels.clone())
+        .unwrap_or_default();
+    labels.insert(
+        "app.kubernetes.io/managed-by".to_string(),
+        "kafka-backup-operator".to_string(),
+    );
+    labels.insert("strimzi.io/cluster".to_string(), cluster.to_string());
+    labels.insert(SCHEDULE_LABEL.to_string(), label_value(&schedule_name));
+    let annotations = template_metadata
+        .map(|m| m.annotations.clone())
+        .unwrap_or_default();
+
+    let mut backup = KafkaBackup::new(&scheduled_backup_name(&schedule_name, cluster), spec);
+    backup.metadata.namespace = Some(namespace);
+    backup.metadata.labels = Some(labels);
+    if !annotations.is_empty() {
+        backup.metadata.annotations = Some(annotations);
+    }
+    backup.metadata.owner_references = Some(vec![OwnerReference {
+        api_version: "kafkabackup.com/v1alpha1".to_string(),
+        kind: "KafkaBackupSchedule".to_string(),
+        name: schedule_name,
+        uid: schedule.metadata.uid.clone().unwrap_or_default(),
+        controller: Some(true),
+        block_owner_deletion: Some(true),
+    }]);
+    Ok(backup)
+}
+
+fn schedule_children_selector(schedule_name: &str) -> ListParams {
+    ListParams::default().labels(&format!("{SCHEDULE_LABEL}={}", label_value(schedule_name)))
+}
+
+fn is_controlled_by(backup: &KafkaBackup, schedule: &KafkaBackupSchedule) -> bool {
+    backup.owner_references().iter().any(|owner| {
+        owner.controller == Some(true)
+            && owner.kind == "KafkaBackupSchedule"
+            && Some(&owner.uid) == schedule.metadata.uid.as_ref()
+    })
+}
+
+fn cluster_status(
+    cluster: &str,
+    backup_name: &str,
+    child: Option<&KafkaBackup>,
+) -> ScheduledClusterStatus {
+    let status = child.and_then(|c| c.status.as_ref());
+    let ready_condition = status.and_then(|s| find_condition(&s.conditions, CONDITION_TYPE_READY));
+    ScheduledClusterStatus {
+        cluster: cluster.to_string(),
+        backup_name: backup_name.to_string(),
+        ready: ready_condition.is_some_and(|c| c.status == STATUS_TRUE),
+        reason: ready_condition.and_then(|c| c.reason.clone()),
+        last_backup_time: status
+            .and_then(|s| s.last_backup.as_ref())
+            .and_then(|b| b.completion_time),
+    }
+}
+
+async fn update_status_clusters(
+    api: &Api<KafkaBackupSchedule>,
+    schedule: &KafkaBackupSchedule,
+    generation: i64,
+    clusters: Vec<ScheduledClusterStatus>,
+) -> Result<()> {
+    let total = clusters.len();
+    let ready_count = clusters.iter().filter(|c| c.ready).count();
+    let ready_clusters = format!("{ready_count}/{total}");
+    let condition = if total == 0 {
+        not_ready(
+            REASON_NO_CLUSTERS_MATCHED,
+            "No Kafka clusters match clusterSelector",
+        )
+    } else if ready_count == total {
+        ready(
+            REASON_CLUSTERS_READY,
+            &format!("{ready_clusters} clusters ready"),
+        )
+    } else {
+        not_ready(
+            REASON_CLUSTERS_NOT_READY,
+            &format!("{ready_clusters} clusters ready"),
+        )
+    };
+
+    let current = schedule.status.clone().unwrap_or_default();
+    let already_current =
+        find_condition(&current.conditions, CONDITION_TYPE_READY).is_some_and(|c| {
+            c.status == condition.status
+                && c.reason == condition.reason
+                && c.message == condition.message
+        }) && current.conditions.len() == 1
+            && current.clusters == clusters
+            && current.ready_clusters.as_deref() == Some(ready_clusters.as_str())
+            && current.observed_generation == Some(generation);
+    if already_current {
+        return Ok(());
+    }
+
+    let status = KafkaBackupScheduleStatus {
+        conditions: vec![condition],
+        clusters,
+        ready_clusters: Some(ready_clusters),
+        observed_generation: Some(generation),
+    };
+    patch_status(api, &schedule.name_any(), &status).await
+}
+
+async fn update_status_reconciliation_paused(
+    api: &Api<KafkaBackupSchedule>,
+    schedule: &KafkaBackupSchedule,
+) -> Result<()> {
+    let generation = schedule.metadata.generation.unwrap_or(0);
+    let mut status = schedule.status.clone().unwrap_or_default();
+    let already_current =
+        is_condition_true(&status.conditions, CONDITION_TYPE_RECONCILIATION_PAUSED)
+            && status.observed_generation == Some(generation);
+    if already_current {
+        return Ok(());
+    }
+
+    status.conditions = vec![reconciliation_paused()];
+    status.observed_generation = Some(generation);
+    patch_status(api, &schedule.name_any(), &status).await
+}
+
+async fn update_status_error(
+    api: &Api<KafkaBackupSchedule>,
+    schedule: &KafkaBackupSchedule,
+    generation: i64,
+    error: &Error,
+) -> Result<()> {
+    let mut status = schedule.status.clone().unwrap_or_default();
+    status.conditions = error_conditions(error.reason(), &error.to_string());
+    status.observed_generation = Some(generation);
+    patch_status(api, &schedule.name_any(), &status).await
+}
+
+async fn patch_status(
+    api: &Api<KafkaBackupSchedule>,
+    name: &str,
+    status: &KafkaBackupScheduleStatus,
+) -> Result<()> {
+    let patch = serde_json::json!({ "status": status });
+    api.patch_status(
+        name,
+        &PatchParams::apply("kafka-backup-operator"),
+        &Patch::Merge(&patch),
+    )
+    .await?;
+    Ok(())
+}
+
+async fn apply_backup(api: &Api<KafkaBackup>, name: &str, backup: &KafkaBackup) -> Result<()> {
+    let patch = serde_json::to_value(backup).map_err(Error::Serialization)?;
+    api.patch(
+        name,
+        // Generated KafkaBackups are fully owned by the schedule; edits are
+        // made on the schedule's backupTemplate instead.
+        &PatchParams::apply("kafka-backup-operator").force(),
+        &Patch::Apply(patch),
+    )
+    .await?;
+    Ok(())
+}
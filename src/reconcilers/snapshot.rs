@@ -0,0 +1,317 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference;
+use kube::{
+    api::{Api, ListParams, Patch, PatchParams, ResourceExt},
+    Client,
+};
+use tracing::{debug, info, warn};
+
+use crate::adapters::storage_location::{backup_storage, with_resolved_storage};
+use crate::crd::common::BackupHistoryEntry;
+use crate::crd::{
+    KafkaBackup, KafkaBackupSnapshot, KafkaBackupSnapshotSpec, KafkaBackupSnapshotStatus,
+};
+use crate::error::{Error, Result};
+use crate::metrics::prometheus::MetricsState;
+use crate::reconcilers::{cleanup_delete_params, fnv1a, label_value, FINALIZER};
+use crate::retention::storage::{discover_backup_history, prune_backup_ids};
+use crate::status::conditions::*;
+
+/// Label linking a KafkaBackupSnapshot to the KafkaBackup it was discovered
+/// for. Its value is the backup name, passed through [`label_value`].
+pub const SNAPSHOT_BACKUP_LABEL: &str = "kafkabackup.com/backup";
+
+/// Snapshots are written by the KafkaBackup reconciler; this reconciler only
+/// handles deletion, pruning the backup from storage before releasing the
+/// finalizer.
+pub async fn reconcile_snapshot(
+    snapshot: Arc<KafkaBackupSnapshot>,
+    client: Client,
+    _metrics: &MetricsState,
+) -> Result<()> {
+    let name = snapshot.name_any();
+    let namespace = snapshot
+        .namespace()
+        .ok_or(Error::MissingObjectKey(".metadata.namespace"))?;
+
+    if snapshot.metadata.deletion_timestamp.is_none() || !has_finalizer(&snapshot) {
+        return Ok(());
+    }
+
+    let snapshot_api: Api<KafkaBackupSnapshot> = Api::namespaced(client.clone(), &namespace);
+    let backup_api: Api<KafkaBackup> = Api::namespaced(client.clone(), &namespace);
+    let backup_id = &snapshot.spec.backup_id;
+
+    match backup_api.get_opt(&snapshot.spec.backup_name).await? {
+        Some(backup) if backup.metadata.deletion_timestamp.is_none() => {
+            if let Err(e) = prune_snapshot_backup(&client, &namespace, &backup, backup_id).await {
+                update_status_error(&snapshot_api, &name, &e).await?;
+                return Err(e);
+            }
+            info!(%name, %backup_id, "Pruned backup for deleted KafkaBackupSnapshot");
+        }
+        // Deleting a KafkaBackup removes its snapshot records through the
+        // garbage collector; that must never delete the backups themselves.
+        _ => {
+            info!(
+                %name,
+                %backup_id,
+                backup = %snapshot.spec.backup_name,
+                "Source KafkaBackup is gone or being deleted; removing snapshot without pruning storage"
+            );
+        }
+    }
+
+    remove_finalizer(&snapshot_api, &name).await
+}
+
+/// Create a KafkaBackupSnapshot for every backup manifest in `backup`'s
+/// storage, and remove snapshots whose backup is no longer there. `backup`
+/// must have its storage resolved.
+///
+/// Stale snapshots are removed with their finalizer stripped first: a backup
+/// missing from one listing (for example an unreadable manifest) must only
+/// drop the record, not prune whatever is left in storage.
+pub async fn sync_backup_snapshots(client: &Client, backup: &KafkaBackup) -> Result<()> {
+    let name = backup.name_any();
+    let namespace = backup
+        .namespace()
+        .ok_or(Error::MissingObjectKey(".metadata.namespace"))?;
+
+    let discovered =
+        discover_backup_history(client, &namespace, backup_storage(backup)?, &name).await?;
+    let recorded = backup
+        .status
+        .as_ref()
+        .map(|status| status.backup_history.as_slice())
+        .unwrap_or(&[]);
+
+    let snapshot_api: Api<KafkaBackupSnapshot> = Api::namespaced(client.clone(), &namespace);
+    let existing: BTreeMap<String, KafkaBackupSnapshot> = snapshot_api
+        .list(
+            &ListParams::default()
+                .labels(&format!("{SNAPSHOT_BACKUP_LABEL}={}", label_value(&name))),
+        )
+        .await?
+        .items
+        .into_iter()
+        .map(|snapshot| (snapshot.name_any(), snapshot))
+        .collect();
+
+    let mut desired = BTreeSet::new();
+    for mut entry in discovered {
+        if entry.completion_time.is_none() {
+            entry.completion_time = recorded
+                .iter()
+                .find(|recorded| recorded.id == entry.id)
+                .and_then(|recorded| recorded.completion_time);
+        }
+        let snapshot = match build_backup_snapshot(backup, &entry) {
+            Err(Error::InvalidConfig(reason)) => {
+                warn!(%name, backup_id = %entry.id, %reason, "Skipping snapshot for backup");
+                continue;
+            }
+            snapshot => snapshot?,
+        };
+        let snapshot_name = snapshot.name_any();
+        desired.insert(snapshot_name.clone());
+
+        let current = match existing.get(&snapshot_name) {
+            Some(current) => Some(current.clone()),
+            None => snapshot_api.get_opt(&snapshot_name).await?,
+        };
+        match current {
+            Some(current) if !is_controlled_by(&current, backup) => {
+                warn!(
+                    %name,
+                    snapshot = %snapshot_name,
+                    "KafkaBackupSnapshot exists and is not controlled by this KafkaBackup; leaving it alone"
+                );
+                continue;
+            }
+            // Finalizers cannot be re-added to an object being deleted.
+            Some(current) if current.metadata.deletion_timestamp.is_some() => continue,
+            Some(current) if snapshot_specs_equal(&current.spec, &snapshot.spec) => continue,
+            _ => {}
+        }
+        apply_snapshot(&snapshot_api, &snapshot_name, &snapshot).await?;
+        debug!(%name, snapshot = %snapshot_name, "Applied KafkaBackupSnapshot");
+    }
+
+    for (snapshot_name, snapshot) in &existing {
+        if desired.contains(snapshot_name)
+            || snapshot.metadata.deletion_timestamp.is_some()
+            || !is_controlled_by(snapshot, backup)
+        {
+            continue;
+        }
+        info!(%name, snapshot = %snapshot_name, "Removing snapshot for backup no longer in storage");
+        remove_finalizer(&snapshot_api, snapshot_name).await?;
+        snapshot_api
+            .delete(snapshot_name, &cleanup_delete_params())
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// Kubernetes object name for the snapshot of `backup_id` taken by the
+/// KafkaBackup `backup_name`: the sanitized ID followed by a short hash of
+/// both, so IDs that sanitize alike (or repeat across backups) do not share a
+/// snapshot.
+pub fn snapshot_name(backup_name: &str, backup_id: &str) -> Result<String> {
+    let sanitized: String = backup_id
+        .to_ascii_lowercase()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '.' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .take(244)
+        .collect();
+    let sanitized = sanitized.trim_matches(|c: char| c == '-' || c == '.');
+    if sanitized.is_empty() {
+        return Err(Error::InvalidConfig(format!(
+            "backup ID {backup_id:?} has no characters usable in an object name"
+        )));
+    }
+    Ok(format!(
+        "{sanitized}-{:08x}",
+        fnv1a(format!("{backup_name}/{backup_id}").as_bytes())
+    ))
+}
+
+/// Build the KafkaBackupSnapshot recording one backup of `backup`
+pub fn build_backup_snapshot(
+    backup: &KafkaBackup,
+    entry: &BackupHistoryEntry,
+) -> Result<KafkaBackupSnapshot> {
+    let backup_name = backup.name_any();
+    let namespace = backup
+        .namespace()
+        .ok_or(Error::MissingObjectKey(".metadata.namespace"))?;
+
+    let spec = KafkaBackupSnapshotSpec {
+        backup_name: backup_name.clone(),
+        backup_id: entry.id.clone(),
+        start_time: Some(entry.start_time),
+        completion_time: entry.completion_time,
+        topics: entry.topics_backed_up,
+        partitions: entry.partitions_backed_up,
+        size_bytes: entry.size_bytes,
+    };
+
+    let mut snapshot = KafkaBackupSnapshot::new(&snapshot_name(&backup_name, &entry.id)?, spec);
+    snapshot.metadata.namespace = Some(namespace);
+    snapshot.metadata.labels = Some(BTreeMap::from([
+        (
+            "app.kubernetes.io/managed-by".to_string(),
+            "kafka-backup-operator".to_string(),
+        ),
+        (SNAPSHOT_BACKUP_LABEL.to_string(), label_value(&backup_name)),
+        (
+            "strimzi.io/cluster".to_string(),
+            backup.spec.strimzi_cluster_ref.name.clone(),
+        ),
+    ]));
+    snapshot.metadata.finalizers = Some(vec![FINALIZER.to_string()]);
+    snapshot.metadata.owner_references = Some(vec![OwnerReference {
+        api_version: "kafkabackup.com/v1alpha1".to_string(),
+        kind: "KafkaBackup".to_string(),
+        name: backup_name,
+        uid: backup.metadata.uid.clone().unwrap_or_default(),
+        controller: Some(true),
+        block_owner_deletion: Some(true),
+    }]);
+    Ok(snapshot)
+}
+
+async fn prune_snapshot_backup(
+    client: &Client,
+    namespace: &str,
+    backup: &KafkaBackup,
+    backup_id: &str,
+) -> Result<()> {
+    let backup = with_resolved_storage(client, backup).await?;
+    prune_backup_ids(
+        client,
+        namespace,
+        backup_storage(&backup)?,
+        &[backup_id.to_string()],
+    )
+    .await?;
+    Ok(())
+}
+
+fn snapshot_specs_equal(a: &KafkaBackupSnapshotSpec, b: &KafkaBackupSnapshotSpec) -> bool {
+    serde_json::to_value(a).ok() == serde_json::to_value(b).ok()
+}
+
+fn is_controlled_by(snapshot: &KafkaBackupSnapshot, backup: &KafkaBackup) -> bool {
+    snapshot.owner_references().iter().any(|owner| {
+        owner.controller == Some(true)
+            && owner.kind == "KafkaBackup"
+            && Some(&owner.uid) == backup.metadata.uid.as_ref()
+    })
+}
+
+fn has_finalizer(snapshot: &KafkaBackupSnapshot) -> bool {
+    snapshot
+        .metadata
+        .finalizers
+        .as_ref()
+        .is_some_and(|f| f.contains(&FINALIZER.to_string()))
+}
+
+async fn remove_finalizer(api: &Api<KafkaBackupSnapshot>, name: &str) -> Result<()> {
+    let patch = serde_json::json!({
+        "metadata": {
+            "finalizers": null
+        }
+    });
+    api.patch(
+        name,
+        &PatchParams::apply("kafka-backup-operator"),
+        &Patch::Merge(&patch),
+    )
+    .await?;
+    Ok(())
+}
+
+async fn update_status_error(
+    api: &Api<KafkaBackupSnapshot>,
+    name: &str,
+    error: &Error,
+) -> Result<()> {
+    let status = KafkaBackupSnapshotStatus {
+        conditions: error_conditions(error.reason(), &error.to_string()),
+    };
+    let patch = serde_json::json!({ "status": status });
+    api.patch_status(
+        name,
+        &PatchParams::apply("kafka-backup-operator"),
+        &Patch::Merge(&patch),
+    )
+    .await?;
+    Ok(())
+}
+
+async fn apply_snapshot(
+    api: &Api<KafkaBackupSnapshot>,
+    name: &str,
+    snapshot: &KafkaBackupSnapshot,
+) -> Result<()> {
+    let patch = serde_json::to_value(snapshot).map_err(Error::Serialization)?;
+    api.patch(
+        name,
+        &PatchParams::apply("kafka-backup-operator").force(),
+        &Patch::Apply(patch),
+    )
+    .await?;
+    Ok(())
+}
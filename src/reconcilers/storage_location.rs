@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use kube::{
+    api::{Api, Patch, PatchParams, ResourceExt},
+    Client,
+};
+use tracing::{info, warn};
+
+use crate::adapters::storage_config::storage_secret_refs;
+use crate::crd::common::{Condition, StorageSpec};
+use crate::crd::{
+    BackupStorageLocation, BackupStorageLocationStatus, ClusterBackupStorageLocation,
+};
+use crate::error::{Error, Result};
+use crate::metrics::prometheus::MetricsState;
+use crate::reconcilers::is_reconciliation_paused;
+use crate::retention::storage::check_storage_reachable;
+use crate::status::conditions::*;
+
+pub async fn reconcile_storage_location(
+    location: Arc<BackupStorageLocation>,
+    client: Client,
+    _metrics: &MetricsState,
+) -> Result<()> {
+    let name = location.name_any();
+    let namespace = location
+        .namespace()
+        .ok_or(Error::MissingObjectKey(".metadata.namespace"))?;
+    let api: Api<BackupStorageLocation> = Api::namespaced(client.clone(), &namespace);
+    let generation = location.metadata.generation.unwrap_or(0);
+
+    let condition = if is_reconciliation_paused(location.as_ref()) {
+        info!(%name, "Reconciliation paused by annotation");
+        reconciliation_paused()
+    } else {
+        reachability_condition(&client, Some(&namespace), &location.spec.storage).await
+    };
+    update_status(&api, &name, location.status.as_ref(), generation, condition).await
+}
+
+pub async fn reconcile_cluster_storage_location(
+    location: Arc<ClusterBackupStorageLocation>,
+    client: Client,
+    _metrics: &MetricsState,
+) -> Result<()> {
+    let name = location.name_any();
+    let api: Api<ClusterBackupStorageLocation> = Api::all(client.clone());
+    let generation = location.metadata.generation.unwrap_or(0);
+
+    let condition = if is_reconciliation_paused(location.as_ref()) {
+        info!(%name, "Reconciliation paused by annotation");
+        reconciliation_paused()
+    } else {
+        reachability_condition(
+            &client,
+            location.spec.check_namespace.as_deref(),
+            &location.spec.storage,
+        )
+        .await
+    };
+    update_status(&api, &name, location.status.as_ref(), generation, condition).await
+}
+
+/// Ready condition describing whether `storage` can be listed. Secrets are
+/// read from `namespace`; without one, storage that references Secrets is
+/// reported as not checked.
+async fn reachability_condition(
+    client: &Client,
+    namespace: Option<&str>,
+    storage: &StorageSpec,
+) -> Condition {
+    let namespace = match namespace {
+        Some(namespace) => namespace,
+        None if storage_secret_refs(storage).is_empty() => "",
+        None => {
+            return new_condition(
+                CONDITION_TYPE_READY,
+                STATUS_UNKNOWN,
+                REASON_REACHABILITY_NOT_CHECKED,
+                "Storage references Secrets; set spec.checkNamespace to check reachability",
+            )
+        }
+    };
+
+    match check_storage_reachable(client, namespace, storage).await {
+        Ok(()) => ready(REASON_STORAGE_REACHABLE, "Storage is reachable"),
+        Err(e) => {
+            warn!(error = %e, "Storage location is unreachable");
+            not_ready(REASON_STORAGE_UNREACHABLE, &e.to_string())
+        }
+    }
+}
+
+async fn update_status<K>(
+    api: &Api<K>,
+    name: &str,
+    current: Option<&BackupStorageLocationStatus>,
+    generation: i64,
+    condition: Condition,
+) -> Result<()>
+where
+    K: kube::Resource + Clone + std::fmt::Debug + serde::de::DeserializeOwned,
+{
+    let already_current = current.is_some_and(|status| {
+        status.observed_generation == Some(generation)
+            && status.conditions.len() == 1
+            && status.conditions[0].condition_type == condition.condition_type
+            && status.conditions[0].status == condition.status
+            && status.conditions[0].reason == condition.reason
+            && status.conditions[0].message == condition.message
+    });
+    if already_current {
+        return Ok(());
+    }
+
+    let status = BackupStorageLocationStatus {
+        conditions: vec![condition],
+        observed_generation: Some(generation),
+    };
+    let patch = serde_json::json!({ "status": status });
+    api.patch_status(
+        name,
+        &PatchParams::apply("kafka-backup-operator"),
+        &Patch::Merge(&patch),
+    )
+    .await?;
+    Ok(())
+}
@@ -0,0 +1,676 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference;
+use kube::{
+    api::{Api, ListParams, Patch, PatchParams, PostParams, ResourceExt},
+    Client,
+};
+use tracing::{info, warn};
+
+use crate::adapters::storage_location::{backup_storage, with_resolved_storage};
+use crate::crd::common::{AuthenticationSpec, Condition, StrimziClusterRef};
+use crate::crd::{
+    BackupRef, KafkaBackup, KafkaBackupVerification, KafkaBackupVerificationStatus, KafkaRestore,
+    KafkaRestoreSpec, RestoreOptionsSpec, TopicMappingEntry, VerificationMode, VerificationOutcome,
+    VerificationResult, VerificationRun,
+};
+use crate::error::{Error, Result};
+use crate::jobs::schedule::next_run;
+use crate::metrics::prometheus::MetricsState;
+use crate::reconcilers::{cleanup_delete_params, is_reconciliation_paused, FINALIZER};
+use crate::retention::storage::{read_backup_topics, BackupTopic};
+use crate::status::conditions::*;
+use crate::strimzi::kafka_topic::{apply_kafka_topic, delete_kafka_topics, unready_kafka_topics};
+
+/// Label set on the KafkaRestores and KafkaTopics created for a
+/// KafkaBackupVerification
+pub const VERIFICATION_LABEL: &str = "kafkabackup.com/verification";
+
+/// Topic name prefix used in Scratch mode when `spec.topicPrefix` is unset
+pub const DEFAULT_TOPIC_PREFIX: &str = "verify-";
+
+pub async fn reconcile_verification(
+    verification: Arc<KafkaBackupVerification>,
+    client: Client,
+    _metrics: &MetricsState,
+) -> Result<()> {
+    let name = verification.name_any();
+    let namespace = verification
+        .namespace()
+        .ok_or(Error::MissingObjectKey(".metadata.namespace"))?;
+    let verification_api: Api<KafkaBackupVerification> =
+        Api::namespaced(client.clone(), &namespace);
+
+    // KafkaRestores are owned by the verification and garbage collected with
+    // it; scratch KafkaTopics may live in another namespace and need the
+    // finalizer.
+    if verification.metadata.deletion_timestamp.is_some() {
+        return handle_cleanup(&verification, &client).await;
+    }
+
+    if is_reconciliation_paused(verification.as_ref()) {
+        update_status_reconciliation_paused(&verification_api, &verification).await?;
+        info!(%name, "Reconciliation paused by annotation");
+        return Ok(());
+    }
+
+    if !verification
+        .metadata
+        .finalizers
+        .as_ref()
+        .is_some_and(|f| f.contains(&FINALIZER.to_string()))
+    {
+        add_finalizer(&verification_api, &name).await?;
+    }
+
+    let current = verification.status.clone().unwrap_or_default();
+    let mut status = current.clone();
+    status.observed_generation = verification.metadata.generation;
+
+    let result = run_verification(&client, &verification, &mut status).await;
+    if let Err(e) = &result {
+        status.conditions = error_conditions(e.reason(), &e.to_string());
+    }
+    if status_changed(&current, &status) {
+        patch_status(&verification_api, &name, &status).await?;
+    }
+    result
+}
+
+/// Advance the verification by one step, recording the outcome in `status`:
+/// finish the active run once its KafkaRestore is done, then start a new run
+/// when the schedule is due.
+async fn run_verification(
+    client: &Client,
+    verification: &KafkaBackupVerification,
+    status: &mut KafkaBackupVerificationStatus,
+) -> Result<()> {
+    let name = verification.name_any();
+    let namespace = verification.namespace().unwrap_or_default();
+
+    // Step 1: Wait for, then record, the active run
+    if let Some(run) = status.active_run.clone() {
+        let restore_api: Api<KafkaRestore> = Api::namespaced(client.clone(), &namespace);
+        let outcome = match restore_api.get_opt(&run.restore_name).await? {
+            Some(restore) => restore_outcome(&restore),
+            None => Some(Err(format!(
+                "KafkaRestore '{}' was deleted before it finished",
+                run.restore_name
+            ))),
+        };
+        let Some(outcome) = outcome else {
+            status.conditions = vec![not_ready(
+                REASON_VERIFICATION_RUNNING,
+                &format!(
+                    "Verifying backup {} with KafkaRestore {}",
+                    run.backup_id, run.restore_name
+                ),
+            )];
+            return Ok(());
+        };
+        let result = finish_run(client, verification, &run, outcome).await?;
+        status.active_run = None;
+        status.last_verification = Some(result);
+    }
+
+    // Step 2: Wait for the next scheduled run
+    let schedule = &verification.spec.schedule;
+    if schedule.suspend {
+        status.next_scheduled_verification = None;
+        status.conditions = vec![ready(
+            REASON_VERIFICATION_SUSPENDED,
+            "Verification schedule is suspended",
+        )];
+        return Ok(());
+    }
+    let now = Utc::now();
+    let last = status
+        .last_schedule_time
+        .or_else(|| {
+            verification
+                .metadata
+                .creation_timestamp
+                .as_ref()
+                .map(|t| t.0)
+        })
+        .unwrap_or(now);
+    let due = next_run(schedule, last)?;
+    if due > now {
+        status.next_scheduled_verification = Some(due);
+        status.conditions = vec![scheduled_condition(status.last_verification.as_ref(), due)];
+        return Ok(());
+    }
+
+    // Step 3: Restore the latest backup. The schedule only advances once the
+    // run has started, so a run that fails to start is retried.
+    let backup_name = &verification.spec.backup_name;
+    let backup_api: Api<KafkaBackup> = Api::namespaced(client.clone(), &namespace);
+    let backup = backup_api
+        .get_opt(backup_name)
+        .await?
+        .ok_or_else(|| Error::BackupNotFound {
+            name: backup_name.clone(),
+        })?;
+    let Some(backup_id) = latest_backup_id(&backup) else {
+        status.last_schedule_time = Some(now);
+        status.next_scheduled_verification = Some(next_run(schedule, now)?);
+        status.conditions = vec![not_ready(
+            REASON_NO_BACKUP_TO_VERIFY,
+            &format!("KafkaBackup '{backup_name}' has no completed backup to verify"),
+        )];
+        return Ok(());
+    };
+    let run = match start_run(client, verification, &backup, &backup_id, now).await? {
+        RunStart::Started(run) => run,
+        RunStart::WaitingForTopics(unready) => {
+            status.conditions = vec![not_ready(
+                REASON_WAITING_FOR_TOPICS,
+                &format!(
+                    "Waiting for scratch KafkaTopics to be Ready: {}",
+                    unready.join(", ")
+                ),
+            )];
+            return Ok(());
+        }
+    };
+    status.last_schedule_time = Some(now);
+    status.next_scheduled_verification = Some(next_run(schedule, now)?);
+    info!(%name, %backup_id, restore = %run.restore_name, "Started backup verification");
+    status.conditions = vec![not_ready(
+        REASON_VERIFICATION_RUNNING,
+        &format!(
+            "Verifying backup {backup_id} with KafkaRestore {}",
+            run.restore_name
+        ),
+    )];
+    status.active_run = Some(run);
+    Ok(())
+}
+
+/// The backup a verification run restores: the KafkaBackup's last backup,
+/// the same default a KafkaRestore without `backupRef.backupId` uses
+pub fn latest_backup_id(backup: &KafkaBackup) -> Option<String> {
+    backup
+        .status
+        .as_ref()
+        .and_then(|status| status.last_backup.as_ref())
+        .map(|last_backup| last_backup.id.clone())
+}
+
+/// Whether a KafkaRestore has finished: `Ok` once it completed, `Err` with
+/// the failure message once it reported an error, `None` while running
+pub fn restore_outcome(restore: &KafkaRestore) -> Option<std::result::Result<(), String>> {
+    let conditions = restore
+        .status
+        .as_ref()
+        .map(|s| s.conditions.as_slice())
+        .unwrap_or(&[]);
+    if is_condition_true(conditions, CONDITION_TYPE_RESTORE_COMPLETE) {
+        return Some(Ok(()));
+    }
+    find_condition(conditions, CONDITION_TYPE_ERROR)
+        .filter(|c| c.status == STATUS_TRUE)
+        .map(|c| Err(c.message.clone().unwrap_or_default()))
+}
+
+/// What [`start_run`] did
+enum RunStart {
+    Started(VerificationRun),
+    /// Scratch KafkaTopics the Topic Operator has not reported Ready yet
+    WaitingForTopics(Vec<String>),
+}
+
+/// Create the KafkaRestore for a run, and in Scratch mode the KafkaTopics it
+/// restores into. The KafkaRestore is only created once those topics are
+/// Ready. Restores from earlier runs are deleted first; the last one is kept
+/// until then for inspection.
+async fn start_run(
+    client: &Client,
+    verification: &KafkaBackupVerification,
+    backup: &KafkaBackup,
+    backup_id: &str,
+    now: DateTime<Utc>,
+) -> Result<RunStart> {
+    let name = verification.name_any();
+    let namespace = verification.namespace().unwrap_or_default();
+    let (cluster_ref, _) = verification_target(verification, backup)?;
+
+    let restore_api: Api<KafkaRestore> = Api::namespaced(client.clone(), &namespace);
+    for previous in restore_api.list(&verification_selector(&name)).await? {
+        if let Err(e) = restore_api
+            .delete(&previous.name_any(), &cleanup_delete_params())
+            .await
+        {
+            warn!(%name, restore = %previous.name_any(), error = %e, "Failed to delete previous verification KafkaRestore");
+        }
+    }
+
+    let mut topics = Vec::new();
+    let mut topics_namespace = None;
+    if verification.spec.mode == VerificationMode::Scratch {
+        let resolved = with_resolved_storage(client, backup).await?;
+        topics = read_backup_topics(client, &namespace, backup_storage(&resolved)?, backup_id)
+            .await?
+            .into_iter()
+            .filter_map(|topic| match &verification.spec.topics {
+                Some(selection) => selection
+                    .matches(&topic.name)
+                    .map(|selected| selected.then_some(topic))
+                    .transpose(),
+                None => Some(Ok(topic)),
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        if topics.is_empty() {
+            return Err(Error::InvalidConfig(format!(
+                "backup {backup_id} has no topics selected for verification"
+            )));
+        }
+
+        let target_namespace = cluster_ref
+            .namespace
+            .clone()
+            .unwrap_or_else(|| namespace.clone());
+        let prefix = topic_prefix(verification);
+        let mut topic_names = Vec::new();
+        for (index, topic) in topics.iter().enumerate() {
+            let topic_name = format!("{name}-topic-{index}");
+            apply_kafka_topic(
+                client,
+                &target_namespace,
+                &cluster_ref.name,
+                &topic_name,
+                &format!("{prefix}{}", topic.name),
+                i32::try_from(topic.partitions).ok(),
+                verification_labels(&name),
+            )
+            .await?;
+            topic_names.push(topic_name);
+        }
+        let unready = unready_kafka_topics(
+            client,
+            &target_namespace,
+            &verification_selector(&name),
+            &topic_names,
+        )
+        .await?;
+        if !unready.is_empty() {
+            return Ok(RunStart::WaitingForTopics(unready));
+        }
+        topics_namespace = Some(target_namespace);
+    }
+
+    let restore_name = format!("{name}-{}", now.format("%Y%m%d-%H%M%S"));
+    let restore =
+        build_verification_restore(verification, backup, backup_id, &topics, &restore_name)?;
+    restore_api.create(&PostParams::default(), &restore).await?;
+
+    Ok(RunStart::Started(VerificationRun {
+        backup_id: backup_id.to_string(),
+        restore_name,
+        start_time: now,
+        topics_namespace,
+    }))
+}
+
+/// Delete a finished run's scratch topics and record its outcome on the
+/// source KafkaBackup
+async fn finish_run(
+    client: &Client,
+    verification: &KafkaBackupVerification,
+    run: &VerificationRun,
+    outcome: std::result::Result<(), String>,
+) -> Result<VerificationResult> {
+    let name = verification.name_any();
+    let namespace = verification.namespace().unwrap_or_default();
+
+    if let Some(topics_namespace) = &run.topics_namespace {
+        delete_kafka_topics(client, topics_namespace, &verification_selector(&name)).await?;
+    }
+
+    let result = VerificationResult {
+        backup_id: run.backup_id.clone(),
+        restore_name: run.restore_name.clone(),
+        result: if outcome.is_ok() {
+            VerificationOutcome::Passed
+        } else {
+            VerificationOutcome::Failed
+        },
+        start_time: run.start_time,
+        completion_time: Utc::now(),
+        message: outcome.err(),
+    };
+    match &result.message {
+        None => info!(%name, backup_id = %run.backup_id, "Backup verification passed"),
+        Some(message) => {
+            warn!(%name, backup_id = %run.backup_id, %message, "Backup verification failed")
+        }
+    }
+
+    let backup_api: Api<KafkaBackup> = Api::namespaced(client.clone(), &namespace);
+    record_backup_verification(&backup_api, verification, &result).await?;
+    Ok(result)
+}
+
+/// Set the `Verified` condition on the source KafkaBackup, and on success its
+/// `lastVerifiedBackupId`. A KafkaBackup deleted in the meantime is skipped.
+async fn record_backup_verification(
+    api: &Api<KafkaBackup>,
+    verification: &KafkaBackupVerification,
+    result: &VerificationResult,
+) -> Result<()> {
+    let backup_name = &verification.spec.backup_name;
+    let Some(backup) = api.get_opt(backup_name).await? else {
+        return Ok(());
+    };
+    let mut conditions = backup.status.map(|s| s.conditions).unwrap_or_default();
+    let condition = match &result.message {
+        None => new_condition(
+            CONDITION_TYPE_VERIFIED,
+            STATUS_TRUE,
+            REASON_VERIFICATION_PASSED,
+            &format!(
+                "Backup {} restored by KafkaBackupVerification {}",
+                result.backup_id,
+                verification.name_any()
+            ),
+        ),
+        Some(message) => new_condition(
+            CONDITION_TYPE_VERIFIED,
+            STATUS_FALSE,
+            REASON_VERIFICATION_FAILED,
+            &format!(
+                "Backup {} failed to restore in KafkaBackupVerification {}: {message}",
+                result.backup_id,
+                verification.name_any()
+            ),
+        ),
+    };
+    set_condition(&mut conditions, condition);
+
+    let mut patch = serde_json::json!({ "status": { "conditions": conditions } });
+    if result.result == VerificationOutcome::Passed {
+        patch["status"]["lastVerifiedBackupId"] = result.backup_id.clone().into();
+    }
+    api.patch_status(
+        backup_name,
+        &PatchParams::apply("kafka-backup-operator"),
+        &Patch::Merge(&patch),
+    )
+    .await?;
+    Ok(())
+}
+
+/// The cluster and authentication a verification restores into. DryRun mode
+/// defaults to the source KafkaBackup's cluster; Scratch mode needs an
+/// explicit scratch cluster.
+fn verification_target(
+    verification: &KafkaBackupVerification,
+    backup: &KafkaBackup,
+) -> Result<(StrimziClusterRef, Option<AuthenticationSpec>)> {
+    match &verification.spec.strimzi_cluster_ref {
+        Some(cluster_ref) => Ok((
+            cluster_ref.clone(),
+            verification.spec.authentication.clone(),
+        )),
+        None if verification.spec.mode == VerificationMode::Scratch => Err(Error::InvalidConfig(
+            "spec.strimziClusterRef is required in Scratch mode".to_string(),
+        )),
+        None => Ok((
+            backup.spec.strimzi_cluster_ref.clone(),
+            verification
+                .spec
+                .authentication
+                .clone()
+                .or_else(|| backup.spec.authentication.clone()),
+        )),
+    }
+}
+
+fn topic_prefix(verification: &KafkaBackupVerification) -> &str {
+    verification
+        .spec
+        .topic_prefix
+        .as_deref()
+        .unwrap_or(DEFAULT_TOPIC_PREFIX)
+}
+
+/// Build the KafkaRestore for one verification run of `backup_id`.
+///
+/// In DryRun mode the restore validates the backup without writing records.
+/// In Scratch mode every topic in `topics` is restored under the topic
+/// prefix through `topicMapping`.
+pub fn build_verification_restore(
+    verification: &KafkaBackupVerification,
+    backup: &KafkaBackup,
+    backup_id: &str,
+    topics: &[BackupTopic],
+    restore_name: &str,
+) -> Result<KafkaRestore> {
+    let name = verification.name_any();
+    let namespace = verification
+        .namespace()
+        .ok_or(Error::MissingObjectKey(".metadata.namespace"))?;
+    let (strimzi_cluster_ref, authentication) = verification_target(verification, backup)?;
+
+    let dry_run = verification.spec.mode == VerificationMode::DryRun;
+    let prefix = topic_prefix(verification);
+    let topic_mapping = if dry_run {
+        Vec::new()
+    } else {
+        topics
+            .iter()
+            .map(|topic| TopicMappingEntry {
+                source_topic: topic.name.clone(),
+                target_topic: format!("{prefix}{}", topic.name),
+            })
+            .collect()
+    };
+
+    let spec = KafkaRestoreSpec {
+        strimzi_cluster_ref,
+        authentication,
+        backup_ref: BackupRef {
+            kind: None,
+            name: Some(verification.spec.backup_name.clone()),
+            namespace: None,
+            backup_id: Some(backup_id.to_string()),
+            storage: None,
+            storage_ref: None,
+        },
+        topics: verification.spec.topics.clone(),
+        point_in_time: None,
+        connection: None,
+        logging: None,
+        env: Vec::new(),
+        topic_mapping,
+        consumer_groups: None,
+        users: None,
+        connect: None,
+        schema_registry: None,
+        restore: Some(RestoreOptionsSpec {
+            dry_run: Some(dry_run),
+            ..Default::default()
+        }),
+        metrics: None,
+        resources: verification.spec.resources.clone(),
+        template: None,
+        image: verification.spec.image.clone(),
+        backoff_limit: None,
+    };
+
+    let mut restore = KafkaRestore::new(restore_name, spec);
+    restore.metadata.namespace = Some(namespace);
+    restore.metadata.labels = Some(verification_labels(&name));
+    restore.metadata.owner_references = Some(vec![OwnerReference {
+        api_version: "kafkabackup.com/v1alpha1".to_string(),
+        kind: "KafkaBackupVerification".to_string(),
+        name,
+        uid: verification.metadata.uid.clone().unwrap_or_default(),
+        controller: Some(true),
+        block_owner_deletion: Some(true),
+    }]);
+    Ok(restore)
+}
+
+fn verification_labels(name: &str) -> BTreeMap<String, String> {
+    BTreeMap::from([
+        (
+            "app.kubernetes.io/managed-by".to_string(),
+            "kafka-backup-operator".to_string(),
+        ),
+        (VERIFICATION_LABEL.to_string(), name.to_string()),
+    ])
+}
+
+fn verification_selector(name: &str) -> ListParams {
+    ListParams::default().labels(&format!("{VERIFICATION_LABEL}={name}"))
+}
+
+fn scheduled_condition(last: Option<&VerificationResult>, due: DateTime<Utc>) -> Condition {
+    let next = format!("next verification at {}", due.to_rfc3339());
+    match last {
+        Some(last) if last.result == VerificationOutcome::Failed => not_ready(
+            REASON_VERIFICATION_FAILED,
+            &format!(
+                "Backup {} failed verification: {}; {next}",
+                last.backup_id,
+                last.message.as_deref().unwrap_or("restore failed")
+            ),
+        ),
+        Some(last) => ready(
+            REASON_VERIFICATION_PASSED,
+            &format!("Backup {} verified; {next}", last.backup_id),
+        ),
+        None => ready(REASON_VERIFICATION_SCHEDULED, &format!("Scheduled {next}")),
+    }
+}
+
+/// Whether `desired` differs from `current` in anything but condition
+/// transition times. Identical statuses are not re-patched, which would
+/// churn `lastTransitionTime` and retrigger the watch.
+fn status_changed(
+    current: &KafkaBackupVerificationStatus,
+    desired: &KafkaBackupVerificationStatus,
+) -> bool {
+    let conditions = |status: &KafkaBackupVerificationStatus| {
+        status
+            .conditions
+            .iter()
+            .map(|c| {
+                (
+                    c.condition_type.clone(),
+                    c.status.clone(),
+                    c.reason.clone(),
+                    c.message.clone(),
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+    conditions(current) != conditions(desired)
+        || current.active_run != desired.active_run
+        || current.last_verification != desired.last_verification
+        || current.last_schedule_time != desired.last_schedule_time
+        || current.next_scheduled_verification != desired.next_scheduled_verification
+        || current.observed_generation != desired.observed_generation
+}
+
+async fn handle_cleanup(verification: &KafkaBackupVerification, client: &Client) -> Result<()> {
+    let name = verification.name_any();
+    let namespace = verification.namespace().unwrap_or_default();
+    if !verification
+        .metadata
+        .finalizers
+        .as_ref()
+        .is_some_and(|f| f.contains(&FINALIZER.to_string()))
+    {
+        return Ok(());
+    }
+    info!(%name, "Cleaning up KafkaBackupVerification resources");
+
+    let topics_namespace = verification
+        .status
+        .as_ref()
+        .and_then(|s| s.active_run.as_ref())
+        .and_then(|run| run.topics_namespace.clone());
+    if let Some(topics_namespace) = topics_namespace {
+        delete_kafka_topics(client, &topics_namespace, &verification_selector(&name)).await?;
+    }
+
+    let api: Api<KafkaBackupVerification> = Api::namespaced(client.clone(), &namespace);
+    remove_finalizer(&api, &name).await?;
+    info!(%name, "Cleanup complete");
+    Ok(())
+}
+
+async fn add_finalizer(api: &Api<KafkaBackupVerification>, name: &str) -> Result<()> {
+    let patch = serde_json::json!({
+        "metadata": {
+            "finalizers": [FINALIZER]
+        }
+    });
+    api.patch(
+        name,
+        &PatchParams::apply("kafka-backup-operator"),
+        &Patch::Merge(&patch),
+    )
+    .await?;
+    Ok(())
+}
+
+async fn remove_finalizer(api: &Api<KafkaBackupVerification>, name: &str) -> Result<()> {
+    let patch = serde_json::json!({
+        "metadata": {
+            "finalizers": null
+        }
+    });
+    api.patch(
+        name,
+        &PatchParams::apply("kafka-backup-operator"),
+        &Patch::Merge(&patch),
+    )
+    .await?;
+    Ok(())
+}
+
+async fn update_status_reconciliation_paused(
+    api: &Api<KafkaBackupVerification>,
+    verification: &KafkaBackupVerification,
+) -> Result<()> {
+    let generation = verification.metadata.generation.unwrap_or(0);
+    let mut status = verification.status.clone().unwrap_or_default();
+    let already_current =
+        is_condition_true(&status.conditions, CONDITION_TYPE_RECONCILIATION_PAUSED)
+            && status.observed_generation == Some(generation);
+    if already_current {
+        return Ok(());
+    }
+
+    status.conditions = vec![reconciliation_paused()];
+    status.observed_generation = Some(generation);
+    patch_status(api, &verification.name_any(), &status).await
+}
+
+async fn patch_status(
+    api: &Api<KafkaBackupVerification>,
+    name: &str,
+    status: &KafkaBackupVerificationStatus,
+) -> Result<()> {
+    // `activeRun` and `nextScheduledVerification` must be explicit nulls to be
+    // cleared, but the status skips `None` fields when serializing.
+    let mut status = serde_json::to_value(status)?;
+    if let Some(fields) = status.as_object_mut() {
+        for field in ["activeRun", "nextScheduledVerification"] {
+            fields.entry(field).or_insert(serde_json::Value::Null);
+        }
+    }
+    let patch = serde_json::json!({ "status": status });
+    api.patch_status(
+        name,
+        &PatchParams::apply("kafka-backup-operator"),
+        &Patch::Merge(&patch),
+    )
+    .await?;
+    Ok(())
+}
@@ -88,6 +88,8 @@ mod tests {
             size_bytes: None,
             topics_backed_up: None,
             partitions_backed_up: None,
+            oldest_timestamp: None,
+            newest_timestamp: None,
         }
     }
 
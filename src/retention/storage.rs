@@ -15,6 +15,24 @@ use crate::adapters::secrets::{extract_secret_data, get_secret};
 use crate::crd::common::{BackupHistoryEntry, BackupStatus, StorageSpec, StorageType};
 use crate::error::{Error, Result};
 
+/// File in a backup's prefix holding the KafkaTopics selected for the backup
+pub const KAFKA_TOPICS_FILE: &str = "kafkatopics.json";
+
+/// File in a backup's prefix holding the KafkaUsers selected by `spec.users`
+pub const KAFKA_USERS_FILE: &str = "kafkausers.json";
+
+/// File in a backup's prefix holding the backed-up Kafka CR and its
+/// KafkaNodePools
+pub const KAFKA_CLUSTER_FILE: &str = "kafkacluster.json";
+
+/// File in a backup's prefix holding the KafkaConnect clusters selected by
+/// `spec.connect` and their KafkaConnectors
+pub const KAFKA_CONNECTS_FILE: &str = "kafkaconnects.json";
+
+/// File in a backup's prefix holding the Schema Registry subjects selected
+/// by `spec.schemaRegistry`
+pub const SCHEMAS_FILE: &str = "schemas.json";
+
 struct BackupObjectStore {
     store: Arc<dyn ObjectStore>,
     prefix: Option<String>,
@@ -49,6 +67,27 @@ impl BackupObjectStore {
         Ok(bytes.to_vec())
     }
 
+    /// Like [`Self::get`], but a missing object is `None` rather than an error
+    async fn get_opt(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let path = self.full_path(key);
+        match self.store.get(&path).await {
+            Ok(response) => Ok(Some(
+                response.bytes().await.map_err(storage_error)?.to_vec(),
+            )),
+            Err(object_store::Error::NotFound { .. }) => Ok(None),
+            Err(e) => Err(storage_error(e)),
+        }
+    }
+
+    async fn put(&self, key: &str, bytes: Vec<u8>) -> Result<()> {
+        let path = self.full_path(key);
+        self.store
+            .put(&path, bytes.into())
+            .await
+            .map_err(storage_error)?;
+        Ok(())
+    }
+
     async fn list(&self, prefix: &str) -> Result<Vec<String>> {
         let path = self.full_path(prefix);
         let mut stream = self.store.list(Some(&path));
@@ -63,6 +102,17 @@ impl BackupObjectStore {
         Ok(keys)
     }
 
+    /// Fetch at most one listing entry under the prefix, to prove the store
+    /// is reachable with the configured credentials.
+    async fn probe(&self) -> Result<()> {
+        let prefix = self.prefix.as_deref().map(Path::from);
+        let mut stream = self.store.list(prefix.as_ref());
+        if let Some(item) = stream.next().await {
+            item.map_err(storage_error)?;
+        }
+        Ok(())
+    }
+
     async fn delete(&self, key: &str) -> Result<()> {
         let path = self.full_path(key);
         match self.store.delete(&path).await {
@@ -82,10 +132,39 @@ struct StoredBackupManifest {
 
 #[derive(Debug, Deserialize)]
 struct StoredTopic {
+    #[serde(default)]
+    name: String,
     #[serde(default)]
     partitions: Vec<StoredPartition>,
 }
 
+/// A topic recorded in a backup manifest
+#[derive(Clone, Debug, PartialEq)]
+pub struct BackupTopic {
+    pub name: String,
+    pub partitions: usize,
+}
+
+/// A topic recorded in a backup manifest, with the segments of all its
+/// partitions
+#[derive(Clone, Debug, PartialEq)]
+pub struct BackupTopicSegments {
+    pub name: String,
+    pub partitions: usize,
+    pub segments: Vec<BackupSegment>,
+}
+
+/// A segment recorded in a backup manifest
+#[derive(Clone, Debug, PartialEq)]
+pub struct BackupSegment {
+    pub compressed_size: u64,
+    pub record_count: Option<u64>,
+    /// Timestamp of the segment's first record
+    pub start_timestamp: Option<DateTime<Utc>>,
+    /// Timestamp of the segment's last record
+    pub end_timestamp: Option<DateTime<Utc>>,
+}
+
 #[derive(Debug, Deserialize)]
 struct StoredPartition {
     #[serde(default)]
@@ -96,6 +175,14 @@ struct StoredPartition {
 struct StoredSegment {
     #[serde(default)]
     compressed_size: u64,
+    #[serde(default)]
+    record_count: Option<u64>,
+    /// Timestamp of the segment's first record, in milliseconds
+    #[serde(default)]
+    start_timestamp: Option<i64>,
+    /// Timestamp of the segment's last record, in milliseconds
+    #[serde(default)]
+    end_timestamp: Option<i64>,
 }
 
 pub async fn discover_backup_history(
@@ -136,6 +223,119 @@ pub async fn discover_backup_history(
     Ok(history)
 }
 
+/// Sizes, counts and record time bounds of backup `backup_id`, read from its
+/// manifest, with the number of records it holds when every segment records
+/// its count
+pub async fn read_backup_details(
+    client: &kube::Client,
+    namespace: &str,
+    storage: &StorageSpec,
+    backup_id: &str,
+) -> Result<(BackupHistoryEntry, Option<u64>)> {
+    let manifest = read_manifest(client, namespace, storage, backup_id).await?;
+    let records = manifest
+        .topics
+        .iter()
+        .flat_map(|topic| &topic.partitions)
+        .flat_map(|partition| &partition.segments)
+        .map(|segment| segment.record_count)
+        .sum();
+    Ok((manifest_to_history_entry(manifest), records))
+}
+
+/// Read the topics recorded in the manifest of backup `backup_id`
+pub async fn read_backup_topics(
+    client: &kube::Client,
+    namespace: &str,
+    storage: &StorageSpec,
+    backup_id: &str,
+) -> Result<Vec<BackupTopic>> {
+    let manifest = read_manifest(client, namespace, storage, backup_id).await?;
+    Ok(manifest
+        .topics
+        .into_iter()
+        .filter(|topic| !topic.name.is_empty())
+        .map(|topic| BackupTopic {
+            name: topic.name,
+            partitions: topic.partitions.len(),
+        })
+        .collect())
+}
+
+/// Read the topics recorded in the manifest of backup `backup_id`, with
+/// their segments
+pub async fn read_backup_segments(
+    client: &kube::Client,
+    namespace: &str,
+    storage: &StorageSpec,
+    backup_id: &str,
+) -> Result<Vec<BackupTopicSegments>> {
+    let manifest = read_manifest(client, namespace, storage, backup_id).await?;
+    Ok(manifest
+        .topics
+        .into_iter()
+        .filter(|topic| !topic.name.is_empty())
+        .map(|topic| BackupTopicSegments {
+            name: topic.name,
+            partitions: topic.partitions.len(),
+            segments: topic
+                .partitions
+                .into_iter()
+                .flat_map(|partition| partition.segments)
+                .map(|segment| BackupSegment {
+                    compressed_size: segment.compressed_size,
+                    record_count: segment.record_count,
+                    start_timestamp: segment
+                        .start_timestamp
+                        .and_then(DateTime::<Utc>::from_timestamp_millis),
+                    end_timestamp: segment
+                        .end_timestamp
+                        .and_then(DateTime::<Utc>::from_timestamp_millis),
+                })
+                .collect(),
+        })
+        .collect())
+}
+
+async fn read_manifest(
+    client: &kube::Client,
+    namespace: &str,
+    storage: &StorageSpec,
+    backup_id: &str,
+) -> Result<StoredBackupManifest> {
+    let store = build_store(client, namespace, storage).await?;
+    let bytes = store.get(&format!("{backup_id}/manifest.json")).await?;
+    serde_json::from_slice(&bytes)
+        .map_err(|e| Error::Storage(format!("invalid manifest for backup {backup_id}: {e}")))
+}
+
+/// Write `bytes` to `file_name` in the prefix of backup `backup_id`, next to
+/// its manifest
+pub async fn write_backup_file(
+    client: &kube::Client,
+    namespace: &str,
+    storage: &StorageSpec,
+    backup_id: &str,
+    file_name: &str,
+    bytes: Vec<u8>,
+) -> Result<()> {
+    let store = build_store(client, namespace, storage).await?;
+    store.put(&format!("{backup_id}/{file_name}"), bytes).await
+}
+
+/// Read `file_name` from the prefix of backup `backup_id`, or `None` when the
+/// backup does not contain it
+pub async fn read_backup_file(
+    client: &kube::Client,
+    namespace: &str,
+    storage: &StorageSpec,
+    backup_id: &str,
+    file_name: &str,
+) -> Result<Option<Vec<u8>>> {
+    let store = build_store(client, namespace, storage).await?;
+    store.get_opt(&format!("{backup_id}/{file_name}")).await
+}
+
 pub async fn prune_backup_ids(
     client: &kube::Client,
     namespace: &str,
@@ -174,6 +374,16 @@ pub async fn prune_backup_ids(
     Ok(pruned)
 }
 
+/// Check that a storage destination can be listed with its configured
+/// credentials, resolving Secrets in `namespace`.
+pub async fn check_storage_reachable(
+    client: &kube::Client,
+    namespace: &str,
+    storage: &StorageSpec,
+) -> Result<()> {
+    build_store(client, namespace, storage).await?.probe().await
+}
+
 pub fn backup_id_belongs_to_cr(backup_id: &str, owner_name: &str) -> bool {
     backup_id == owner_name || backup_id.starts_with(&format!("{owner_name}-"))
 }
@@ -353,13 +563,24 @@ fn manifest_to_history_entry(manifest: StoredBackupManifest) -> BackupHistoryEnt
         .iter()
         .map(|topic| topic.partitions.len())
         .sum::<usize>();
-    let size_bytes = manifest
-        .topics
-        .iter()
-        .flat_map(|topic| &topic.partitions)
-        .flat_map(|partition| &partition.segments)
+    let segments = || {
+        manifest
+            .topics
+            .iter()
+            .flat_map(|topic| &topic.partitions)
+            .flat_map(|partition| &partition.segments)
+    };
+    let size_bytes = segments()
         .map(|segment| segment.compressed_size)
         .sum::<u64>();
+    let oldest_timestamp = segments()
+        .filter_map(|segment| segment.start_timestamp)
+        .min()
+        .and_then(DateTime::<Utc>::from_timestamp_millis);
+    let newest_timestamp = segments()
+        .filter_map(|segment| segment.end_timestamp)
+        .max()
+        .and_then(DateTime::<Utc>::from_timestamp_millis);
 
     BackupHistoryEntry {
         id: manifest.backup_id,
@@ -369,6 +590,8 @@ fn manifest_to_history_entry(manifest: StoredBackupManifest) -> BackupHistoryEnt
         size_bytes: i64::try_from(size_bytes).ok(),
         topics_backed_up,
         partitions_backed_up: i32::try_from(partitions_backed_up).ok(),
+        oldest_timestamp,
+        newest_timestamp,
     }
 }
 
@@ -488,13 +711,20 @@ mod tests {
             backup_id: "daily-backup-1".to_string(),
             created_at: 1_777_334_400_000,
             topics: vec![StoredTopic {
+                name: "orders".to_string(),
                 partitions: vec![StoredPartition {
                     segments: vec![
                         StoredSegment {
                             compressed_size: 100,
+                            record_count: None,
+                            start_timestamp: Some(1_777_330_800_000),
+                            end_timestamp: Some(1_777_332_000_000),
                         },
                         StoredSegment {
                             compressed_size: 200,
+                            record_count: None,
+                            start_timestamp: Some(1_777_332_000_001),
+                            end_timestamp: Some(1_777_334_399_000),
                         },
                     ],
                 }],
@@ -510,6 +740,29 @@ mod tests {
         assert_eq!(entry.size_bytes, Some(300));
         assert_eq!(entry.topics_backed_up, Some(1));
         assert_eq!(entry.partitions_backed_up, Some(1));
+        assert_eq!(
+            entry.oldest_timestamp,
+            Some(Utc.with_ymd_and_hms(2026, 4, 27, 23, 0, 0).unwrap())
+        );
+        assert_eq!(
+            entry.newest_timestamp,
+            Some(Utc.with_ymd_and_hms(2026, 4, 27, 23, 59, 59).unwrap())
+        );
+    }
+
+    #[test]
+    fn manifest_entry_without_segment_timestamps_has_no_time_bounds() {
+        let manifest: StoredBackupManifest = serde_json::from_value(serde_json::json!({
+            "backup_id": "daily-backup-1",
+            "created_at": 1_777_334_400_000_i64,
+            "topics": [{"name": "orders", "partitions": [{"segments": [{"compressed_size": 42}]}]}]
+        }))
+        .unwrap();
+
+        let entry = manifest_to_history_entry(manifest);
+        assert_eq!(entry.size_bytes, Some(42));
+        assert_eq!(entry.oldest_timestamp, None);
+        assert_eq!(entry.newest_timestamp, None);
     }
 
     #[tokio::test]
@@ -538,4 +791,32 @@ mod tests {
 
         assert!(store.list("daily-backup-1/").await.unwrap().is_empty());
     }
+
+    #[tokio::test]
+    async fn local_store_round_trips_backup_files() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let store = BackupObjectStore::new(
+            Arc::new(LocalFileSystem::new_with_prefix(temp_dir.path()).unwrap()),
+            None,
+        );
+
+        assert_eq!(
+            store
+                .get_opt("daily-backup-1/kafkatopics.json")
+                .await
+                .unwrap(),
+            None
+        );
+        store
+            .put("daily-backup-1/kafkatopics.json", b"[]".to_vec())
+            .await
+            .unwrap();
+        assert_eq!(
+            store
+                .get_opt("daily-backup-1/kafkatopics.json")
+                .await
+                .unwrap(),
+            Some(b"[]".to_vec())
+        );
+    }
 }
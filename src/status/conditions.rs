@@ -9,6 +9,7 @@ pub const CONDITION_TYPE_RESTORE_COMPLETE: &str = "RestoreComplete";
 pub const CONDITION_TYPE_SCHEDULED: &str = "Scheduled";
 pub const CONDITION_TYPE_ERROR: &str = "Error";
 pub const CONDITION_TYPE_RECONCILIATION_PAUSED: &str = "ReconciliationPaused";
+pub const CONDITION_TYPE_VERIFIED: &str = "Verified";
 
 /// Condition status values
 pub const STATUS_TRUE: &str = "True";
@@ -22,13 +23,29 @@ pub const REASON_BACKUP_COMPLETED: &str = "BackupCompleted";
 pub const REASON_BACKUP_FAILED: &str = "BackupFailed";
 pub const REASON_BACKUP_SCHEDULED: &str = "BackupScheduled";
 pub const REASON_BACKUP_SUSPENDED: &str = "BackupSuspended";
+pub const REASON_RETENTION_PRUNED: &str = "RetentionPruned";
+pub const REASON_SNAPSHOTS_INCOMPLETE: &str = "SnapshotsIncomplete";
 pub const REASON_RESTORE_RUNNING: &str = "RestoreRunning";
 pub const REASON_RESTORE_COMPLETED: &str = "RestoreCompleted";
 pub const REASON_RESTORE_FAILED: &str = "RestoreFailed";
+pub const REASON_WAITING_FOR_TOPICS: &str = "WaitingForTopics";
+pub const REASON_WAITING_FOR_CLUSTER: &str = "WaitingForCluster";
 pub const REASON_CLUSTER_NOT_FOUND: &str = "ClusterNotFound";
 pub const REASON_INVALID_CONFIG: &str = "InvalidConfiguration";
 pub const REASON_SECRET_NOT_FOUND: &str = "SecretNotFound";
 pub const REASON_RECONCILIATION_PAUSED: &str = "ReconciliationPaused";
+pub const REASON_CLUSTERS_READY: &str = "ClustersReady";
+pub const REASON_CLUSTERS_NOT_READY: &str = "ClustersNotReady";
+pub const REASON_NO_CLUSTERS_MATCHED: &str = "NoClustersMatched";
+pub const REASON_STORAGE_REACHABLE: &str = "StorageReachable";
+pub const REASON_STORAGE_UNREACHABLE: &str = "StorageUnreachable";
+pub const REASON_REACHABILITY_NOT_CHECKED: &str = "ReachabilityNotChecked";
+pub const REASON_VERIFICATION_SCHEDULED: &str = "VerificationScheduled";
+pub const REASON_VERIFICATION_SUSPENDED: &str = "VerificationSuspended";
+pub const REASON_VERIFICATION_RUNNING: &str = "VerificationRunning";
+pub const REASON_VERIFICATION_PASSED: &str = "VerificationPassed";
+pub const REASON_VERIFICATION_FAILED: &str = "VerificationFailed";
+pub const REASON_NO_BACKUP_TO_VERIFY: &str = "NoBackupToVerify";
 
 /// Create a new condition
 pub fn new_condition(condition_type: &str, status: &str, reason: &str, message: &str) -> Condition {
@@ -103,6 +120,17 @@ pub fn error_conditions(reason: &str, message: &str) -> Vec<Condition> {
     ]
 }
 
+/// Replace a resource's conditions, keeping its `Verified` condition. That
+/// condition is written by KafkaBackupVerification, not by the reconciler
+/// that owns the rest of the conditions.
+pub fn replace_conditions(conditions: &mut Vec<Condition>, replacement: Vec<Condition>) {
+    let verified = find_condition(conditions, CONDITION_TYPE_VERIFIED).cloned();
+    *conditions = replacement;
+    if let Some(verified) = verified {
+        set_condition(conditions, verified);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
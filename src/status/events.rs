@@ -0,0 +1,52 @@
+use kube::runtime::events::{Event, EventType, Recorder, Reporter};
+use kube::{Client, Resource};
+use tracing::warn;
+
+/// Controller name Events are reported under
+pub const REPORTING_CONTROLLER: &str = "strimzi-backup-operator";
+
+/// Environment variable holding the operator pod's name, reported as the
+/// Event's reporting instance
+pub const POD_NAME_ENV: &str = "POD_NAME";
+
+/// Event actions
+pub const ACTION_CREATE_JOB: &str = "CreateJob";
+pub const ACTION_RECORD_RESULT: &str = "RecordResult";
+pub const ACTION_PRUNE: &str = "Prune";
+pub const ACTION_RECONCILE: &str = "Reconcile";
+
+/// Recorder for Events regarding `resource`
+pub fn recorder<K>(client: Client, resource: &K) -> Recorder
+where
+    K: Resource<DynamicType = ()>,
+{
+    let reporter = Reporter {
+        controller: REPORTING_CONTROLLER.to_string(),
+        instance: std::env::var(POD_NAME_ENV).ok(),
+    };
+    Recorder::new(client, reporter, resource.object_ref(&()))
+}
+
+/// Publish a Normal Event. Events only narrate what the status already
+/// records, so failing to publish one is logged and otherwise ignored.
+pub async fn normal(recorder: &Recorder, reason: &str, action: &str, note: String) {
+    publish(recorder, EventType::Normal, reason, action, note).await;
+}
+
+/// Publish a Warning Event, ignoring failures like [`normal`]
+pub async fn warning(recorder: &Recorder, reason: &str, action: &str, note: String) {
+    publish(recorder, EventType::Warning, reason, action, note).await;
+}
+
+async fn publish(recorder: &Recorder, type_: EventType, reason: &str, action: &str, note: String) {
+    let event = Event {
+        type_,
+        reason: reason.to_string(),
+        note: Some(note),
+        action: action.to_string(),
+        secondary: None,
+    };
+    if let Err(e) = recorder.publish(event).await {
+        warn!(%reason, error = %e, "Failed to publish Event");
+    }
+}
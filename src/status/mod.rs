@@ -1,3 +1,4 @@
 pub mod conditions;
+pub mod events;
 
 pub use conditions::*;
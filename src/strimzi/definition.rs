@@ -0,0 +1,113 @@
+use std::collections::BTreeMap;
+
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use kube::{
+    api::{DynamicObject, ListParams},
+    Client, ResourceExt,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+
+use super::resource::{apply_namespaced_resource, list_namespaced_resources};
+
+/// Label Strimzi uses to assign a resource to a Kafka cluster
+pub const STRIMZI_CLUSTER_LABEL: &str = "strimzi.io/cluster";
+
+/// Annotations that describe how a resource was applied rather than the
+/// resource itself, and are not carried into a backup
+const TRANSIENT_ANNOTATIONS: &[&str] = &["kubectl.kubernetes.io/last-applied-configuration"];
+
+/// A Strimzi resource as recorded in a backup: its name, labels, annotations
+/// and spec, without the server-populated metadata and status
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct ResourceDefinition {
+    pub metadata: ObjectMeta,
+    #[serde(default)]
+    pub spec: serde_json::Value,
+}
+
+impl ResourceDefinition {
+    pub(crate) fn from_resource(resource: DynamicObject) -> Self {
+        let annotations: BTreeMap<String, String> = resource
+            .annotations()
+            .iter()
+            .filter(|(key, _)| !TRANSIENT_ANNOTATIONS.contains(&key.as_str()))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+        let labels = resource.labels().clone();
+        Self {
+            metadata: ObjectMeta {
+                name: Some(resource.name_any()),
+                labels: (!labels.is_empty()).then_some(labels),
+                annotations: (!annotations.is_empty()).then_some(annotations),
+                ..Default::default()
+            },
+            spec: resource
+                .data
+                .get("spec")
+                .cloned()
+                .unwrap_or_else(|| serde_json::json!({})),
+        }
+    }
+
+    /// Name of the resource
+    pub fn name(&self) -> &str {
+        self.metadata.name.as_deref().unwrap_or_default()
+    }
+
+    /// Set a label, adding the labels map if needed
+    pub fn set_label(&mut self, key: &str, value: &str) {
+        self.metadata
+            .labels
+            .get_or_insert_with(BTreeMap::new)
+            .insert(key.to_string(), value.to_string());
+    }
+}
+
+/// List the `kind` resources in `namespace` that belong to Strimzi cluster
+/// `cluster`, as definitions that can be recreated elsewhere
+pub(crate) async fn list_cluster_definitions(
+    client: &Client,
+    namespace: &str,
+    kind: &str,
+    cluster: &str,
+) -> Result<Vec<ResourceDefinition>> {
+    let params = ListParams::default().labels(&format!("{STRIMZI_CLUSTER_LABEL}={cluster}"));
+    let mut definitions: Vec<ResourceDefinition> =
+        list_namespaced_resources(client, namespace, kind, &params)
+            .await?
+            .into_iter()
+            .map(ResourceDefinition::from_resource)
+            .collect();
+    definitions.sort_by(|a, b| a.metadata.name.cmp(&b.metadata.name));
+    Ok(definitions)
+}
+
+/// Create or update a `kind` resource from `definition` on Strimzi cluster
+/// `cluster`, replacing any `strimzi.io/cluster` label it carries
+pub(crate) async fn apply_cluster_definition(
+    client: &Client,
+    namespace: &str,
+    kind: &str,
+    cluster: &str,
+    definition: &ResourceDefinition,
+) -> Result<()> {
+    let mut definition = definition.clone();
+    definition.set_label(STRIMZI_CLUSTER_LABEL, cluster);
+    apply_definition(client, namespace, kind, &definition).await
+}
+
+/// Create or update a `kind` resource in `namespace` from `definition`
+pub(crate) async fn apply_definition(
+    client: &Client,
+    namespace: &str,
+    kind: &str,
+    definition: &ResourceDefinition,
+) -> Result<()> {
+    let mut definition = definition.clone();
+    definition.metadata.namespace = Some(namespace.to_string());
+    let object = serde_json::to_value(&definition)?;
+    apply_namespaced_resource(client, namespace, kind, definition.name(), &object).await?;
+    Ok(())
+}
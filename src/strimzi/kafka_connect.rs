@@ -0,0 +1,124 @@
+use kube::{api::ListParams, Client};
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+
+use super::definition::{
+    apply_cluster_definition, apply_definition, list_cluster_definitions, ResourceDefinition,
+};
+use super::resource::list_namespaced_resources;
+
+/// Worker config keys naming the internal topics of a KafkaConnect cluster,
+/// with the defaults Strimzi applies when they are not set
+pub const CONNECT_STORAGE_TOPICS: [(&str, &str); 3] = [
+    ("offset.storage.topic", "connect-cluster-offsets"),
+    ("config.storage.topic", "connect-cluster-configs"),
+    ("status.storage.topic", "connect-cluster-status"),
+];
+
+/// A KafkaConnect cluster and its KafkaConnectors as recorded in a backup
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct KafkaConnectDefinition {
+    pub kafka_connect: ResourceDefinition,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub connectors: Vec<ResourceDefinition>,
+}
+
+impl KafkaConnectDefinition {
+    /// Name of the KafkaConnect cluster
+    pub fn name(&self) -> &str {
+        self.kafka_connect.name()
+    }
+}
+
+/// The KafkaConnect clusters stored with a backup, together with the name of
+/// the Kafka cluster they were connected to
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct KafkaConnectSnapshot {
+    pub kafka_cluster: String,
+    #[serde(default)]
+    pub kafka_connects: Vec<KafkaConnectDefinition>,
+}
+
+/// The `(config key, topic)` pairs of the internal topics of `connect`
+pub fn connect_storage_topics(connect: &ResourceDefinition) -> Vec<(&'static str, String)> {
+    CONNECT_STORAGE_TOPICS
+        .iter()
+        .map(|(key, default)| {
+            let topic = connect
+                .spec
+                .pointer("/config")
+                .and_then(|config| config.get(*key))
+                .and_then(|topic| topic.as_str())
+                .unwrap_or(default);
+            (*key, topic.to_string())
+        })
+        .collect()
+}
+
+/// Whether the `host:port` address `server` is the bootstrap Service of
+/// Strimzi cluster `cluster`, under its short or namespace-qualified name
+pub fn is_cluster_bootstrap(server: &str, cluster: &str) -> bool {
+    let host = server.trim().split(':').next().unwrap_or_default();
+    let service = format!("{cluster}-kafka-bootstrap");
+    host == service || host.starts_with(&format!("{service}."))
+}
+
+/// List the KafkaConnect clusters in `namespace` whose bootstrapServers point
+/// at Strimzi cluster `cluster`, each with its KafkaConnectors
+pub async fn list_kafka_connect_definitions(
+    client: &Client,
+    namespace: &str,
+    cluster: &str,
+) -> Result<Vec<KafkaConnectDefinition>> {
+    let mut connects = Vec::new();
+    for resource in
+        list_namespaced_resources(client, namespace, "KafkaConnect", &ListParams::default()).await?
+    {
+        let kafka_connect = ResourceDefinition::from_resource(resource);
+        let targets_cluster = kafka_connect
+            .spec
+            .get("bootstrapServers")
+            .and_then(|servers| servers.as_str())
+            .is_some_and(|servers| {
+                servers
+                    .split(',')
+                    .any(|server| is_cluster_bootstrap(server, cluster))
+            });
+        if !targets_cluster {
+            continue;
+        }
+        let connectors =
+            list_cluster_definitions(client, namespace, "KafkaConnector", kafka_connect.name())
+                .await?;
+        connects.push(KafkaConnectDefinition {
+            kafka_connect,
+            connectors,
+        });
+    }
+    connects.sort_by(|a, b| a.name().cmp(b.name()));
+    Ok(connects)
+}
+
+/// Create or update the KafkaConnect cluster in `definition` and its
+/// KafkaConnectors in `namespace`
+pub async fn apply_kafka_connect_definition(
+    client: &Client,
+    namespace: &str,
+    definition: &KafkaConnectDefinition,
+) -> Result<()> {
+    apply_definition(client, namespace, "KafkaConnect", &definition.kafka_connect).await?;
+    for connector in &definition.connectors {
+        apply_cluster_definition(
+            client,
+            namespace,
+            "KafkaConnector",
+            definition.name(),
+            connector,
+        )
+        .await?;
+    }
+    Ok(())
+}
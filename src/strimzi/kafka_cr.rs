@@ -1,10 +1,19 @@
-use kube::{api::DynamicObject, Client, ResourceExt};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
+use kube::{
+    api::{DynamicObject, ListParams},
+    core::Selector,
+    Client, ResourceExt,
+};
+use serde::{Deserialize, Serialize};
 use tracing::{debug, info};
 
 use crate::crd::common::{AuthenticationType, StrimziClusterRef};
 use crate::error::{Error, Result};
 
-use super::resource::get_namespaced_resource;
+use super::definition::{
+    apply_cluster_definition, apply_definition, list_cluster_definitions, ResourceDefinition,
+};
+use super::resource::{get_namespaced_resource, is_not_found, is_ready, list_namespaced_resources};
 
 /// Resolved information from a Strimzi Kafka CR
 #[derive(Clone, Debug)]
@@ -73,6 +82,93 @@ pub async fn resolve_kafka_cluster(
     Ok(resolved)
 }
 
+/// The Kafka CR and KafkaNodePools of a cluster as recorded in a backup
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct KafkaClusterDefinition {
+    pub kafka: ResourceDefinition,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub node_pools: Vec<ResourceDefinition>,
+}
+
+/// Read the Kafka CR `name` and its KafkaNodePools, without status or
+/// server-populated metadata
+pub async fn read_kafka_cluster_definition(
+    client: &Client,
+    namespace: &str,
+    name: &str,
+) -> Result<KafkaClusterDefinition> {
+    let kafka = get_namespaced_resource(client, namespace, "Kafka", name)
+        .await
+        .map_err(|e| match &e {
+            kube::Error::Api(ae) if ae.code == 404 => Error::StrimziClusterNotFound {
+                name: name.to_string(),
+                namespace: namespace.to_string(),
+            },
+            _ => Error::Kube(e),
+        })?;
+    Ok(KafkaClusterDefinition {
+        kafka: ResourceDefinition::from_resource(kafka),
+        node_pools: list_cluster_definitions(client, namespace, "KafkaNodePool", name).await?,
+    })
+}
+
+/// Whether the Kafka CR `name` is Ready, or `None` when it does not exist
+pub async fn kafka_cluster_ready(
+    client: &Client,
+    namespace: &str,
+    name: &str,
+) -> Result<Option<bool>> {
+    match get_namespaced_resource(client, namespace, "Kafka", name).await {
+        Ok(kafka) => Ok(Some(is_ready(&kafka))),
+        Err(e) if is_not_found(&e) => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Create the cluster in `definition` as Kafka CR `name`. The KafkaNodePools
+/// are applied first, since the Cluster Operator needs them to deploy brokers.
+pub async fn create_kafka_cluster(
+    client: &Client,
+    namespace: &str,
+    name: &str,
+    definition: &KafkaClusterDefinition,
+) -> Result<()> {
+    for node_pool in &definition.node_pools {
+        apply_cluster_definition(client, namespace, "KafkaNodePool", name, node_pool).await?;
+    }
+    let mut kafka = definition.kafka.clone();
+    kafka.metadata.name = Some(name.to_string());
+    apply_definition(client, namespace, "Kafka", &kafka).await?;
+    info!(
+        %name,
+        %namespace,
+        node_pools = definition.node_pools.len(),
+        "Created Kafka cluster from backup"
+    );
+    Ok(())
+}
+
+/// List the names of Strimzi Kafka CRs in `namespace` matching `selector`.
+/// An empty selector matches every Kafka CR in the namespace.
+pub async fn list_kafka_clusters(
+    client: &Client,
+    namespace: &str,
+    selector: &LabelSelector,
+) -> Result<Vec<String>> {
+    let selector = Selector::try_from(selector.clone())
+        .map_err(|e| Error::InvalidConfig(format!("invalid clusterSelector: {e}")))?;
+    let params = ListParams::default().labels_from(&selector);
+
+    let mut names: Vec<String> = list_namespaced_resources(client, namespace, "Kafka", &params)
+        .await?
+        .iter()
+        .map(|kafka| kafka.name_any())
+        .collect();
+    names.sort();
+    Ok(names)
+}
+
 /// Connection details derived from one listener of the Kafka CR
 #[derive(Debug, PartialEq)]
 struct ResolvedConnection {
@@ -0,0 +1,121 @@
+use std::collections::BTreeMap;
+
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use kube::{api::ListParams, Client, ResourceExt};
+use tracing::info;
+
+use crate::error::Result;
+
+use super::definition::{apply_cluster_definition, list_cluster_definitions, ResourceDefinition};
+use super::resource::{delete_namespaced_resources, is_ready, list_namespaced_resources};
+
+/// Name of the Kafka topic a KafkaTopic definition describes: `spec.topicName`,
+/// or the resource name when the topic name is a valid resource name
+pub fn kafka_topic_name(topic: &ResourceDefinition) -> &str {
+    topic
+        .spec
+        .get("topicName")
+        .and_then(|name| name.as_str())
+        .unwrap_or(topic.name())
+}
+
+/// KafkaTopic resource name for Kafka topic `topic_name`. Kafka allows `_` and
+/// upper case in topic names, which Kubernetes names do not; the Topic
+/// Operator reads the real name from `spec.topicName`.
+pub fn kafka_topic_resource_name(topic_name: &str) -> String {
+    let name: String = topic_name
+        .to_ascii_lowercase()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '.' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .take(253)
+        .collect();
+    name.trim_matches(|c: char| c == '-' || c == '.')
+        .to_string()
+}
+
+/// Create or update the KafkaTopic `name` for Kafka topic `topic_name` on
+/// Strimzi cluster `cluster`. The Strimzi Topic Operator creates the topic.
+pub async fn apply_kafka_topic(
+    client: &Client,
+    namespace: &str,
+    cluster: &str,
+    name: &str,
+    topic_name: &str,
+    partitions: Option<i32>,
+    labels: BTreeMap<String, String>,
+) -> Result<()> {
+    let mut spec = serde_json::json!({ "topicName": topic_name });
+    if let Some(partitions) = partitions {
+        spec["partitions"] = partitions.into();
+    }
+    let topic = ResourceDefinition {
+        metadata: ObjectMeta {
+            name: Some(name.to_string()),
+            labels: Some(labels),
+            ..Default::default()
+        },
+        spec,
+    };
+    apply_kafka_topic_definition(client, namespace, cluster, &topic).await
+}
+
+/// Create or update a KafkaTopic from `topic` on Strimzi cluster `cluster`
+pub async fn apply_kafka_topic_definition(
+    client: &Client,
+    namespace: &str,
+    cluster: &str,
+    topic: &ResourceDefinition,
+) -> Result<()> {
+    apply_cluster_definition(client, namespace, "KafkaTopic", cluster, topic).await
+}
+
+/// List the KafkaTopics in `namespace` that belong to Strimzi cluster `cluster`
+pub async fn list_kafka_topic_definitions(
+    client: &Client,
+    namespace: &str,
+    cluster: &str,
+) -> Result<Vec<ResourceDefinition>> {
+    list_cluster_definitions(client, namespace, "KafkaTopic", cluster).await
+}
+
+/// Names of the KafkaTopics in `names` that the Topic Operator has not yet
+/// reported Ready for their current generation. A topic that `params` does
+/// not select counts as not ready.
+pub async fn unready_kafka_topics(
+    client: &Client,
+    namespace: &str,
+    params: &ListParams,
+    names: &[String],
+) -> Result<Vec<String>> {
+    let ready: Vec<String> = list_namespaced_resources(client, namespace, "KafkaTopic", params)
+        .await?
+        .into_iter()
+        .filter(is_ready)
+        .map(|topic| topic.name_any())
+        .collect();
+    Ok(names
+        .iter()
+        .filter(|name| !ready.contains(name))
+        .cloned()
+        .collect())
+}
+
+/// Delete the KafkaTopics in `namespace` matching `params`. The Strimzi Topic
+/// Operator deletes the topics themselves from Kafka.
+pub async fn delete_kafka_topics(
+    client: &Client,
+    namespace: &str,
+    params: &ListParams,
+) -> Result<usize> {
+    let deleted = delete_namespaced_resources(client, namespace, "KafkaTopic", params).await?;
+    if deleted > 0 {
+        info!(%namespace, deleted, "Deleted KafkaTopics");
+    }
+    Ok(deleted)
+}
@@ -4,6 +4,7 @@ use tracing::{debug, info};
 use crate::crd::common::{AuthenticationSpec, AuthenticationType};
 use crate::error::{Error, Result};
 
+use super::definition::{apply_cluster_definition, list_cluster_definitions, ResourceDefinition};
 use super::resource::get_namespaced_resource;
 
 /// Resolved authentication credentials
@@ -37,12 +38,44 @@ pub async fn resolve_auth(
         return Ok(ResolvedAuth::None);
     };
 
+    check_authentication(auth)?;
     match auth.auth_type {
         AuthenticationType::Tls => resolve_tls_auth(client, auth, namespace).await,
         AuthenticationType::ScramSha512 => resolve_scram_auth(client, auth, namespace).await,
     }
 }
 
+/// Check that `auth` names the credentials its type requires, without
+/// looking them up
+pub fn check_authentication(auth: &AuthenticationSpec) -> Result<()> {
+    match auth.auth_type {
+        AuthenticationType::Tls => {
+            if auth.kafka_user_ref.is_none() && auth.certificate_and_key.is_none() {
+                return Err(Error::InvalidConfig(
+                    "TLS authentication requires either kafkaUserRef or certificateAndKey"
+                        .to_string(),
+                ));
+            }
+        }
+        AuthenticationType::ScramSha512 => {
+            if auth.kafka_user_ref.is_none() {
+                if auth.username.is_none() {
+                    return Err(Error::InvalidConfig(
+                        "SCRAM authentication requires username".to_string(),
+                    ));
+                }
+                if auth.password_secret.is_none() {
+                    return Err(Error::InvalidConfig(
+                        "SCRAM authentication requires either kafkaUserRef or passwordSecret"
+                            .to_string(),
+                    ));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Resolve TLS authentication credentials
 async fn resolve_tls_auth(
     client: &Client,
@@ -132,3 +165,36 @@ async fn resolve_kafka_user_secret(
     debug!(%user_name, "Using conventional secret name for KafkaUser");
     Ok(user_name.to_string())
 }
+
+/// List the KafkaUsers in `namespace` that belong to Strimzi cluster
+/// `cluster`, with their ACLs and quotas but without credentials: a password
+/// supplied through `spec.authentication.password` is dropped, so the User
+/// Operator generates a new one when the user is recreated.
+pub async fn list_kafka_user_definitions(
+    client: &Client,
+    namespace: &str,
+    cluster: &str,
+) -> Result<Vec<ResourceDefinition>> {
+    let mut users = list_cluster_definitions(client, namespace, "KafkaUser", cluster).await?;
+    for user in &mut users {
+        if let Some(authentication) = user
+            .spec
+            .get_mut("authentication")
+            .and_then(|a| a.as_object_mut())
+        {
+            authentication.remove("password");
+        }
+    }
+    Ok(users)
+}
+
+/// Create or update a KafkaUser from `user` on Strimzi cluster `cluster`. The
+/// Strimzi User Operator creates its credentials and ACLs.
+pub async fn apply_kafka_user_definition(
+    client: &Client,
+    namespace: &str,
+    cluster: &str,
+    user: &ResourceDefinition,
+) -> Result<()> {
+    apply_cluster_definition(client, namespace, "KafkaUser", cluster, user).await
+}
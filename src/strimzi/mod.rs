@@ -1,4 +1,7 @@
+pub mod definition;
+pub mod kafka_connect;
 pub mod kafka_cr;
+pub mod kafka_topic;
 pub mod kafka_user;
 mod resource;
 pub mod tls;
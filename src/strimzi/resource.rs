@@ -1,6 +1,6 @@
 use kube::{
-    api::{Api, ApiResource, DynamicObject, GroupVersionKind},
-    Client,
+    api::{Api, ApiResource, DynamicObject, GroupVersionKind, ListParams, Patch, PatchParams},
+    Client, ResourceExt,
 };
 use tracing::debug;
 
@@ -38,6 +38,127 @@ pub(crate) async fn get_namespaced_resource(
     }
 }
 
+/// List namespaced Strimzi resources, with the same `v1` → `v1beta2`
+/// fallback as [`get_namespaced_resource`]. A list only returns 404 when the
+/// API version itself is not served.
+pub(crate) async fn list_namespaced_resources(
+    client: &Client,
+    namespace: &str,
+    kind: &str,
+    params: &ListParams,
+) -> Result<Vec<DynamicObject>, kube::Error> {
+    match list_at_version(client, namespace, kind, params, PREFERRED_API_VERSION).await {
+        Ok(resources) => Ok(resources),
+        Err(error) if is_not_found(&error) => {
+            debug!(
+                %kind,
+                %namespace,
+                version = PREFERRED_API_VERSION,
+                fallback_version = LEGACY_API_VERSION,
+                "Strimzi API version not served; listing through legacy API"
+            );
+            list_at_version(client, namespace, kind, params, LEGACY_API_VERSION).await
+        }
+        Err(error) => Err(error),
+    }
+}
+
+/// Server-side apply a namespaced Strimzi resource, with the same `v1` →
+/// `v1beta2` fallback as [`get_namespaced_resource`]. `object` carries the
+/// metadata and spec; `apiVersion` and `kind` are filled in per version.
+pub(crate) async fn apply_namespaced_resource(
+    client: &Client,
+    namespace: &str,
+    kind: &str,
+    name: &str,
+    object: &serde_json::Value,
+) -> Result<(), kube::Error> {
+    match apply_at_version(client, namespace, kind, name, object, PREFERRED_API_VERSION).await {
+        Ok(()) => Ok(()),
+        Err(error) if is_not_found(&error) => {
+            debug!(
+                %kind,
+                %name,
+                %namespace,
+                version = PREFERRED_API_VERSION,
+                fallback_version = LEGACY_API_VERSION,
+                "Strimzi API version not served; applying through legacy API"
+            );
+            apply_at_version(client, namespace, kind, name, object, LEGACY_API_VERSION).await
+        }
+        Err(error) => Err(error),
+    }
+}
+
+/// Delete the namespaced Strimzi resources matching `params`, returning how
+/// many were deleted. Resources already gone are not an error.
+pub(crate) async fn delete_namespaced_resources(
+    client: &Client,
+    namespace: &str,
+    kind: &str,
+    params: &ListParams,
+) -> Result<usize, kube::Error> {
+    let resources = list_namespaced_resources(client, namespace, kind, params).await?;
+    let mut deleted = 0;
+    for resource in &resources {
+        let version = resource
+            .types
+            .as_ref()
+            .and_then(|types| types.api_version.rsplit('/').next())
+            .unwrap_or(PREFERRED_API_VERSION);
+        let api_resource =
+            ApiResource::from_gvk(&GroupVersionKind::gvk(STRIMZI_GROUP, version, kind));
+        let api: Api<DynamicObject> =
+            Api::namespaced_with(client.clone(), namespace, &api_resource);
+        match api
+            .delete(
+                &resource.name_any(),
+                &crate::reconcilers::cleanup_delete_params(),
+            )
+            .await
+        {
+            Ok(_) => deleted += 1,
+            Err(error) if is_not_found(&error) => {}
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(deleted)
+}
+
+async fn apply_at_version(
+    client: &Client,
+    namespace: &str,
+    kind: &str,
+    name: &str,
+    object: &serde_json::Value,
+    version: &str,
+) -> Result<(), kube::Error> {
+    let resource = ApiResource::from_gvk(&GroupVersionKind::gvk(STRIMZI_GROUP, version, kind));
+    let api: Api<DynamicObject> = Api::namespaced_with(client.clone(), namespace, &resource);
+    let mut object = object.clone();
+    object["apiVersion"] = resource.api_version.clone().into();
+    object["kind"] = kind.into();
+    api.patch(
+        name,
+        &PatchParams::apply("kafka-backup-operator").force(),
+        &Patch::Apply(object),
+    )
+    .await?;
+    Ok(())
+}
+
+async fn list_at_version(
+    client: &Client,
+    namespace: &str,
+    kind: &str,
+    params: &ListParams,
+    version: &str,
+) -> Result<Vec<DynamicObject>, kube::Error> {
+    let resource = ApiResource::from_gvk(&GroupVersionKind::gvk(STRIMZI_GROUP, version, kind));
+    let api: Api<DynamicObject> = Api::namespaced_with(client.clone(), namespace, &resource);
+    Ok(api.list(params).await?.items)
+}
+
 async fn get_at_version(
     client: &Client,
     namespace: &str,
@@ -50,6 +171,29 @@ async fn get_at_version(
     api.get(name).await
 }
 
-fn is_not_found(error: &kube::Error) -> bool {
+/// Whether a Strimzi resource reports a `Ready=True` condition observed at its
+/// current generation
+pub(crate) fn is_ready(resource: &DynamicObject) -> bool {
+    let status = resource.data.get("status");
+    let observed_generation = status
+        .and_then(|s| s.get("observedGeneration"))
+        .and_then(|g| g.as_i64());
+    let current = match (resource.metadata.generation, observed_generation) {
+        (Some(generation), Some(observed)) => observed >= generation,
+        _ => true,
+    };
+    let ready = status
+        .and_then(|s| s.get("conditions"))
+        .and_then(|c| c.as_array())
+        .is_some_and(|conditions| {
+            conditions.iter().any(|c| {
+                c.get("type").and_then(|t| t.as_str()) == Some("Ready")
+                    && c.get("status").and_then(|s| s.as_str()) == Some("True")
+            })
+        });
+    current && ready
+}
+
+pub(crate) fn is_not_found(error: &kube::Error) -> bool {
     matches!(error, kube::Error::Api(response) if response.code == 404)
 }
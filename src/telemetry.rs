@@ -0,0 +1,135 @@
+//! Optional export of the operator's spans over OTLP/HTTP, and propagation of
+//! the trace context into the Jobs it creates so that a backup or restore run
+//! can be followed from the reconcile that started it.
+
+use std::collections::HashMap;
+
+use k8s_openapi::api::core::v1::EnvVar;
+use opentelemetry::propagation::TextMapPropagator;
+use opentelemetry::trace::{TraceContextExt, TracerProvider as _};
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::{SdkTracer, SdkTracerProvider};
+use opentelemetry_sdk::Resource;
+use tracing::Subscriber;
+use tracing_opentelemetry::{OpenTelemetryLayer, OpenTelemetrySpanExt};
+use tracing_subscriber::registry::LookupSpan;
+
+use crate::error::{Error, Result};
+
+/// Standard OpenTelemetry environment variables read by [`TracingConfig`]
+pub const ENDPOINT_ENV: &str = "OTEL_EXPORTER_OTLP_ENDPOINT";
+pub const TRACES_ENDPOINT_ENV: &str = "OTEL_EXPORTER_OTLP_TRACES_ENDPOINT";
+pub const SERVICE_NAME_ENV: &str = "OTEL_SERVICE_NAME";
+
+/// Service name reported when [`SERVICE_NAME_ENV`] is unset
+pub const DEFAULT_SERVICE_NAME: &str = "strimzi-backup-operator";
+
+/// Environment variable carrying the W3C trace context into Job containers
+pub const TRACEPARENT_ENV: &str = "TRACEPARENT";
+
+/// OTLP exporter settings
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TracingConfig {
+    /// URL spans are posted to, ending in `/v1/traces`
+    pub endpoint: String,
+    /// `service.name` of the exported spans
+    pub service_name: String,
+}
+
+impl TracingConfig {
+    /// Read the exporter settings, `None` unless an OTLP endpoint is set.
+    /// [`ENDPOINT_ENV`] is the collector's base URL, while
+    /// [`TRACES_ENDPOINT_ENV`] is used as is.
+    pub fn from_env() -> Option<Self> {
+        let var = |name| std::env::var(name).ok().filter(|v| !v.trim().is_empty());
+        let endpoint = var(TRACES_ENDPOINT_ENV).or_else(|| {
+            var(ENDPOINT_ENV).map(|base| format!("{}/v1/traces", base.trim_end_matches('/')))
+        })?;
+        Some(Self {
+            endpoint,
+            service_name: var(SERVICE_NAME_ENV).unwrap_or_else(|| DEFAULT_SERVICE_NAME.to_string()),
+        })
+    }
+}
+
+/// Tracer provider exporting batches of spans to the configured endpoint.
+/// Spans are exported from a background thread; shut the provider down to
+/// flush the last batch.
+pub fn tracer_provider(config: &TracingConfig) -> Result<SdkTracerProvider> {
+    let exporter = SpanExporter::builder()
+        .with_http()
+        .with_endpoint(&config.endpoint)
+        .build()
+        .map_err(|e| Error::InvalidConfig(format!("OTLP exporter: {e}")))?;
+    let resource = Resource::builder()
+        .with_service_name(config.service_name.clone())
+        .build();
+    Ok(SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(resource)
+        .build())
+}
+
+/// `tracing` layer recording spans with `provider`
+pub fn layer<S>(provider: &SdkTracerProvider) -> OpenTelemetryLayer<S, SdkTracer>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+{
+    tracing_opentelemetry::layer().with_tracer(provider.tracer(env!("CARGO_PKG_NAME")))
+}
+
+/// W3C `traceparent` of the current span, `None` when spans are not exported
+pub fn current_traceparent() -> Option<String> {
+    let context = tracing::Span::current().context();
+    if !context.span().span_context().is_valid() {
+        return None;
+    }
+    let mut carrier = HashMap::new();
+    TraceContextPropagator::new().inject_context(&context, &mut carrier);
+    carrier.remove("traceparent")
+}
+
+/// [`TRACEPARENT_ENV`] for a Job container, so that spans of the run join
+/// the trace of the reconcile creating it
+pub fn traceparent_env_var() -> Option<EnvVar> {
+    current_traceparent().map(|value| EnvVar {
+        name: TRACEPARENT_ENV.to_string(),
+        value: Some(value),
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[test]
+    fn no_trace_context_without_an_exporter() {
+        let _span = tracing::info_span!("reconcile").entered();
+        assert_eq!(traceparent_env_var(), None);
+    }
+
+    #[test]
+    fn jobs_inherit_the_current_span() {
+        let provider = SdkTracerProvider::builder().build();
+        let subscriber = tracing_subscriber::registry().with(layer(&provider));
+
+        let (trace_id, env) = tracing::subscriber::with_default(subscriber, || {
+            let _reconcile = tracing::info_span!("reconcile").entered();
+            let create_job = tracing::info_span!("create_job");
+            let _entered = create_job.enter();
+            let trace_id = create_job.context().span().span_context().trace_id();
+            (trace_id, traceparent_env_var().unwrap())
+        });
+
+        assert_eq!(env.name, TRACEPARENT_ENV);
+        let value = env.value.unwrap();
+        let parts: Vec<&str> = value.split('-').collect();
+        assert_eq!(parts.len(), 4, "{value}");
+        assert_eq!(parts[0], "00");
+        assert_eq!(parts[1], trace_id.to_string());
+        assert_eq!(parts[3], "01");
+    }
+}
@@ -0,0 +1,178 @@
+//! Conversion between the served versions of KafkaBackup and KafkaRestore.
+//! `v1beta1` has the fields of `v1alpha1` minus the legacy ones, with enums
+//! in place of strings, so objects are converted as JSON. `v1alpha1` values
+//! that `v1beta1` cannot hold are moved into an annotation and restored on
+//! the way back, so a round trip through either version loses nothing.
+
+use serde::de::DeserializeOwned;
+use serde_json::{json, Map, Value};
+
+use crate::crd::v1beta1;
+use crate::error::{Error, Result};
+
+/// Annotation on `v1beta1` objects holding the `v1alpha1` values `v1beta1`
+/// cannot represent, as a JSON object keyed by JSON pointer
+pub const PRESERVED_FIELDS_ANNOTATION: &str = "kafkabackup.com/v1alpha1-fields";
+
+/// Whether a `v1alpha1` value can be represented in `v1beta1`
+type Fits = fn(&Value) -> bool;
+
+fn fits<T: DeserializeOwned>(value: &Value) -> bool {
+    T::deserialize(value).is_ok()
+}
+
+fn never(_: &Value) -> bool {
+    false
+}
+
+/// Fields of a `v1alpha1` kind whose values `v1beta1` may not hold, as JSON
+/// pointers in which `*` matches every array index
+fn v1alpha1_fields(kind: &str) -> &'static [(&'static str, Fits)] {
+    match kind {
+        "KafkaBackup" => &[
+            ("/spec/backup/encryption", never),
+            ("/spec/backup/compression", fits::<v1beta1::Compression>),
+            ("/spec/backup/startOffset", fits::<v1beta1::StartOffset>),
+        ],
+        "KafkaRestore" => &[
+            ("/spec/pointInTime/offsetFromEnd", never),
+            (
+                "/spec/consumerGroups/strategy",
+                fits::<v1beta1::OffsetStrategy>,
+            ),
+            (
+                "/spec/restore/existingTopicPolicy",
+                fits::<v1beta1::ExistingTopicPolicy>,
+            ),
+            (
+                "/spec/restore/repartitioning/*/strategy",
+                fits::<v1beta1::PartitioningStrategy>,
+            ),
+        ],
+        _ => &[],
+    }
+}
+
+/// Convert a KafkaBackup or KafkaRestore to `desired_api_version`
+pub fn convert(mut object: Value, desired_api_version: &str) -> Result<Value> {
+    let kind = object["kind"].as_str().unwrap_or_default().to_string();
+    let api_version = object["apiVersion"]
+        .as_str()
+        .unwrap_or_default()
+        .to_string();
+    let version = |api_version: &str| {
+        api_version
+            .strip_prefix("kafkabackup.com/")
+            .map(str::to_string)
+    };
+    match (
+        version(&api_version).as_deref(),
+        version(desired_api_version).as_deref(),
+    ) {
+        (Some(from), Some(to)) if from == to => return Ok(object),
+        (Some("v1alpha1"), Some("v1beta1")) => to_v1beta1(&mut object, &kind),
+        (Some("v1beta1"), Some("v1alpha1")) => to_v1alpha1(&mut object)?,
+        _ => {
+            return Err(Error::InvalidConfig(format!(
+                "cannot convert {kind} from {api_version} to {desired_api_version}"
+            )))
+        }
+    }
+    object["apiVersion"] = desired_api_version.into();
+    Ok(object)
+}
+
+fn to_v1beta1(object: &mut Value, kind: &str) {
+    let mut preserved = Map::new();
+    for (pattern, fits) in v1alpha1_fields(kind) {
+        for pointer in expand(object, pattern) {
+            if object.pointer(&pointer).is_some_and(|value| !fits(value)) {
+                if let Some(value) = remove(object, &pointer) {
+                    preserved.insert(pointer, value);
+                }
+            }
+        }
+    }
+    let preserved = (!preserved.is_empty()).then(|| Value::Object(preserved).to_string());
+    set_annotation(object, preserved);
+}
+
+fn to_v1alpha1(object: &mut Value) -> Result<()> {
+    let Some(preserved) = object
+        .pointer(&format!(
+            "/metadata/annotations/{}",
+            PRESERVED_FIELDS_ANNOTATION.replace('/', "~1")
+        ))
+        .and_then(Value::as_str)
+    else {
+        return Ok(());
+    };
+    let preserved: Map<String, Value> = serde_json::from_str(preserved)?;
+    set_annotation(object, None);
+    for (pointer, value) in preserved {
+        let Some((parent, key)) = pointer.rsplit_once('/') else {
+            continue;
+        };
+        // A value set in v1beta1 since takes precedence, and a block removed
+        // in v1beta1 stays removed
+        if let Some(parent) = object.pointer_mut(parent).and_then(Value::as_object_mut) {
+            parent.entry(key).or_insert(value);
+        }
+    }
+    Ok(())
+}
+
+/// Pointers in `object` matching `pattern`, expanding `*` to each index of
+/// the array at that position
+fn expand(object: &Value, pattern: &str) -> Vec<String> {
+    let mut pointers = vec![String::new()];
+    for token in pattern.split('/').skip(1) {
+        pointers = pointers
+            .into_iter()
+            .flat_map(|pointer| {
+                if token == "*" {
+                    let len = object
+                        .pointer(&pointer)
+                        .and_then(Value::as_array)
+                        .map_or(0, Vec::len);
+                    (0..len).map(|i| format!("{pointer}/{i}")).collect()
+                } else {
+                    vec![format!("{pointer}/{token}")]
+                }
+            })
+            .collect();
+    }
+    pointers
+}
+
+fn remove(object: &mut Value, pointer: &str) -> Option<Value> {
+    let (parent, key) = pointer.rsplit_once('/')?;
+    object.pointer_mut(parent)?.as_object_mut()?.remove(key)
+}
+
+/// Set or remove the preserved fields annotation, dropping an emptied
+/// annotations map
+fn set_annotation(object: &mut Value, value: Option<String>) {
+    let Some(metadata) = object.get_mut("metadata").and_then(Value::as_object_mut) else {
+        return;
+    };
+    match value {
+        Some(value) => {
+            let annotations = metadata.entry("annotations").or_insert_with(|| json!({}));
+            if let Some(annotations) = annotations.as_object_mut() {
+                annotations.insert(PRESERVED_FIELDS_ANNOTATION.to_string(), value.into());
+            }
+        }
+        None => {
+            if let Some(annotations) = metadata
+                .get_mut("annotations")
+                .and_then(Value::as_object_mut)
+            {
+                annotations.remove(PRESERVED_FIELDS_ANNOTATION);
+                if annotations.is_empty() {
+                    metadata.remove("annotations");
+                }
+            }
+        }
+    }
+}
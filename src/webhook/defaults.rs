@@ -0,0 +1,45 @@
+use crate::config;
+use crate::crd::common::MetricsSpec;
+use crate::crd::kafka_backup::KafkaBackupSpec;
+use crate::crd::kafka_restore::KafkaRestoreSpec;
+use crate::jobs::templates::DEFAULT_METRICS_PORT;
+use crate::reconcilers::{
+    DEFAULT_BACKUP_BACKOFF_LIMIT, DEFAULT_JOBS_HISTORY_LIMIT, DEFAULT_RESTORE_BACKOFF_LIMIT,
+};
+
+/// Fill the unset fields of a KafkaBackup spec that the operator would
+/// otherwise default when building its Jobs and CronJob
+pub fn default_backup(spec: &mut KafkaBackupSpec) {
+    spec.image
+        .get_or_insert_with(|| config::current().jobs.image.clone());
+    spec.backoff_limit
+        .get_or_insert(DEFAULT_BACKUP_BACKOFF_LIMIT);
+    default_metrics(&mut spec.metrics);
+    if let Some(schedule) = &mut spec.schedule {
+        schedule
+            .successful_jobs_history_limit
+            .get_or_insert(DEFAULT_JOBS_HISTORY_LIMIT);
+        schedule
+            .failed_jobs_history_limit
+            .get_or_insert(DEFAULT_JOBS_HISTORY_LIMIT);
+    }
+}
+
+/// Fill the unset fields of a KafkaRestore spec that the operator would
+/// otherwise default when building its Job
+pub fn default_restore(spec: &mut KafkaRestoreSpec) {
+    spec.image
+        .get_or_insert_with(|| config::current().jobs.image.clone());
+    spec.backoff_limit
+        .get_or_insert(DEFAULT_RESTORE_BACKOFF_LIMIT);
+    default_metrics(&mut spec.metrics);
+}
+
+/// The job metrics server is enabled on its default port unless configured
+fn default_metrics(metrics: &mut Option<MetricsSpec>) {
+    let metrics = metrics.get_or_insert_with(MetricsSpec::default);
+    metrics.enabled.get_or_insert(true);
+    if metrics.enabled == Some(true) {
+        metrics.port.get_or_insert(DEFAULT_METRICS_PORT);
+    }
+}
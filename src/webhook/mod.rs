@@ -0,0 +1,175 @@
+//! Admission webhooks for KafkaBackup and KafkaRestore. The mutating webhook
+//! writes the defaults the operator would apply into the spec, so the stored
+//! resource shows what its Jobs run with. The validating webhook runs the
+//! reconcilers' configuration checks, so `kubectl apply` rejects a spec that
+//! would only fail at reconcile time. The conversion webhook translates
+//! between their served API versions.
+
+pub mod conversion;
+pub mod defaults;
+pub mod tls;
+pub mod validation;
+
+use axum::routing::post;
+use axum::{Json, Router};
+use kube::core::admission::{AdmissionRequest, AdmissionResponse, AdmissionReview};
+use kube::core::conversion::{ConversionRequest, ConversionResponse, ConversionReview};
+use kube::core::{DynamicObject, Status};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tracing::{debug, info, warn};
+
+use crate::crd::{KafkaBackup, KafkaRestore};
+use crate::error::Result;
+
+/// Path of the validating webhook
+pub const VALIDATE_PATH: &str = "/validate";
+
+/// Path of the defaulting (mutating) webhook
+pub const MUTATE_PATH: &str = "/mutate";
+
+/// Path of the CRD conversion webhook
+pub const CONVERT_PATH: &str = "/convert";
+
+/// Routes served on the webhook port
+pub fn router() -> Router {
+    Router::new()
+        .route(
+            VALIDATE_PATH,
+            post(
+                |Json(request): Json<AdmissionReview<DynamicObject>>| async move {
+                    Json(review(request))
+                },
+            ),
+        )
+        .route(
+            MUTATE_PATH,
+            post(
+                |Json(request): Json<AdmissionReview<DynamicObject>>| async move {
+                    Json(mutate(request))
+                },
+            ),
+        )
+        .route(
+            CONVERT_PATH,
+            post(|Json(request): Json<ConversionReview>| async move { Json(convert(request)) }),
+        )
+}
+
+/// Answer a ConversionReview by converting each KafkaBackup or KafkaRestore
+/// to the desired API version. The review fails if any object cannot be
+/// converted.
+pub fn convert(review: ConversionReview) -> ConversionReview {
+    let request = match ConversionRequest::try_from(review) {
+        Ok(request) => request,
+        Err(e) => {
+            return ConversionResponse::invalid(Status::failure(&e.to_string(), "InvalidRequest"))
+                .into_review()
+        }
+    };
+    let desired_api_version = request.desired_api_version.clone();
+    let converted = request
+        .objects
+        .iter()
+        .cloned()
+        .map(|object| conversion::convert(object, &desired_api_version))
+        .collect::<Result<Vec<_>>>();
+    let response = ConversionResponse::for_request(request);
+    match converted {
+        Ok(objects) => {
+            debug!(%desired_api_version, objects = objects.len(), "Converted");
+            response.success(objects).into_review()
+        }
+        Err(e) => {
+            warn!(%desired_api_version, error = %e, "Conversion failed");
+            response
+                .failure(Status::failure(&e.to_string(), "ConversionFailed"))
+                .into_review()
+        }
+    }
+}
+
+/// Answer an AdmissionReview for a KafkaBackup or KafkaRestore with a JSON
+/// patch that sets the effective defaults of unset fields. Objects of other
+/// kinds, and requests without an object, are admitted unchanged.
+pub fn mutate(review: AdmissionReview<DynamicObject>) -> AdmissionReview<DynamicObject> {
+    let request: AdmissionRequest<DynamicObject> = match review.try_into() {
+        Ok(request) => request,
+        Err(e) => return AdmissionResponse::invalid(e.to_string()).into_review(),
+    };
+    let response = AdmissionResponse::from(&request);
+    let Some(object) = &request.object else {
+        return response.into_review();
+    };
+
+    let patch = match request.kind.kind.as_str() {
+        "KafkaBackup" => {
+            defaults_patch::<KafkaBackup>(object, |b| defaults::default_backup(&mut b.spec))
+        }
+        // The spec of a started restore is immutable
+        "KafkaRestore" => defaults_patch::<KafkaRestore>(object, |r| {
+            if !r.has_started() {
+                defaults::default_restore(&mut r.spec)
+            }
+        }),
+        _ => Ok(json_patch::Patch(Vec::new())),
+    };
+    let patch = match patch {
+        Ok(patch) => patch,
+        Err(e) => return response.deny(e.to_string()).into_review(),
+    };
+    if patch.0.is_empty() {
+        return response.into_review();
+    }
+    debug!(kind = %request.kind.kind, name = %request.name, fields = patch.0.len(), "Defaulted");
+    match response.with_patch(patch) {
+        Ok(response) => response.into_review(),
+        Err(e) => AdmissionResponse::invalid(e.to_string()).into_review(),
+    }
+}
+
+/// Answer an AdmissionReview for a KafkaBackup or KafkaRestore. Objects of
+/// other kinds, and requests without an object such as deletes, are allowed.
+pub fn review(review: AdmissionReview<DynamicObject>) -> AdmissionReview<DynamicObject> {
+    let request: AdmissionRequest<DynamicObject> = match review.try_into() {
+        Ok(request) => request,
+        Err(e) => return AdmissionResponse::invalid(e.to_string()).into_review(),
+    };
+    let response = AdmissionResponse::from(&request);
+    let Some(object) = &request.object else {
+        return response.into_review();
+    };
+
+    let result = match request.kind.kind.as_str() {
+        "KafkaBackup" => typed::<KafkaBackup>(object).and_then(|b| validation::validate_backup(&b)),
+        "KafkaRestore" => {
+            typed::<KafkaRestore>(object).and_then(|r| validation::validate_restore(&r))
+        }
+        _ => Ok(()),
+    };
+    match result {
+        Ok(()) => {
+            debug!(kind = %request.kind.kind, name = %request.name, "Admitted");
+            response.into_review()
+        }
+        Err(e) => {
+            info!(kind = %request.kind.kind, name = %request.name, namespace = ?request.namespace, error = %e, "Rejected");
+            response.deny(e.to_string()).into_review()
+        }
+    }
+}
+
+/// JSON patch from `object` to `object` with `apply_defaults` applied
+fn defaults_patch<K: DeserializeOwned + Serialize>(
+    object: &DynamicObject,
+    apply_defaults: impl FnOnce(&mut K),
+) -> Result<json_patch::Patch> {
+    let mut resource: K = typed(object)?;
+    let before = serde_json::to_value(&resource)?;
+    apply_defaults(&mut resource);
+    Ok(json_patch::diff(&before, &serde_json::to_value(&resource)?))
+}
+
+fn typed<K: DeserializeOwned>(object: &DynamicObject) -> Result<K> {
+    Ok(serde_json::from_value(serde_json::to_value(object)?)?)
+}
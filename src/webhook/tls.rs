@@ -0,0 +1,137 @@
+use std::io;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinSet;
+use tokio_rustls::rustls::ServerConfig;
+use tokio_rustls::server::TlsStream;
+use tokio_rustls::TlsAcceptor;
+use tracing::{debug, info, warn};
+
+/// Certificate file in the webhook certificate directory
+pub const CERT_FILE: &str = "tls.crt";
+/// Private key file in the webhook certificate directory
+pub const KEY_FILE: &str = "tls.key";
+
+/// Time allowed for a client to complete the TLS handshake
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// TCP listener that terminates TLS with the certificate and key in a
+/// directory. The files are reloaded when the certificate changes, so a
+/// rotated Secret mounted as a volume is picked up without a restart.
+///
+/// Handshakes run in their own tasks, so a slow or idle client does not hold
+/// up the connections accepted after it.
+pub struct TlsListener {
+    listener: TcpListener,
+    cert_dir: PathBuf,
+    acceptor: TlsAcceptor,
+    loaded: Option<SystemTime>,
+    handshakes: JoinSet<Option<(TlsStream<TcpStream>, SocketAddr)>>,
+}
+
+impl TlsListener {
+    /// Bind `addr`, serving the certificate in `cert_dir`
+    pub async fn bind(addr: SocketAddr, cert_dir: &Path) -> io::Result<Self> {
+        let acceptor = load_acceptor(cert_dir)?;
+        Ok(Self {
+            listener: TcpListener::bind(addr).await?,
+            cert_dir: cert_dir.to_path_buf(),
+            acceptor,
+            loaded: modified(cert_dir),
+            handshakes: JoinSet::new(),
+        })
+    }
+
+    fn reload_if_changed(&mut self) {
+        let current = modified(&self.cert_dir);
+        if current == self.loaded {
+            return;
+        }
+        match load_acceptor(&self.cert_dir) {
+            Ok(acceptor) => {
+                info!(cert_dir = %self.cert_dir.display(), "Reloaded webhook certificate");
+                self.acceptor = acceptor;
+                self.loaded = current;
+            }
+            Err(e) => warn!(error = %e, "Failed to reload webhook certificate"),
+        }
+    }
+}
+
+impl axum::serve::Listener for TlsListener {
+    type Io = TlsStream<TcpStream>;
+    type Addr = SocketAddr;
+
+    async fn accept(&mut self) -> (Self::Io, Self::Addr) {
+        loop {
+            tokio::select! {
+                (stream, addr) = axum::serve::Listener::accept(&mut self.listener) => {
+                    self.reload_if_changed();
+                    self.handshakes.spawn(handshake(self.acceptor.clone(), stream, addr));
+                }
+                Some(handshake) = self.handshakes.join_next() => {
+                    if let Ok(Some(accepted)) = handshake {
+                        return accepted;
+                    }
+                }
+            }
+        }
+    }
+
+    fn local_addr(&self) -> io::Result<Self::Addr> {
+        self.listener.local_addr()
+    }
+}
+
+/// Complete the TLS handshake of a client connected from `addr`, `None` when
+/// it fails or the client does not finish it within [`HANDSHAKE_TIMEOUT`]
+async fn handshake(
+    acceptor: TlsAcceptor,
+    stream: TcpStream,
+    addr: SocketAddr,
+) -> Option<(TlsStream<TcpStream>, SocketAddr)> {
+    match tokio::time::timeout(HANDSHAKE_TIMEOUT, acceptor.accept(stream)).await {
+        Ok(Ok(stream)) => Some((stream, addr)),
+        Ok(Err(e)) => {
+            debug!(%addr, error = %e, "TLS handshake failed");
+            None
+        }
+        Err(_) => {
+            debug!(%addr, "TLS handshake timed out");
+            None
+        }
+    }
+}
+
+/// Modification time of the certificate in `cert_dir`
+fn modified(cert_dir: &Path) -> Option<SystemTime> {
+    std::fs::metadata(cert_dir.join(CERT_FILE))
+        .and_then(|m| m.modified())
+        .ok()
+}
+
+fn load_acceptor(cert_dir: &Path) -> io::Result<TlsAcceptor> {
+    let certs = rustls_pemfile::certs(&mut io::BufReader::new(std::fs::File::open(
+        cert_dir.join(CERT_FILE),
+    )?))
+    .collect::<io::Result<Vec<_>>>()?;
+    let key = rustls_pemfile::private_key(&mut io::BufReader::new(std::fs::File::open(
+        cert_dir.join(KEY_FILE),
+    )?))?
+    .ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("no private key in {}", cert_dir.join(KEY_FILE).display()),
+        )
+    })?;
+
+    let config = ServerConfig::builder()
+        .with_no_client_auth()
+        .with_single_cert(certs, key)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    Ok(TlsAcceptor::from(Arc::new(config)))
+}
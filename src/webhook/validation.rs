@@ -0,0 +1,70 @@
+use chrono::Utc;
+
+use crate::adapters::backup_config::build_backup_options;
+use crate::adapters::restore_config::build_restore_options;
+use crate::adapters::storage_config::build_storage_config;
+use crate::adapters::storage_location::check_storage_source;
+use crate::crd::common::AuthenticationSpec;
+use crate::crd::{KafkaBackup, KafkaRestore};
+use crate::error::{Error, Result};
+use crate::jobs::schedule::next_run;
+use crate::reconcilers::restore::direct_backup_id;
+use crate::strimzi::kafka_user::check_authentication;
+
+/// Check a KafkaBackup for the configuration errors the backup reconciler
+/// would report, without reading anything from the cluster
+pub fn validate_backup(backup: &KafkaBackup) -> Result<()> {
+    let spec = &backup.spec;
+    check_storage_source(spec.storage.as_ref(), spec.storage_ref.as_ref())?;
+    if let Some(storage) = &spec.storage {
+        build_storage_config(storage)?;
+    }
+    validate_authentication(spec.authentication.as_ref())?;
+    if let Some(topics) = &spec.topics {
+        topics.matches("")?;
+    }
+    if let Some(opts) = &spec.backup {
+        build_backup_options(opts)?;
+    }
+    if let Some(retention) = &spec.retention {
+        if retention.max_backups.is_none() && retention.max_age.is_none() {
+            return Err(Error::InvalidConfig(
+                "retention requires at least one of maxBackups or maxAge".to_string(),
+            ));
+        }
+    }
+    if let Some(schedule) = &spec.schedule {
+        next_run(schedule, Utc::now())?;
+    }
+    Ok(())
+}
+
+/// Check a KafkaRestore for the configuration errors the restore reconciler
+/// would report, without reading anything from the cluster
+pub fn validate_restore(restore: &KafkaRestore) -> Result<()> {
+    let spec = &restore.spec;
+    if direct_backup_id(&spec.backup_ref)?.is_some() {
+        check_storage_source(
+            spec.backup_ref.storage.as_ref(),
+            spec.backup_ref.storage_ref.as_ref(),
+        )?;
+        if let Some(storage) = &spec.backup_ref.storage {
+            build_storage_config(storage)?;
+        }
+    } else if spec.backup_ref.name.is_none() {
+        return Err(Error::InvalidConfig(
+            "backupRef.name is required unless backupRef.storage or backupRef.storageRef is set"
+                .to_string(),
+        ));
+    }
+    validate_authentication(spec.authentication.as_ref())?;
+    if let Some(topics) = &spec.topics {
+        topics.matches("")?;
+    }
+    build_restore_options(restore)?;
+    Ok(())
+}
+
+fn validate_authentication(auth: Option<&AuthenticationSpec>) -> Result<()> {
+    auth.map_or(Ok(()), check_authentication)
+}
@@ -23,9 +23,12 @@ fn sample_backup() -> KafkaBackup {
         }),
         connection: None,
         consumer_groups: None,
+        users: None,
+        connect: None,
+        schema_registry: None,
         logging: None,
         env: Vec::new(),
-        storage: StorageSpec {
+        storage: Some(StorageSpec {
             storage_type: StorageType::S3,
             s3: Some(S3StorageSpec {
                 bucket: "my-kafka-backups".to_string(),
@@ -44,7 +47,8 @@ fn sample_backup() -> KafkaBackup {
             azure: None,
             gcs: None,
             filesystem: None,
-        },
+        }),
+        storage_ref: None,
         backup: Some(BackupOptionsSpec {
             compression: Some("zstd".to_string()),
             compression_level: None,
@@ -70,6 +74,8 @@ fn sample_backup() -> KafkaBackup {
             cron: "0 2 * * *".to_string(),
             timezone: Some("UTC".to_string()),
             suspend: false,
+            successful_jobs_history_limit: None,
+            failed_jobs_history_limit: None,
         }),
         retention: Some(RetentionSpec {
             max_backups: Some(30),
@@ -549,3 +555,34 @@ fn test_backup_cronjob_custom_backoff_limit() {
         .unwrap();
     assert_eq!(job_spec.backoff_limit, Some(0));
 }
+
+#[test]
+fn test_backup_cronjob_history_limits() {
+    let mut backup = sample_backup();
+    let cronjob = build_backup_cronjob(
+        &backup,
+        "daily-backup-config",
+        &sample_cluster(),
+        &ResolvedAuth::None,
+        None,
+    )
+    .unwrap();
+    let spec = cronjob.spec.unwrap();
+    assert_eq!(spec.successful_jobs_history_limit, Some(3));
+    assert_eq!(spec.failed_jobs_history_limit, Some(3));
+
+    let schedule = backup.spec.schedule.as_mut().unwrap();
+    schedule.successful_jobs_history_limit = Some(1);
+    schedule.failed_jobs_history_limit = Some(5);
+    let cronjob = build_backup_cronjob(
+        &backup,
+        "daily-backup-config",
+        &sample_cluster(),
+        &ResolvedAuth::None,
+        None,
+    )
+    .unwrap();
+    let spec = cronjob.spec.unwrap();
+    assert_eq!(spec.successful_jobs_history_limit, Some(1));
+    assert_eq!(spec.failed_jobs_history_limit, Some(5));
+}
@@ -0,0 +1,194 @@
+use kafka_backup_operator::crd::v1beta1;
+use kafka_backup_operator::webhook::conversion::{convert, PRESERVED_FIELDS_ANNOTATION};
+use kafka_backup_operator::webhook::convert as convert_review;
+use kube::core::conversion::ConversionReview;
+use serde_json::{json, Value};
+
+const V1ALPHA1: &str = "kafkabackup.com/v1alpha1";
+const V1BETA1: &str = "kafkabackup.com/v1beta1";
+
+fn backup(backup_options: Value) -> Value {
+    json!({
+        "apiVersion": V1ALPHA1,
+        "kind": "KafkaBackup",
+        "metadata": {"name": "orders-backup", "namespace": "kafka"},
+        "spec": {
+            "strimziClusterRef": {"name": "production-cluster"},
+            "storage": {"type": "s3", "s3": {"bucket": "kafka-backups"}},
+            "backup": backup_options
+        },
+        "status": {"observedGeneration": 2}
+    })
+}
+
+fn restore(spec: Value) -> Value {
+    let mut restore = json!({
+        "apiVersion": V1ALPHA1,
+        "kind": "KafkaRestore",
+        "metadata": {
+            "name": "orders-restore",
+            "namespace": "kafka",
+            "annotations": {"team": "payments"}
+        },
+        "spec": {
+            "strimziClusterRef": {"name": "production-cluster"},
+            "backupRef": {"name": "orders-backup"}
+        }
+    });
+    for (key, value) in spec.as_object().unwrap() {
+        restore["spec"][key] = value.clone();
+    }
+    restore
+}
+
+fn annotation(object: &Value) -> Option<Value> {
+    object["metadata"]["annotations"][PRESERVED_FIELDS_ANNOTATION]
+        .as_str()
+        .map(|a| serde_json::from_str(a).unwrap())
+}
+
+#[test]
+fn test_backup_converts_to_typed_v1beta1_and_back() {
+    let original = backup(json!({"compression": "zstd", "startOffset": "earliest"}));
+
+    let converted = convert(original.clone(), V1BETA1).unwrap();
+    assert_eq!(converted["apiVersion"], V1BETA1);
+    assert!(converted["metadata"].get("annotations").is_none());
+    let typed: v1beta1::KafkaBackup = serde_json::from_value(converted.clone()).unwrap();
+    let options = typed.spec.backup.unwrap();
+    assert_eq!(options.compression, Some(v1beta1::Compression::Zstd));
+    assert_eq!(options.start_offset, Some(v1beta1::StartOffset::Earliest));
+    assert_eq!(typed.status.unwrap().observed_generation, Some(2));
+
+    assert_eq!(convert(converted, V1ALPHA1).unwrap(), original);
+}
+
+#[test]
+fn test_backup_legacy_fields_survive_a_round_trip() {
+    let original = backup(json!({
+        "compression": "snappy",
+        "encryption": {"enabled": true},
+        "parallelism": 8
+    }));
+
+    let converted = convert(original.clone(), V1BETA1).unwrap();
+    assert_eq!(converted["spec"]["backup"], json!({"parallelism": 8}));
+    assert_eq!(
+        annotation(&converted),
+        Some(json!({
+            "/spec/backup/compression": "snappy",
+            "/spec/backup/encryption": {"enabled": true}
+        }))
+    );
+    serde_json::from_value::<v1beta1::KafkaBackup>(converted.clone()).unwrap();
+
+    assert_eq!(convert(converted, V1ALPHA1).unwrap(), original);
+}
+
+#[test]
+fn test_restore_legacy_fields_survive_a_round_trip() {
+    let original = restore(json!({
+        "pointInTime": {"timestamp": "2026-02-13T02:00:00Z", "offsetFromEnd": "1h"},
+        "consumerGroups": {"restore": true, "groups": ["billing"], "strategy": "header-based"},
+        "restore": {
+            "existingTopicPolicy": "fail",
+            "repartitioning": [
+                {"topic": "orders", "strategy": "murmur2", "targetPartitions": 12},
+                {"topic": "payments", "strategy": "round-robin", "targetPartitions": 6}
+            ]
+        }
+    }));
+
+    let converted = convert(original.clone(), V1BETA1).unwrap();
+    assert_eq!(
+        annotation(&converted),
+        Some(json!({
+            "/spec/pointInTime/offsetFromEnd": "1h",
+            "/spec/restore/existingTopicPolicy": "fail",
+            "/spec/restore/repartitioning/1/strategy": "round-robin"
+        }))
+    );
+    assert_eq!(converted["metadata"]["annotations"]["team"], "payments");
+    let typed: v1beta1::KafkaRestore = serde_json::from_value(converted.clone()).unwrap();
+    assert_eq!(
+        typed.spec.consumer_groups.unwrap().strategy,
+        Some(v1beta1::OffsetStrategy::HeaderBased)
+    );
+    let options = typed.spec.restore.unwrap();
+    assert_eq!(options.existing_topic_policy, None);
+    assert_eq!(
+        options.repartitioning[0].strategy,
+        Some(v1beta1::PartitioningStrategy::Murmur2)
+    );
+
+    assert_eq!(convert(converted, V1ALPHA1).unwrap(), original);
+}
+
+#[test]
+fn test_v1beta1_values_take_precedence_over_preserved_ones() {
+    let mut converted = convert(
+        backup(json!({"compression": "snappy", "encryption": {"enabled": true}})),
+        V1BETA1,
+    )
+    .unwrap();
+    // Edited through v1beta1: compression set, encryption can't be removed
+    // through a version that doesn't have it
+    converted["spec"]["backup"]["compression"] = json!("lz4");
+
+    let downgraded = convert(converted, V1ALPHA1).unwrap();
+    assert_eq!(
+        downgraded["spec"]["backup"],
+        json!({"compression": "lz4", "encryption": {"enabled": true}})
+    );
+    assert!(downgraded["metadata"].get("annotations").is_none());
+}
+
+#[test]
+fn test_preserved_fields_of_a_removed_block_are_dropped() {
+    let mut converted = convert(backup(json!({"encryption": {"enabled": true}})), V1BETA1).unwrap();
+    converted["spec"].as_object_mut().unwrap().remove("backup");
+
+    let downgraded = convert(converted, V1ALPHA1).unwrap();
+    assert!(downgraded["spec"].get("backup").is_none());
+}
+
+#[test]
+fn test_conversion_review_converts_every_object() {
+    let review: ConversionReview = serde_json::from_value(json!({
+        "apiVersion": "apiextensions.k8s.io/v1",
+        "kind": "ConversionReview",
+        "request": {
+            "uid": "0ea4a1f4-7c2e-4d0b-9a4e-6a8c3d1e2f00",
+            "desiredAPIVersion": V1BETA1,
+            "objects": [backup(json!({"compression": "lz4"})), restore(json!({}))]
+        }
+    }))
+    .unwrap();
+
+    let response = serde_json::to_value(convert_review(review)).unwrap()["response"].clone();
+    assert_eq!(response["uid"], "0ea4a1f4-7c2e-4d0b-9a4e-6a8c3d1e2f00");
+    assert_eq!(response["result"]["status"], "Success");
+    let objects = response["convertedObjects"].as_array().unwrap();
+    assert_eq!(objects.len(), 2);
+    assert!(objects.iter().all(|o| o["apiVersion"] == V1BETA1));
+}
+
+#[test]
+fn test_conversion_review_fails_for_an_unknown_version() {
+    let review: ConversionReview = serde_json::from_value(json!({
+        "apiVersion": "apiextensions.k8s.io/v1",
+        "kind": "ConversionReview",
+        "request": {
+            "uid": "0ea4a1f4-7c2e-4d0b-9a4e-6a8c3d1e2f01",
+            "desiredAPIVersion": "kafkabackup.com/v1",
+            "objects": [backup(json!({}))]
+        }
+    }))
+    .unwrap();
+
+    let response = serde_json::to_value(convert_review(review)).unwrap()["response"].clone();
+    assert_eq!(response["result"]["status"], "Failure");
+    assert!(response["result"]["message"].as_str().unwrap().contains(
+        "cannot convert KafkaBackup from kafkabackup.com/v1alpha1 to kafkabackup.com/v1"
+    ));
+}
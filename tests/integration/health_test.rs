@@ -0,0 +1,43 @@
+use http::{Request, Response};
+use kafka_backup_operator::controllers::health::check_api;
+use kube::client::Body;
+use kube::Client;
+use serde_json::json;
+use tower_test::mock;
+
+/// A mock API server serving `groups` from `/apis`
+fn api_server(groups: &[&str]) -> Client {
+    let (mock_service, mut handle) = mock::pair::<Request<Body>, Response<Body>>();
+    let body = json!({
+        "kind": "APIGroupList",
+        "apiVersion": "v1",
+        "groups": groups.iter().map(|name| json!({
+            "name": name,
+            "versions": [{"groupVersion": format!("{name}/v1"), "version": "v1"}]
+        })).collect::<Vec<_>>()
+    });
+    tokio::spawn(async move {
+        while let Some((request, send)) = handle.next_request().await {
+            assert_eq!(request.uri().path(), "/apis");
+            let response = Response::builder()
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::to_vec(&body).unwrap()))
+                .unwrap();
+            send.send_response(response);
+        }
+    });
+    Client::new(mock_service, "default")
+}
+
+#[tokio::test]
+async fn test_api_check_passes_when_strimzi_is_served() {
+    let client = api_server(&["apps", "kafka.strimzi.io", "kafkabackup.com"]);
+    assert_eq!(check_api(&client).await, Ok(()));
+}
+
+#[tokio::test]
+async fn test_api_check_fails_without_the_strimzi_crds() {
+    let client = api_server(&["apps", "kafkabackup.com"]);
+    let error = check_api(&client).await.unwrap_err();
+    assert!(error.contains("kafka.strimzi.io"), "{error}");
+}
@@ -0,0 +1,120 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use http::{Request, Response};
+use http_body_util::BodyExt;
+use kafka_backup_operator::controllers::leader::{LeaderElectionConfig, LeaderElector};
+use kube::client::Body;
+use kube::Client;
+use serde_json::json;
+use tower_test::mock;
+
+/// A mock API server holding a single Lease, with optimistic concurrency on
+/// its resourceVersion like the real one
+fn lease_server() -> (Client, Arc<Mutex<Option<serde_json::Value>>>) {
+    let (mock_service, mut handle) = mock::pair::<Request<Body>, Response<Body>>();
+    let stored: Arc<Mutex<Option<serde_json::Value>>> = Arc::new(Mutex::new(None));
+
+    let lease = Arc::clone(&stored);
+    tokio::spawn(async move {
+        while let Some((request, send)) = handle.next_request().await {
+            let method = request.method().to_string();
+            let bytes = request.into_body().collect().await.unwrap().to_bytes();
+            let mut lease = lease.lock().unwrap();
+            let version = |l: &serde_json::Value| l["metadata"]["resourceVersion"].clone();
+
+            let (status, body) = match (method.as_str(), lease.as_ref()) {
+                ("GET", Some(current)) => (200, current.clone()),
+                ("GET", None) => (404, status_body(404, "NotFound")),
+                ("POST", Some(_)) => (409, status_body(409, "AlreadyExists")),
+                ("PUT", Some(current))
+                    if version(current) != version(&serde_json::from_slice(&bytes).unwrap()) =>
+                {
+                    (409, status_body(409, "Conflict"))
+                }
+                ("POST" | "PUT", current) => {
+                    let next = current
+                        .and_then(|l| version(l).as_str()?.parse::<u64>().ok())
+                        .unwrap_or(0)
+                        + 1;
+                    let mut written: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+                    written["metadata"]["resourceVersion"] = json!(next.to_string());
+                    *lease = Some(written.clone());
+                    (200, written)
+                }
+                _ => (404, status_body(404, "NotFound")),
+            };
+
+            let response = Response::builder()
+                .status(status)
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::to_vec(&body).unwrap()))
+                .unwrap();
+            send.send_response(response);
+        }
+    });
+
+    (Client::new(mock_service, "kafka-backup-system"), stored)
+}
+
+fn status_body(code: u16, reason: &str) -> serde_json::Value {
+    json!({
+        "kind": "Status", "apiVersion": "v1", "metadata": {},
+        "status": "Failure", "message": reason, "reason": reason, "code": code
+    })
+}
+
+fn elector(client: &Client, identity: &str) -> LeaderElector {
+    LeaderElector::new(
+        client.clone(),
+        LeaderElectionConfig {
+            lease_name: "strimzi-backup-operator".to_string(),
+            namespace: "kafka-backup-system".to_string(),
+            identity: identity.to_string(),
+            lease_duration: Duration::from_secs(15),
+            renew_deadline: Duration::from_secs(10),
+            retry_period: Duration::from_secs(2),
+        },
+    )
+}
+
+#[tokio::test]
+async fn test_only_one_replica_holds_the_lease_until_it_is_released() {
+    let (client, lease) = lease_server();
+    let first = elector(&client, "operator-0");
+    let second = elector(&client, "operator-1");
+
+    assert!(first.try_acquire_or_renew().await.unwrap());
+    assert!(!second.try_acquire_or_renew().await.unwrap());
+    // Renewing keeps the Lease with its holder
+    assert!(first.try_acquire_or_renew().await.unwrap());
+    assert!(!second.try_acquire_or_renew().await.unwrap());
+    assert_eq!(
+        lease.lock().unwrap().as_ref().unwrap()["spec"]["holderIdentity"],
+        json!("operator-0")
+    );
+
+    first.release().await.unwrap();
+    assert!(second.try_acquire_or_renew().await.unwrap());
+    assert!(!first.try_acquire_or_renew().await.unwrap());
+
+    let spec = lease.lock().unwrap().as_ref().unwrap()["spec"].clone();
+    assert_eq!(spec["holderIdentity"], json!("operator-1"));
+    assert_eq!(spec["leaseDurationSeconds"], json!(15));
+    assert_eq!(spec["leaseTransitions"], json!(1));
+}
+
+#[tokio::test]
+async fn test_releasing_a_lease_held_by_another_replica_leaves_it_alone() {
+    let (client, lease) = lease_server();
+    let first = elector(&client, "operator-0");
+    let second = elector(&client, "operator-1");
+
+    assert!(first.try_acquire_or_renew().await.unwrap());
+    second.release().await.unwrap();
+
+    assert_eq!(
+        lease.lock().unwrap().as_ref().unwrap()["spec"]["holderIdentity"],
+        json!("operator-0")
+    );
+}
@@ -1,7 +1,19 @@
 mod backup_test;
 mod cleanup_test;
+mod conversion_test;
+mod health_test;
 mod job_state_test;
+mod leader_test;
 mod reconcile_backup_test;
 mod reconcile_pause_test;
+mod reconcile_restore_test;
 mod restore_test;
+mod schedule_test;
+mod schema_registry_test;
+mod snapshot_test;
+mod storage_location_test;
 mod strimzi_api_test;
+mod telemetry_test;
+mod verification_test;
+mod webhook_test;
+mod webhook_tls_test;
@@ -3,13 +3,16 @@ use std::{
     sync::{Arc, Mutex},
 };
 
+use chrono::Utc;
 use http::{Request, Response};
 use http_body_util::BodyExt;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
 use kafka_backup_operator::crd::common::*;
 use kafka_backup_operator::crd::kafka_backup::*;
 use kafka_backup_operator::crd::KafkaBackup;
 use kafka_backup_operator::metrics::prometheus::MetricsState;
 use kafka_backup_operator::reconcilers::backup::reconcile_backup;
+use kafka_backup_operator::status::events;
 use kube::client::Body;
 use kube::Client;
 use serde_json::json;
@@ -35,9 +38,12 @@ fn scheduled_backup(suspend: bool) -> KafkaBackup {
         topics: None,
         connection: None,
         consumer_groups: None,
+        users: None,
+        connect: None,
+        schema_registry: None,
         logging: None,
         env: Vec::new(),
-        storage: StorageSpec {
+        storage: Some(StorageSpec {
             storage_type: StorageType::Filesystem,
             s3: None,
             azure: None,
@@ -45,7 +51,8 @@ fn scheduled_backup(suspend: bool) -> KafkaBackup {
             filesystem: Some(FilesystemStorageSpec {
                 path: "/backups".to_string(),
             }),
-        },
+        }),
+        storage_ref: None,
         backup: None,
         metrics: None,
         offset_storage: None,
@@ -53,6 +60,8 @@ fn scheduled_backup(suspend: bool) -> KafkaBackup {
             cron: "0 2 * * *".to_string(),
             timezone: None,
             suspend,
+            successful_jobs_history_limit: None,
+            failed_jobs_history_limit: None,
         }),
         retention: None,
         resources: None,
@@ -71,6 +80,26 @@ fn scheduled_backup(suspend: bool) -> KafkaBackup {
 /// Run `reconcile_backup` against a mock API server and record every request
 /// the operator makes.
 async fn reconcile_with_mock_api(backup: KafkaBackup) -> Vec<RecordedRequest> {
+    reconcile_with_mock_objects(backup, Vec::new(), Vec::new()).await
+}
+
+/// Like [`reconcile_with_mock_api`], with the API server also listing `jobs`
+/// for the backup and the Strimzi resources in `strimzi` by kind.
+async fn reconcile_with_mock_objects(
+    backup: KafkaBackup,
+    jobs: Vec<serde_json::Value>,
+    strimzi: Vec<serde_json::Value>,
+) -> Vec<RecordedRequest> {
+    reconcile_with_metrics(backup, jobs, strimzi, &MetricsState::new()).await
+}
+
+/// Like [`reconcile_with_mock_objects`], recording into `metrics`.
+async fn reconcile_with_metrics(
+    backup: KafkaBackup,
+    jobs: Vec<serde_json::Value>,
+    strimzi: Vec<serde_json::Value>,
+    metrics: &MetricsState,
+) -> Vec<RecordedRequest> {
     let (mock_service, mut handle) = mock::pair::<Request<Body>, Response<Body>>();
     let recorded = Arc::new(Mutex::new(Vec::new()));
 
@@ -113,10 +142,28 @@ async fn reconcile_with_mock_api(backup: KafkaBackup) -> Vec<RecordedRequest> {
                 } else if path.ends_with("/jobs") {
                     (
                         200,
-                        json!({"kind": "JobList", "apiVersion": "batch/v1", "metadata": {}, "items": []}),
+                        json!({"kind": "JobList", "apiVersion": "batch/v1", "metadata": {}, "items": jobs}),
+                    )
+                } else if let Some(kind) = strimzi_list_kind(&path) {
+                    let items: Vec<&serde_json::Value> =
+                        strimzi.iter().filter(|r| r["kind"] == kind).collect();
+                    (
+                        200,
+                        json!({"kind": format!("{kind}List"), "apiVersion": "kafka.strimzi.io/v1", "metadata": {}, "items": items}),
+                    )
+                } else if path.ends_with("/kafkabackupsnapshots") {
+                    (
+                        200,
+                        json!({"kind": "KafkaBackupSnapshotList", "apiVersion": "kafkabackup.com/v1alpha1", "metadata": {}, "items": []}),
                     )
                 } else if path.contains("/kafkabackups/") {
                     (200, backup_json.clone())
+                } else if path.contains("/cronjobs/") {
+                    // Server-side apply of the CronJob: echo it back with the
+                    // status of a CronJob that has fired before
+                    let mut cronjob = body.clone();
+                    cronjob["status"] = json!({"lastScheduleTime": "2026-02-13T02:00:00Z"});
+                    (200, cronjob)
                 } else {
                     // Server-side apply of ConfigMap/CronJob: echo the object back
                     (200, body.clone())
@@ -140,10 +187,12 @@ async fn reconcile_with_mock_api(backup: KafkaBackup) -> Vec<RecordedRequest> {
     };
 
     let client = Client::new(mock_service, "kafka");
-    let metrics = MetricsState::new();
-    reconcile_backup(Arc::new(backup), client, &metrics)
+    // The recorder holds a client too: drop it so the mock API shuts down
+    let recorder = events::recorder(client.clone(), &backup);
+    reconcile_backup(Arc::new(backup), client, &recorder, metrics)
         .await
         .expect("reconcile should succeed");
+    drop(recorder);
 
     dispatcher.await.unwrap();
     Arc::try_unwrap(recorded).unwrap().into_inner().unwrap()
@@ -178,6 +227,7 @@ async fn test_reconcile_propagates_suspend_to_cronjob() {
         status.contains_key("nextScheduledBackup") && status["nextScheduledBackup"].is_null(),
         "suspending must clear nextScheduledBackup with an explicit null"
     );
+    assert_eq!(status["lastScheduleTime"], json!("2026-02-13T02:00:00Z"));
 }
 
 #[tokio::test]
@@ -195,6 +245,22 @@ async fn test_reconcile_applies_active_cronjob_when_not_suspended() {
         status_patch.body["status"]["conditions"][0]["reason"],
         json!("BackupScheduled")
     );
+
+    // The next fire time of "0 2 * * *" in UTC, not the cron expression
+    let status = &status_patch.body["status"];
+    let next = status["nextScheduledBackup"].as_str().unwrap();
+    let next: chrono::DateTime<chrono::Utc> = next.parse().expect("an RFC 3339 timestamp");
+    assert!(next > chrono::Utc::now());
+    assert_eq!(next.format("%H:%M:%S").to_string(), "02:00:00");
+    assert!(next - chrono::Utc::now() <= chrono::Duration::days(1));
+    assert_eq!(
+        status["conditions"][0]["message"],
+        json!(format!(
+            "Next backup scheduled at {}",
+            status["nextScheduledBackup"].as_str().unwrap()
+        ))
+    );
+    assert_eq!(status["lastScheduleTime"], json!("2026-02-13T02:00:00Z"));
 }
 
 #[tokio::test]
@@ -228,3 +294,686 @@ async fn test_reconcile_force_applies_resource_updates_to_owned_cronjob() {
         "controller-owned CronJob fields must be force-applied so a stale field manager cannot block spec updates"
     );
 }
+
+/// Kind of the Strimzi resources a list request path is for
+fn strimzi_list_kind(path: &str) -> Option<&'static str> {
+    [
+        ("/kafkatopics", "KafkaTopic"),
+        ("/kafkausers", "KafkaUser"),
+        ("/kafkanodepools", "KafkaNodePool"),
+        ("/kafkaconnects", "KafkaConnect"),
+        ("/kafkaconnectors", "KafkaConnector"),
+    ]
+    .into_iter()
+    .find(|(suffix, _)| path.ends_with(suffix))
+    .map(|(_, kind)| kind)
+}
+
+fn completed_backup_job() -> serde_json::Value {
+    json!({
+        "apiVersion": "batch/v1",
+        "kind": "Job",
+        "metadata": {"name": "daily-backup-20260213-020000", "namespace": "kafka"},
+        "status": {
+            "succeeded": 1,
+            "startTime": "2026-02-13T02:00:00Z",
+            "completionTime": "2026-02-13T02:05:00Z"
+        }
+    })
+}
+
+#[tokio::test]
+async fn test_completed_backup_stores_selected_kafka_topics() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let mut backup = scheduled_backup(false);
+    backup.spec.topics = Some(TopicSelection {
+        include: vec!["orders*".to_string()],
+        exclude: Vec::new(),
+    });
+    backup.spec.storage.as_mut().unwrap().filesystem = Some(FilesystemStorageSpec {
+        path: temp_dir.path().to_string_lossy().into_owned(),
+    });
+
+    let topic = |name: &str, topic_name: &str| {
+        json!({
+            "apiVersion": "kafka.strimzi.io/v1",
+            "kind": "KafkaTopic",
+            "metadata": {
+                "name": name,
+                "namespace": "kafka",
+                "uid": "topic-uid",
+                "resourceVersion": "42",
+                "labels": {"strimzi.io/cluster": "production-cluster"},
+                "annotations": {"kubectl.kubernetes.io/last-applied-configuration": "{}"}
+            },
+            "spec": {
+                "topicName": topic_name,
+                "partitions": 12,
+                "replicas": 3,
+                "config": {"cleanup.policy": "compact"}
+            },
+            "status": {"conditions": [{"type": "Ready", "status": "True"}]}
+        })
+    };
+
+    let requests = reconcile_with_mock_objects(
+        backup,
+        vec![completed_backup_job()],
+        vec![
+            topic("orders-v2", "orders_v2"),
+            topic("payments", "payments"),
+        ],
+    )
+    .await;
+
+    let list = requests
+        .iter()
+        .find(|r| r.method == "GET" && r.path.ends_with("/namespaces/kafka/kafkatopics"))
+        .expect("the backup's KafkaTopics must be listed");
+    assert!(list
+        .query
+        .as_deref()
+        .unwrap()
+        .contains("strimzi.io%2Fcluster%3Dproduction-cluster"));
+
+    let stored = std::fs::read(
+        temp_dir
+            .path()
+            .join("daily-backup-20260213-020000/kafkatopics.json"),
+    )
+    .expect("the KafkaTopic snapshot must be stored next to the manifest");
+    let stored: Vec<serde_json::Value> = serde_json::from_slice(&stored).unwrap();
+    assert_eq!(stored.len(), 1, "topics are selected by spec.topics");
+    assert!(
+        !temp_dir
+            .path()
+            .join("daily-backup-20260213-020000/kafkausers.json")
+            .exists(),
+        "users are only backed up when spec.users is set"
+    );
+    assert_eq!(
+        stored[0],
+        json!({
+            "metadata": {
+                "name": "orders-v2",
+                "labels": {"strimzi.io/cluster": "production-cluster"}
+            },
+            "spec": {
+                "topicName": "orders_v2",
+                "partitions": 12,
+                "replicas": 3,
+                "config": {"cleanup.policy": "compact"}
+            }
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_incremental_backup_stores_kafka_topics_next_to_its_manifest() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let mut backup = scheduled_backup(false);
+    backup.spec.offset_storage = Some(OffsetStorageSpec::default());
+    backup.spec.storage.as_mut().unwrap().filesystem = Some(FilesystemStorageSpec {
+        path: temp_dir.path().to_string_lossy().into_owned(),
+    });
+    let topic = json!({
+        "apiVersion": "kafka.strimzi.io/v1",
+        "kind": "KafkaTopic",
+        "metadata": {
+            "name": "orders",
+            "namespace": "kafka",
+            "labels": {"strimzi.io/cluster": "production-cluster"}
+        },
+        "spec": {"partitions": 3, "replicas": 3}
+    });
+
+    reconcile_with_mock_objects(backup, vec![completed_backup_job()], vec![topic]).await;
+
+    assert!(temp_dir
+        .path()
+        .join("daily-backup/kafkatopics.json")
+        .exists());
+}
+
+#[tokio::test]
+async fn test_completed_backup_stores_kafka_users_without_passwords() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let mut backup = scheduled_backup(false);
+    backup.spec.users = Some(UserSelection {
+        include: Vec::new(),
+        exclude: vec!["admin-*".to_string()],
+    });
+    backup.spec.storage.as_mut().unwrap().filesystem = Some(FilesystemStorageSpec {
+        path: temp_dir.path().to_string_lossy().into_owned(),
+    });
+
+    let user = |name: &str| {
+        json!({
+            "apiVersion": "kafka.strimzi.io/v1",
+            "kind": "KafkaUser",
+            "metadata": {
+                "name": name,
+                "namespace": "kafka",
+                "labels": {"strimzi.io/cluster": "production-cluster"}
+            },
+            "spec": {
+                "authentication": {
+                    "type": "scram-sha-512",
+                    "password": {"valueFrom": {"secretKeyRef": {"name": "app-password", "key": "password"}}}
+                },
+                "authorization": {"type": "simple", "acls": [
+                    {"resource": {"type": "topic", "name": "orders"}, "operations": ["Read"]}
+                ]},
+                "quotas": {"producerByteRate": 1048576}
+            },
+            "status": {"secret": name, "username": name}
+        })
+    };
+
+    reconcile_with_mock_objects(
+        backup,
+        vec![completed_backup_job()],
+        vec![user("admin-ops"), user("orders-app")],
+    )
+    .await;
+
+    let stored = std::fs::read(
+        temp_dir
+            .path()
+            .join("daily-backup-20260213-020000/kafkausers.json"),
+    )
+    .expect("the KafkaUser snapshot must be stored next to the manifest");
+    let stored: Vec<serde_json::Value> = serde_json::from_slice(&stored).unwrap();
+    assert_eq!(stored.len(), 1, "users are selected by spec.users");
+    assert_eq!(stored[0]["metadata"]["name"], json!("orders-app"));
+    let spec = &stored[0]["spec"];
+    assert_eq!(
+        spec["authentication"],
+        json!({"type": "scram-sha-512"}),
+        "credentials are never stored"
+    );
+    assert_eq!(
+        spec["authorization"]["acls"][0]["resource"]["name"],
+        json!("orders")
+    );
+    assert_eq!(spec["quotas"]["producerByteRate"], json!(1048576));
+    assert!(stored[0].get("status").is_none());
+}
+
+#[tokio::test]
+async fn test_completed_backup_stores_kafka_cluster_definition() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let mut backup = scheduled_backup(false);
+    backup.spec.storage.as_mut().unwrap().filesystem = Some(FilesystemStorageSpec {
+        path: temp_dir.path().to_string_lossy().into_owned(),
+    });
+
+    let node_pool = json!({
+        "apiVersion": "kafka.strimzi.io/v1",
+        "kind": "KafkaNodePool",
+        "metadata": {
+            "name": "brokers",
+            "namespace": "kafka",
+            "uid": "pool-uid",
+            "resourceVersion": "7",
+            "generation": 3,
+            "labels": {"strimzi.io/cluster": "production-cluster"}
+        },
+        "spec": {"replicas": 3, "roles": ["broker"], "storage": {"type": "persistent-claim", "size": "100Gi"}},
+        "status": {"replicas": 3, "nodeIds": [0, 1, 2]}
+    });
+
+    reconcile_with_mock_objects(backup, vec![completed_backup_job()], vec![node_pool]).await;
+
+    let stored = std::fs::read(
+        temp_dir
+            .path()
+            .join("daily-backup-20260213-020000/kafkacluster.json"),
+    )
+    .expect("the Kafka cluster snapshot must be stored next to the manifest");
+    let stored: serde_json::Value = serde_json::from_slice(&stored).unwrap();
+    assert_eq!(
+        stored["kafka"]["metadata"],
+        json!({"name": "production-cluster"})
+    );
+    assert_eq!(stored["kafka"]["spec"]["kafka"]["replicas"], json!(3));
+    assert_eq!(
+        stored["nodePools"],
+        json!([{
+            "metadata": {
+                "name": "brokers",
+                "labels": {"strimzi.io/cluster": "production-cluster"}
+            },
+            "spec": {"replicas": 3, "roles": ["broker"], "storage": {"type": "persistent-claim", "size": "100Gi"}}
+        }])
+    );
+}
+
+#[tokio::test]
+async fn test_completed_backup_stores_kafka_connects_and_their_topics() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let mut backup = scheduled_backup(false);
+    backup.spec.storage.as_mut().unwrap().filesystem = Some(FilesystemStorageSpec {
+        path: temp_dir.path().to_string_lossy().into_owned(),
+    });
+    backup.spec.topics = Some(TopicSelection {
+        include: vec!["orders".to_string()],
+        exclude: Vec::new(),
+    });
+    backup.spec.connect = Some(ConnectSelection::default());
+
+    let strimzi = vec![
+        json!({
+            "apiVersion": "kafka.strimzi.io/v1",
+            "kind": "KafkaConnect",
+            "metadata": {
+                "name": "pipelines",
+                "namespace": "kafka",
+                "annotations": {"strimzi.io/use-connector-resources": "true"}
+            },
+            "spec": {
+                "replicas": 2,
+                "bootstrapServers": "production-cluster-kafka-bootstrap.kafka.svc:9093",
+                "config": {"group.id": "pipelines", "offset.storage.topic": "pipelines-offsets"}
+            },
+            "status": {"url": "http://pipelines-connect-api.kafka.svc:8083"}
+        }),
+        json!({
+            "apiVersion": "kafka.strimzi.io/v1",
+            "kind": "KafkaConnect",
+            "metadata": {"name": "analytics", "namespace": "kafka"},
+            "spec": {"bootstrapServers": "analytics-cluster-kafka-bootstrap:9092"}
+        }),
+        json!({
+            "apiVersion": "kafka.strimzi.io/v1",
+            "kind": "KafkaConnector",
+            "metadata": {
+                "name": "orders-sink",
+                "namespace": "kafka",
+                "labels": {"strimzi.io/cluster": "pipelines"}
+            },
+            "spec": {"class": "io.example.OrdersSink", "tasksMax": 2, "config": {"topics": "orders"}},
+            "status": {"connectorStatus": {"connector": {"state": "RUNNING"}}}
+        }),
+    ];
+
+    let requests = reconcile_with_mock_objects(backup, vec![completed_backup_job()], strimzi).await;
+
+    let config_map = requests
+        .iter()
+        .find(|r| r.method == "PATCH" && r.path.ends_with("/configmaps/daily-backup-config"))
+        .expect("reconcile must apply the backup config");
+    let config: serde_yaml::Value =
+        serde_yaml::from_str(config_map.body["data"]["backup.yaml"].as_str().unwrap()).unwrap();
+    let include: Vec<&str> = config["source"]["topics"]["include"]
+        .as_sequence()
+        .unwrap()
+        .iter()
+        .map(|t| t.as_str().unwrap())
+        .collect();
+    assert_eq!(
+        include,
+        vec![
+            "orders",
+            "pipelines-offsets",
+            "connect-cluster-configs",
+            "connect-cluster-status"
+        ]
+    );
+
+    let stored = std::fs::read(
+        temp_dir
+            .path()
+            .join("daily-backup-20260213-020000/kafkaconnects.json"),
+    )
+    .expect("the KafkaConnect snapshot must be stored next to the manifest");
+    let stored: serde_json::Value = serde_json::from_slice(&stored).unwrap();
+    assert_eq!(stored["kafkaCluster"], json!("production-cluster"));
+    let connects = stored["kafkaConnects"].as_array().unwrap();
+    assert_eq!(
+        connects.len(),
+        1,
+        "only Connect clusters of the backed-up cluster are stored"
+    );
+    assert_eq!(
+        connects[0]["kafkaConnect"]["metadata"]["name"],
+        json!("pipelines")
+    );
+    assert_eq!(connects[0]["kafkaConnect"].get("status"), None);
+    assert_eq!(
+        connects[0]["connectors"][0]["spec"]["class"],
+        json!("io.example.OrdersSink")
+    );
+}
+
+#[tokio::test]
+async fn test_completed_backup_status_is_filled_from_the_manifest() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let mut backup = scheduled_backup(false);
+    backup.spec.storage.as_mut().unwrap().filesystem = Some(FilesystemStorageSpec {
+        path: temp_dir.path().to_string_lossy().into_owned(),
+    });
+    let backup_dir = temp_dir.path().join("daily-backup-20260213-020000");
+    std::fs::create_dir_all(&backup_dir).unwrap();
+    let segment = |size: u64, records: u64, start: i64, end: i64| {
+        json!({"compressed_size": size, "record_count": records,
+               "start_timestamp": start, "end_timestamp": end})
+    };
+    std::fs::write(
+        backup_dir.join("manifest.json"),
+        serde_json::to_vec(&json!({
+            "backup_id": "daily-backup-20260213-020000",
+            "created_at": 1_770_948_000_000_i64,
+            "topics": [
+                {"name": "orders", "partitions": [
+                    {"segments": [segment(1024, 10, 1_770_940_800_000, 1_770_944_400_000)]},
+                    {"segments": [segment(2048, 20, 1_770_941_000_000, 1_770_947_999_000)]}
+                ]},
+                {"name": "payments", "partitions": [
+                    {"segments": [segment(512, 5, 1_770_942_000_000, 1_770_946_000_000)]}
+                ]}
+            ]
+        }))
+        .unwrap(),
+    )
+    .unwrap();
+
+    let metrics = MetricsState::new();
+    let requests =
+        reconcile_with_metrics(backup, vec![completed_backup_job()], Vec::new(), &metrics).await;
+
+    let status = &requests
+        .iter()
+        .find(|r| {
+            r.method == "PATCH"
+                && r.path.ends_with("/kafkabackups/daily-backup/status")
+                && r.body["status"].get("lastBackup").is_some()
+        })
+        .expect("a completed backup must be recorded in the status")
+        .body["status"];
+    let last_backup = &status["lastBackup"];
+    assert_eq!(last_backup["sizeBytes"], json!(3584));
+    assert_eq!(last_backup["topicsBackedUp"], json!(2));
+    assert_eq!(last_backup["partitionsBackedUp"], json!(3));
+    assert_eq!(
+        last_backup["oldestTimestamp"],
+        json!("2026-02-13T00:00:00Z")
+    );
+    assert_eq!(
+        last_backup["newestTimestamp"],
+        json!("2026-02-13T01:59:59Z")
+    );
+
+    let entry = &status["backupHistory"][0];
+    assert_eq!(entry["id"], json!("daily-backup-20260213-020000"));
+    assert_eq!(entry["sizeBytes"], json!(3584));
+    assert_eq!(entry["oldestTimestamp"], json!("2026-02-13T00:00:00Z"));
+    assert_eq!(entry["newestTimestamp"], json!("2026-02-13T01:59:59Z"));
+
+    let event = requests
+        .iter()
+        .find(|r| r.method == "POST" && r.path.ends_with("/namespaces/kafka/events"))
+        .expect("a completed backup must be reported as an Event");
+    assert_eq!(event.body["type"], json!("Normal"));
+    assert_eq!(event.body["reason"], json!("BackupCompleted"));
+    assert_eq!(
+        event.body["note"],
+        json!("Backup daily-backup-20260213-020000 completed: 3584 bytes in 2 topics")
+    );
+
+    let labels = r#"{backup_name="daily-backup",cluster="production-cluster"}"#;
+    let output = metrics.gather();
+    assert!(output.contains(&format!("strimzi_backup_bytes_total{labels} 3584")));
+    assert!(output.contains(&format!("strimzi_backup_records_total{labels} 35")));
+    assert!(output.contains(&format!("strimzi_backup_duration_seconds_sum{labels} 300")));
+    assert!(output.contains(&format!(
+        "strimzi_backup_last_success_timestamp{labels} 1770948300"
+    )));
+}
+
+#[tokio::test]
+async fn test_incremental_backup_status_is_filled_from_its_stable_manifest() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let mut backup = scheduled_backup(false);
+    backup.spec.offset_storage = Some(OffsetStorageSpec {
+        backend: Some("sqlite".to_string()),
+        ..Default::default()
+    });
+    backup.spec.storage.as_mut().unwrap().filesystem = Some(FilesystemStorageSpec {
+        path: temp_dir.path().to_string_lossy().into_owned(),
+    });
+    // Incremental runs merge into the manifest named after the KafkaBackup
+    let backup_dir = temp_dir.path().join("daily-backup");
+    std::fs::create_dir_all(&backup_dir).unwrap();
+    std::fs::write(
+        backup_dir.join("manifest.json"),
+        serde_json::to_vec(&json!({
+            "backup_id": "daily-backup",
+            "created_at": 1_770_948_000_000_i64,
+            "topics": [{"name": "orders", "partitions": [{"segments": [
+                {"compressed_size": 4096, "start_timestamp": 1_770_940_800_000_i64, "end_timestamp": 1_770_944_400_000_i64}
+            ]}]}]
+        }))
+        .unwrap(),
+    )
+    .unwrap();
+
+    let requests =
+        reconcile_with_mock_objects(backup, vec![completed_backup_job()], Vec::new()).await;
+
+    let last_backup = &requests
+        .iter()
+        .find(|r| {
+            r.method == "PATCH"
+                && r.path.ends_with("/kafkabackups/daily-backup/status")
+                && r.body["status"].get("lastBackup").is_some()
+        })
+        .expect("a completed backup must be recorded in the status")
+        .body["status"]["lastBackup"];
+    assert_eq!(last_backup["id"], json!("daily-backup-20260213-020000"));
+    assert_eq!(last_backup["sizeBytes"], json!(4096));
+    assert_eq!(last_backup["topicsBackedUp"], json!(1));
+    assert_eq!(
+        last_backup["oldestTimestamp"],
+        json!("2026-02-13T00:00:00Z")
+    );
+}
+
+#[tokio::test]
+async fn test_failed_backup_is_recorded_in_metrics() {
+    let mut job = completed_backup_job();
+    job["status"] = json!({"failed": 4, "startTime": "2026-02-13T02:00:00Z",
+        "conditions": [{"type": "Failed", "status": "True"}]});
+    let mut backup = scheduled_backup(false);
+    backup.metadata.creation_timestamp = Some(Time(Utc::now() - chrono::Duration::hours(1)));
+    let metrics = MetricsState::new();
+
+    reconcile_with_metrics(backup, vec![job], Vec::new(), &metrics).await;
+
+    let labels = ["daily-backup", "production-cluster"];
+    assert!(
+        metrics
+            .backup_last_failure_timestamp
+            .with_label_values(&labels)
+            .get()
+            > 0.0
+    );
+    // No backup has succeeded, so the lag counts from the resource's creation
+    let lag = metrics.backup_lag_seconds.with_label_values(&labels).get();
+    assert!((3600.0..3610.0).contains(&lag), "lag was {lag}");
+    assert!(!metrics
+        .gather()
+        .contains("strimzi_backup_last_success_timestamp{"));
+}
+
+#[tokio::test]
+async fn test_completed_backup_without_a_manifest_is_still_recorded() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let mut backup = scheduled_backup(false);
+    backup.spec.storage.as_mut().unwrap().filesystem = Some(FilesystemStorageSpec {
+        path: temp_dir.path().to_string_lossy().into_owned(),
+    });
+
+    let requests =
+        reconcile_with_mock_objects(backup, vec![completed_backup_job()], Vec::new()).await;
+
+    let last_backup = &requests
+        .iter()
+        .find(|r| {
+            r.method == "PATCH"
+                && r.path.ends_with("/kafkabackups/daily-backup/status")
+                && r.body["status"].get("lastBackup").is_some()
+        })
+        .expect("a completed backup must be recorded without its manifest")
+        .body["status"]["lastBackup"];
+    assert_eq!(last_backup["status"], json!("Completed"));
+    assert!(last_backup.get("sizeBytes").is_none());
+}
+
+#[tokio::test]
+async fn test_snapshots_that_fail_to_store_are_recorded_as_missing() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let mut backup = scheduled_backup(false);
+    backup.spec.storage.as_mut().unwrap().filesystem = Some(FilesystemStorageSpec {
+        path: temp_dir.path().to_string_lossy().into_owned(),
+    });
+    // A file in place of the backup's directory makes every write fail
+    std::fs::write(temp_dir.path().join("daily-backup-20260213-020000"), b"").unwrap();
+
+    let requests =
+        reconcile_with_mock_objects(backup, vec![completed_backup_job()], Vec::new()).await;
+
+    let completed = requests
+        .iter()
+        .find(|r| {
+            r.method == "PATCH"
+                && r.path.ends_with("/kafkabackups/daily-backup/status")
+                && r.body["status"].get("lastBackup").is_some()
+        })
+        .expect("a completed backup must be recorded");
+    assert_eq!(
+        completed.body["status"]["snapshots"],
+        json!({
+            "backupId": "daily-backup-20260213-020000",
+            "missing": ["kafkacluster.json", "kafkatopics.json"]
+        })
+    );
+    let event = requests
+        .iter()
+        .find(|r| {
+            r.method == "POST"
+                && r.path.ends_with("/namespaces/kafka/events")
+                && r.body["reason"] == "SnapshotsIncomplete"
+        })
+        .expect("missing snapshots must be reported as an Event");
+    assert_eq!(event.body["type"], json!("Warning"));
+    assert!(event.body["note"].as_str().unwrap().starts_with(
+        "Backup daily-backup-20260213-020000 is missing kafkacluster.json, kafkatopics.json"
+    ));
+}
+
+#[tokio::test]
+async fn test_missing_snapshots_are_retried() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let mut backup = scheduled_backup(false);
+    backup.spec.storage.as_mut().unwrap().filesystem = Some(FilesystemStorageSpec {
+        path: temp_dir.path().to_string_lossy().into_owned(),
+    });
+    backup.status = Some(KafkaBackupStatus {
+        last_backup: Some(LastBackupInfo {
+            id: "daily-backup-20260213-020000".to_string(),
+            start_time: "2026-02-13T02:00:00Z".parse().unwrap(),
+            completion_time: None,
+            status: BackupStatus::Completed,
+            size_bytes: None,
+            topics_backed_up: None,
+            partitions_backed_up: None,
+            oldest_timestamp: None,
+            newest_timestamp: None,
+        }),
+        snapshots: Some(BackupSnapshotsInfo {
+            backup_id: "daily-backup-20260213-020000".to_string(),
+            missing: vec!["kafkatopics.json".to_string()],
+        }),
+        ..Default::default()
+    });
+
+    let requests =
+        reconcile_with_mock_objects(backup, vec![completed_backup_job()], Vec::new()).await;
+
+    assert!(temp_dir
+        .path()
+        .join("daily-backup-20260213-020000/kafkatopics.json")
+        .exists());
+    assert!(
+        !temp_dir
+            .path()
+            .join("daily-backup-20260213-020000/kafkacluster.json")
+            .exists(),
+        "files already written are not written again"
+    );
+    let patch = requests
+        .iter()
+        .rfind(|r| {
+            r.method == "PATCH"
+                && r.path.ends_with("/kafkabackups/daily-backup/status")
+                && r.body["status"].get("snapshots").is_some()
+        })
+        .expect("the written snapshot must be recorded");
+    assert_eq!(patch.body["status"]["snapshots"]["missing"], json!([]));
+}
+
+fn backup_with_last_backup(storage: &std::path::Path, synced: Option<&str>) -> KafkaBackup {
+    let mut backup = scheduled_backup(false);
+    backup.spec.storage.as_mut().unwrap().filesystem = Some(FilesystemStorageSpec {
+        path: storage.to_string_lossy().into_owned(),
+    });
+    backup.status = Some(KafkaBackupStatus {
+        last_backup: Some(LastBackupInfo {
+            id: "daily-backup-20260213-020000".to_string(),
+            start_time: "2026-02-13T02:00:00Z".parse().unwrap(),
+            completion_time: None,
+            status: BackupStatus::Completed,
+            size_bytes: None,
+            topics_backed_up: None,
+            partitions_backed_up: None,
+            oldest_timestamp: None,
+            newest_timestamp: None,
+        }),
+        last_synced_backup_id: synced.map(str::to_string),
+        ..Default::default()
+    });
+    backup
+}
+
+#[tokio::test]
+async fn test_backup_snapshots_are_synced_when_the_last_backup_changes() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let backup = backup_with_last_backup(temp_dir.path(), Some("daily-backup-20260212-020000"));
+
+    let requests = reconcile_with_mock_api(backup).await;
+
+    assert!(requests
+        .iter()
+        .any(|r| r.method == "GET" && r.path.ends_with("/kafkabackupsnapshots")));
+    let patch = requests
+        .iter()
+        .rfind(|r| r.method == "PATCH" && r.path.ends_with("/kafkabackups/daily-backup/status"))
+        .expect("the sync must be recorded");
+    assert_eq!(
+        patch.body["status"]["lastSyncedBackupId"],
+        json!("daily-backup-20260213-020000")
+    );
+}
+
+#[tokio::test]
+async fn test_backup_snapshots_are_not_resynced_for_the_same_backup() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let backup = backup_with_last_backup(temp_dir.path(), Some("daily-backup-20260213-020000"));
+
+    let requests = reconcile_with_mock_api(backup).await;
+
+    assert!(!requests
+        .iter()
+        .any(|r| r.path.ends_with("/kafkabackupsnapshots")));
+}
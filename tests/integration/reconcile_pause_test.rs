@@ -11,6 +11,7 @@ use kafka_backup_operator::crd::{KafkaBackup, KafkaRestore};
 use kafka_backup_operator::metrics::prometheus::MetricsState;
 use kafka_backup_operator::reconcilers::backup::reconcile_backup;
 use kafka_backup_operator::reconcilers::restore::reconcile_restore;
+use kafka_backup_operator::status::events;
 use kube::client::Body;
 use kube::Client;
 use serde::Serialize;
@@ -40,9 +41,12 @@ fn paused_backup() -> KafkaBackup {
         topics: None,
         connection: None,
         consumer_groups: None,
+        users: None,
+        connect: None,
+        schema_registry: None,
         logging: None,
         env: Vec::new(),
-        storage: StorageSpec {
+        storage: Some(StorageSpec {
             storage_type: StorageType::Filesystem,
             s3: None,
             azure: None,
@@ -50,7 +54,8 @@ fn paused_backup() -> KafkaBackup {
             filesystem: Some(FilesystemStorageSpec {
                 path: "/backups".to_string(),
             }),
-        },
+        }),
+        storage_ref: None,
         backup: None,
         metrics: None,
         offset_storage: None,
@@ -77,8 +82,12 @@ fn paused_restore() -> KafkaRestore {
         strimzi_cluster_ref: cluster_ref(),
         authentication: None,
         backup_ref: BackupRef {
-            name: "restore-source".to_string(),
+            kind: None,
+            name: Some("restore-source".to_string()),
+            namespace: None,
             backup_id: Some("snapshot-before-disaster".to_string()),
+            storage: None,
+            storage_ref: None,
         },
         topics: None,
         point_in_time: None,
@@ -87,6 +96,9 @@ fn paused_restore() -> KafkaRestore {
         env: Vec::new(),
         topic_mapping: Vec::new(),
         consumer_groups: None,
+        users: None,
+        connect: None,
+        schema_registry: None,
         restore: None,
         metrics: None,
         resources: None,
@@ -173,7 +185,8 @@ fn assert_status_only(requests: &[RecordedRequest], resource_plural: &str, name:
 async fn paused_backup_does_not_create_operator_resources() {
     let backup = paused_backup();
     let requests = reconcile_paused_resource(backup.clone(), move |client, metrics| async move {
-        reconcile_backup(Arc::new(backup), client, &metrics).await
+        let recorder = events::recorder(client.clone(), &backup);
+        reconcile_backup(Arc::new(backup), client, &recorder, &metrics).await
     })
     .await;
 
@@ -184,7 +197,8 @@ async fn paused_backup_does_not_create_operator_resources() {
 async fn paused_restore_does_not_resolve_dependencies_or_create_a_job() {
     let restore = paused_restore();
     let requests = reconcile_paused_resource(restore.clone(), move |client, metrics| async move {
-        reconcile_restore(Arc::new(restore), client, &metrics).await
+        let recorder = events::recorder(client.clone(), &restore);
+        reconcile_restore(Arc::new(restore), client, &recorder, &metrics).await
     })
     .await;
 
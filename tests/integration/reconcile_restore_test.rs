@@ -0,0 +1,1412 @@
+use std::sync::{Arc, Mutex};
+
+use http::{Request, Response};
+use http_body_util::BodyExt;
+use kafka_backup_operator::crd::kafka_restore::KafkaRestoreStatus;
+use kafka_backup_operator::crd::KafkaRestore;
+use kafka_backup_operator::metrics::prometheus::MetricsState;
+use kafka_backup_operator::reconcilers::restore::reconcile_restore;
+use kafka_backup_operator::status::events;
+use kube::client::Body;
+use kube::Client;
+use serde_json::json;
+use tower_test::mock;
+
+#[derive(Debug)]
+struct RecordedRequest {
+    method: String,
+    path: String,
+    body: serde_json::Value,
+}
+
+/// A restore in a fresh DR cluster: no KafkaBackup CR exists, only the
+/// storage the backups were written to.
+fn restore_from_storage(backup_ref: serde_json::Value) -> KafkaRestore {
+    serde_json::from_value(json!({
+        "apiVersion": "kafkabackup.com/v1alpha1",
+        "kind": "KafkaRestore",
+        "metadata": {
+            "name": "dr-restore",
+            "namespace": "kafka",
+            "uid": "restore-uid",
+            "generation": 1,
+            "finalizers": ["kafkabackup.com/cleanup"]
+        },
+        "spec": {
+            "strimziClusterRef": {"name": "dr-cluster"},
+            "backupRef": backup_ref
+        }
+    }))
+    .unwrap()
+}
+
+fn not_found() -> (u16, serde_json::Value) {
+    (
+        404,
+        json!({
+            "kind": "Status", "apiVersion": "v1", "metadata": {},
+            "status": "Failure", "message": "not found",
+            "reason": "NotFound", "code": 404
+        }),
+    )
+}
+
+/// A KafkaBackup in the locked-down `kafka-backups` namespace, with S3
+/// credentials in a Secret there
+fn shared_backup() -> serde_json::Value {
+    json!({
+        "apiVersion": "kafkabackup.com/v1alpha1",
+        "kind": "KafkaBackup",
+        "metadata": {"name": "orders-backup", "namespace": "kafka-backups"},
+        "spec": {
+            "strimziClusterRef": {"name": "production-cluster"},
+            "storage": {"type": "s3", "s3": {
+                "bucket": "kafka-backups",
+                "region": "eu-west-1",
+                "accessKeySecret": {"name": "s3-creds", "key": "access-key-id"},
+                "secretKeySecret": {"name": "s3-creds", "key": "secret-access-key"}
+            }}
+        }
+    })
+}
+
+fn grant_to_team_a(kind: &str) -> serde_json::Value {
+    json!({
+        "apiVersion": "kafkabackup.com/v1alpha1",
+        "kind": "KafkaBackupGrant",
+        "metadata": {"name": "team-a", "namespace": "kafka-backups"},
+        "spec": {"from": [{"namespace": "kafka"}], "to": [{"kind": kind}]}
+    })
+}
+
+/// Run `reconcile_restore` against a mock API server holding a Kafka cluster
+/// and a BackupStorageLocation in `kafka`, and `grants` plus a KafkaBackup in
+/// `kafka-backups`.
+async fn reconcile_with_mock_api(
+    restore: KafkaRestore,
+    grants: Vec<serde_json::Value>,
+) -> (
+    kafka_backup_operator::error::Result<()>,
+    Vec<RecordedRequest>,
+) {
+    reconcile_with_kafka_topics(restore, grants, Vec::new()).await
+}
+
+/// Like [`reconcile_with_mock_api`], with the API server also listing
+/// `kafka_topics` in `kafka`.
+async fn reconcile_with_kafka_topics(
+    restore: KafkaRestore,
+    grants: Vec<serde_json::Value>,
+    kafka_topics: Vec<serde_json::Value>,
+) -> (
+    kafka_backup_operator::error::Result<()>,
+    Vec<RecordedRequest>,
+) {
+    reconcile_with_objects(
+        restore,
+        grants,
+        Vec::new(),
+        kafka_topics,
+        Vec::new(),
+        Vec::new(),
+        &MetricsState::new(),
+    )
+    .await
+}
+
+/// Like [`reconcile_with_kafka_topics`], with the API server also serving
+/// `backups` in `kafka` and listing the restore's `jobs` and their `pods`,
+/// recording into `metrics`.
+async fn reconcile_with_objects(
+    restore: KafkaRestore,
+    grants: Vec<serde_json::Value>,
+    backups: Vec<serde_json::Value>,
+    kafka_topics: Vec<serde_json::Value>,
+    jobs: Vec<serde_json::Value>,
+    pods: Vec<serde_json::Value>,
+    metrics: &MetricsState,
+) -> (
+    kafka_backup_operator::error::Result<()>,
+    Vec<RecordedRequest>,
+) {
+    let (mock_service, mut handle) = mock::pair::<Request<Body>, Response<Body>>();
+    let recorded = Arc::new(Mutex::new(Vec::new()));
+
+    let restore_json = serde_json::to_value(&restore).unwrap();
+    let dispatcher = {
+        let recorded = Arc::clone(&recorded);
+        tokio::spawn(async move {
+            while let Some((request, send)) = handle.next_request().await {
+                let method = request.method().to_string();
+                let path = request.uri().path().to_string();
+                let bytes = request.into_body().collect().await.unwrap().to_bytes();
+                let body: serde_json::Value = if bytes.is_empty() {
+                    serde_json::Value::Null
+                } else {
+                    serde_json::from_slice(&bytes).unwrap()
+                };
+
+                let (status, response_body) = if method == "GET" && path.ends_with("/jobs") {
+                    (
+                        200,
+                        json!({"apiVersion": "batch/v1", "kind": "JobList", "metadata": {}, "items": jobs}),
+                    )
+                } else if method == "GET" && path.ends_with("/namespaces/kafka/pods") {
+                    (
+                        200,
+                        json!({"apiVersion": "v1", "kind": "PodList", "metadata": {}, "items": pods}),
+                    )
+                } else if method == "GET" && path.ends_with("/namespaces/kafka/kafkatopics") {
+                    (
+                        200,
+                        json!({
+                            "apiVersion": "kafka.strimzi.io/v1",
+                            "kind": "KafkaTopicList",
+                            "metadata": {},
+                            "items": kafka_topics
+                        }),
+                    )
+                } else if path.ends_with("/backupstoragelocations/dr-bucket") {
+                    (
+                        200,
+                        json!({
+                            "apiVersion": "kafkabackup.com/v1alpha1",
+                            "kind": "BackupStorageLocation",
+                            "metadata": {"name": "dr-bucket", "namespace": "kafka"},
+                            "spec": {"storage": {
+                                "type": "filesystem",
+                                "filesystem": {"path": "/mnt/dr-backups"}
+                            }}
+                        }),
+                    )
+                } else if path.ends_with("/kafkas/dr-cluster") {
+                    (
+                        200,
+                        json!({
+                            "apiVersion": "kafka.strimzi.io/v1beta2",
+                            "kind": "Kafka",
+                            "metadata": {"name": "dr-cluster", "namespace": "kafka"},
+                            "spec": {"kafka": {"listeners": [
+                                {"name": "plain", "port": 9092, "type": "internal", "tls": false}
+                            ]}},
+                            "status": {"listeners": [
+                                {"name": "plain", "bootstrapServers": "dr-cluster-kafka-bootstrap.kafka.svc:9092"}
+                            ]}
+                        }),
+                    )
+                } else if path.ends_with("/namespaces/kafka-backups/kafkabackupgrants") {
+                    (
+                        200,
+                        json!({
+                            "apiVersion": "kafkabackup.com/v1alpha1",
+                            "kind": "KafkaBackupGrantList",
+                            "metadata": {},
+                            "items": grants
+                        }),
+                    )
+                } else if let Some(backup) = path
+                    .strip_prefix("/apis/kafkabackup.com/v1alpha1/namespaces/kafka/kafkabackups/")
+                    .and_then(|name| {
+                        backups
+                            .iter()
+                            .find(|b| b["metadata"]["name"].as_str() == Some(name))
+                    })
+                {
+                    (200, backup.clone())
+                } else if path.ends_with("/namespaces/kafka-backups/kafkabackups/orders-backup") {
+                    (200, shared_backup())
+                } else if path.ends_with("/namespaces/kafka-backups/secrets/s3-creds") {
+                    (
+                        200,
+                        json!({
+                            "apiVersion": "v1",
+                            "kind": "Secret",
+                            "metadata": {"name": "s3-creds", "namespace": "kafka-backups"},
+                            "data": {
+                                "access-key-id": "QUtJQQ==",
+                                "secret-access-key": "c2VjcmV0",
+                                "unrelated": "bm90LWNvcGllZA=="
+                            }
+                        }),
+                    )
+                } else if path.contains("/kafkarestores/") {
+                    (200, restore_json.clone())
+                } else if method == "GET" {
+                    not_found()
+                } else {
+                    // ConfigMap apply and Job create: echo the object back
+                    (200, body.clone())
+                };
+
+                recorded
+                    .lock()
+                    .unwrap()
+                    .push(RecordedRequest { method, path, body });
+
+                let response = Response::builder()
+                    .status(status)
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&response_body).unwrap()))
+                    .unwrap();
+                send.send_response(response);
+            }
+        })
+    };
+
+    let client = Client::new(mock_service, "kafka");
+    let recorder = events::recorder(client.clone(), &restore);
+    let result = reconcile_restore(Arc::new(restore), client, &recorder, metrics).await;
+    // The recorder holds a client too: drop it so the mock API shuts down
+    drop(recorder);
+
+    dispatcher.await.unwrap();
+    let requests = Arc::try_unwrap(recorded).unwrap().into_inner().unwrap();
+    (result, requests)
+}
+
+#[tokio::test]
+async fn test_restore_from_storage_location_without_kafka_backup() {
+    let restore = restore_from_storage(json!({
+        "storageRef": {"name": "dr-bucket"},
+        "backupId": "daily-backup-20260213-020000"
+    }));
+
+    let (result, requests) = reconcile_with_mock_api(restore, Vec::new()).await;
+    result.expect("reconcile should succeed");
+
+    assert!(!requests.iter().any(|r| r.path.contains("/kafkabackups")));
+
+    let config_map = requests
+        .iter()
+        .find(|r| r.method == "PATCH" && r.path.ends_with("/configmaps/dr-restore-config"))
+        .expect("reconcile must write the restore config");
+    let config: serde_yaml::Value =
+        serde_yaml::from_str(config_map.body["data"]["restore.yaml"].as_str().unwrap()).unwrap();
+    assert_eq!(
+        config["backup_id"].as_str(),
+        Some("daily-backup-20260213-020000")
+    );
+    assert_eq!(config["storage"]["backend"].as_str(), Some("filesystem"));
+    assert_eq!(config["storage"]["path"].as_str(), Some("/mnt/dr-backups"));
+
+    assert!(requests
+        .iter()
+        .any(|r| r.method == "POST" && r.path.ends_with("/jobs")));
+
+    // status.restore marks the restore as started, which freezes its spec
+    let status_patch = requests
+        .iter()
+        .rfind(|r| r.method == "PATCH" && r.path.ends_with("/kafkarestores/dr-restore/status"))
+        .expect("reconcile must report the running restore");
+    let info = &status_patch.body["status"]["restore"];
+    assert_eq!(info["status"], json!("Running"));
+    assert!(info["startTime"].is_string());
+    assert!(info.get("completionTime").is_none());
+}
+
+#[tokio::test]
+async fn test_restore_from_storage_requires_backup_id() {
+    let restore = restore_from_storage(json!({
+        "storage": {"type": "filesystem", "filesystem": {"path": "/mnt/dr-backups"}}
+    }));
+
+    let (result, requests) = reconcile_with_mock_api(restore, Vec::new()).await;
+    let err = result.unwrap_err();
+    assert_eq!(err.reason(), "InvalidConfiguration");
+    assert!(err.to_string().contains("backupRef.backupId is required"));
+
+    let status_patch = requests
+        .iter()
+        .find(|r| r.method == "PATCH" && r.path.ends_with("/kafkarestores/dr-restore/status"))
+        .expect("reconcile must report the invalid backupRef");
+    assert_eq!(
+        status_patch.body["status"]["conditions"][0]["status"],
+        json!("False")
+    );
+    assert!(!requests
+        .iter()
+        .any(|r| r.method == "POST" && r.path.ends_with("/jobs")));
+}
+
+#[tokio::test]
+async fn test_cross_namespace_restore_copies_storage_credentials() {
+    let restore = restore_from_storage(json!({
+        "name": "orders-backup",
+        "namespace": "kafka-backups",
+        "backupId": "orders-backup-20260213-020000"
+    }));
+
+    let (result, requests) =
+        reconcile_with_mock_api(restore, vec![grant_to_team_a("KafkaBackup")]).await;
+    result.expect("reconcile should succeed");
+
+    let copy = requests
+        .iter()
+        .find(|r| {
+            r.method == "PATCH"
+                && r.path
+                    .ends_with("/namespaces/kafka/secrets/dr-restore-storage-s3-creds")
+        })
+        .expect("reconcile must copy the storage Secret into the restore namespace");
+    let data = copy.body["data"].as_object().unwrap();
+    assert_eq!(data.len(), 2, "only the referenced keys are copied");
+    assert_eq!(data["access-key-id"], json!("QUtJQQ=="));
+    assert_eq!(
+        copy.body["metadata"]["labels"]["kafkabackup.com/source-namespace"],
+        json!("kafka-backups")
+    );
+    assert_eq!(
+        copy.body["metadata"]["ownerReferences"][0]["kind"],
+        json!("KafkaRestore")
+    );
+
+    let job = requests
+        .iter()
+        .find(|r| r.method == "POST" && r.path.ends_with("/namespaces/kafka/jobs"))
+        .expect("reconcile must create the restore Job");
+    let job = job.body.to_string();
+    assert!(job.contains("dr-restore-storage-s3-creds"));
+    assert!(!job.contains("\"s3-creds\""));
+}
+
+#[tokio::test]
+async fn test_completed_cross_namespace_restore_copies_no_secrets() {
+    let mut restore = restore_from_storage(json!({
+        "name": "orders-backup",
+        "namespace": "kafka-backups"
+    }));
+    restore.status = Some(
+        serde_json::from_value(json!({"restore": {
+            "startTime": "2026-02-13T03:00:00Z",
+            "status": "Running",
+            "backupId": "orders-backup-20260213-020000"
+        }}))
+        .unwrap(),
+    );
+
+    let (result, requests) = reconcile_with_objects(
+        restore,
+        vec![grant_to_team_a("KafkaBackup")],
+        Vec::new(),
+        Vec::new(),
+        vec![finished_restore_job("Complete")],
+        vec![restore_pod(
+            0,
+            r#"{"exitCode":0,"bytes":10,"topics":[{"name":"orders"}]}"#,
+        )],
+        &MetricsState::new(),
+    )
+    .await;
+    result.expect("reconcile should succeed");
+
+    assert!(!requests
+        .iter()
+        .any(|r| r.method == "PATCH" && r.path.contains("/secrets/")));
+    assert_eq!(
+        status_patch(&requests)["restore"]["status"],
+        json!("Completed")
+    );
+}
+
+#[tokio::test]
+async fn test_cross_namespace_restore_without_grant_is_rejected() {
+    let restore = restore_from_storage(json!({
+        "name": "orders-backup",
+        "namespace": "kafka-backups"
+    }));
+
+    // A grant for snapshots does not cover KafkaBackups
+    let (result, requests) =
+        reconcile_with_mock_api(restore, vec![grant_to_team_a("KafkaBackupSnapshot")]).await;
+    let err = result.unwrap_err();
+    assert_eq!(err.reason(), "BackupRefNotPermitted");
+
+    assert!(!requests
+        .iter()
+        .any(|r| r.path.contains("/kafkabackups/") || r.path.contains("/secrets/")));
+    let status_patch = requests
+        .iter()
+        .find(|r| r.method == "PATCH" && r.path.ends_with("/kafkarestores/dr-restore/status"))
+        .expect("reconcile must report the rejected backupRef");
+    assert_eq!(
+        status_patch.body["status"]["conditions"][0]["reason"],
+        json!("BackupRefNotPermitted")
+    );
+
+    let event = events_posted(&requests)
+        .next()
+        .expect("the rejected backupRef must be reported as an Event");
+    assert_eq!(event["type"], json!("Warning"));
+    assert_eq!(event["reason"], json!("BackupRefNotPermitted"));
+    assert_eq!(
+        event["regarding"]["name"],
+        json!("dr-restore"),
+        "the Event must be attached to the KafkaRestore"
+    );
+}
+
+#[tokio::test]
+async fn test_repeated_error_is_reported_as_an_event_once() {
+    let mut restore = restore_from_storage(json!({
+        "name": "orders-backup",
+        "namespace": "kafka-backups"
+    }));
+    let (first, _) = reconcile_with_mock_api(restore.clone(), Vec::new()).await;
+    let message = first.unwrap_err().to_string();
+    restore.status = Some(serde_json::from_value(json!({
+        "conditions": [{"type": "Error", "status": "True", "reason": "BackupRefNotPermitted", "message": message}]
+    })).unwrap());
+
+    let (result, requests) = reconcile_with_mock_api(restore, Vec::new()).await;
+    assert!(result.is_err());
+    assert_eq!(events_posted(&requests).count(), 0);
+}
+
+/// Bodies of the Events the operator published
+fn events_posted(requests: &[RecordedRequest]) -> impl Iterator<Item = &serde_json::Value> {
+    requests
+        .iter()
+        .filter(|r| r.method == "POST" && r.path.ends_with("/namespaces/kafka/events"))
+        .map(|r| &r.body)
+}
+
+/// A restore from a filesystem backup in `backup_dir` holding the
+/// `production-cluster` Kafka, the `orders` and `payments` KafkaTopics, the
+/// `orders-app` and `admin` KafkaUsers, and the `pipelines` KafkaConnect with
+/// its `orders-sink` connector
+fn restore_with_stored_resources(
+    backup_dir: &std::path::Path,
+    restore_spec: serde_json::Value,
+) -> KafkaRestore {
+    let backup_id = "daily-backup-20260213-020000";
+    std::fs::create_dir_all(backup_dir.join(backup_id)).unwrap();
+    std::fs::write(
+        backup_dir.join(backup_id).join("kafkatopics.json"),
+        serde_json::to_vec(&json!([
+            {
+                "metadata": {
+                    "name": "orders",
+                    "labels": {"strimzi.io/cluster": "production-cluster", "team": "checkout"}
+                },
+                "spec": {"partitions": 12, "replicas": 3, "config": {"cleanup.policy": "compact"}}
+            },
+            {
+                "metadata": {"name": "payments", "labels": {"strimzi.io/cluster": "production-cluster"}},
+                "spec": {"topicName": "payments", "partitions": 6, "replicas": 3}
+            }
+        ]))
+        .unwrap(),
+    )
+    .unwrap();
+    std::fs::write(
+        backup_dir.join(backup_id).join("kafkausers.json"),
+        serde_json::to_vec(&json!([
+            {
+                "metadata": {"name": "admin", "labels": {"strimzi.io/cluster": "production-cluster"}},
+                "spec": {"authentication": {"type": "tls"}}
+            },
+            {
+                "metadata": {"name": "orders-app", "labels": {"strimzi.io/cluster": "production-cluster"}},
+                "spec": {
+                    "authentication": {"type": "tls"},
+                    "authorization": {"type": "simple", "acls": [
+                        {"resource": {"type": "topic", "name": "orders"}, "operations": ["Read"]},
+                        {"resource": {"type": "topic", "name": "orders", "patternType": "prefix"}, "operations": ["Describe"]},
+                        {"resource": {"type": "group", "name": "orders"}, "operations": ["Read"]}
+                    ]},
+                    "quotas": {"consumerByteRate": 1048576}
+                }
+            }
+        ]))
+        .unwrap(),
+    )
+    .unwrap();
+
+    std::fs::write(
+        backup_dir.join(backup_id).join("kafkaconnects.json"),
+        serde_json::to_vec(&json!({
+            "kafkaCluster": "production-cluster",
+            "kafkaConnects": [{
+                "kafkaConnect": {
+                    "metadata": {
+                        "name": "pipelines",
+                        "annotations": {"strimzi.io/use-connector-resources": "true"}
+                    },
+                    "spec": {
+                        "replicas": 2,
+                        "bootstrapServers": "production-cluster-kafka-bootstrap.kafka.svc:9093",
+                        "tls": {"trustedCertificates": [
+                            {"secretName": "production-cluster-cluster-ca-cert", "certificate": "ca.crt"}
+                        ]},
+                        "config": {"group.id": "pipelines", "offset.storage.topic": "pipelines-offsets"}
+                    }
+                },
+                "connectors": [{
+                    "metadata": {"name": "orders-sink", "labels": {"strimzi.io/cluster": "pipelines"}},
+                    "spec": {"class": "io.example.OrdersSink", "tasksMax": 2, "pause": false}
+                }]
+            }]
+        }))
+        .unwrap(),
+    )
+    .unwrap();
+    std::fs::write(
+        backup_dir.join(backup_id).join("kafkacluster.json"),
+        serde_json::to_vec(&json!({
+            "kafka": {
+                "metadata": {
+                    "name": "production-cluster",
+                    "annotations": {"strimzi.io/node-pools": "enabled", "strimzi.io/kraft": "enabled"}
+                },
+                "spec": {"kafka": {"listeners": [
+                    {"name": "plain", "port": 9092, "type": "internal", "tls": false}
+                ]}}
+            },
+            "nodePools": [{
+                "metadata": {"name": "dual-role", "labels": {"strimzi.io/cluster": "production-cluster"}},
+                "spec": {"replicas": 3, "roles": ["controller", "broker"]}
+            }]
+        }))
+        .unwrap(),
+    )
+    .unwrap();
+
+    let mut restore = restore_from_storage(json!({
+        "storage": {
+            "type": "filesystem",
+            "filesystem": {"path": backup_dir.to_string_lossy()}
+        },
+        "backupId": backup_id
+    }));
+    let mut spec = serde_json::to_value(&restore.spec).unwrap();
+    for (key, value) in restore_spec.as_object().unwrap() {
+        spec[key] = value.clone();
+    }
+    restore.spec = serde_json::from_value(spec).unwrap();
+    restore
+}
+
+fn ready_topic(name: &str) -> serde_json::Value {
+    json!({
+        "apiVersion": "kafka.strimzi.io/v1",
+        "kind": "KafkaTopic",
+        "metadata": {"name": name, "namespace": "kafka", "generation": 1},
+        "status": {
+            "observedGeneration": 1,
+            "conditions": [{"type": "Ready", "status": "True"}]
+        }
+    })
+}
+
+#[tokio::test]
+async fn test_strimzi_topic_creation_waits_for_kafka_topics() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let restore = restore_with_stored_resources(
+        temp_dir.path(),
+        json!({
+            "topics": {"include": ["orders"]},
+            "topicMapping": [{"sourceTopic": "orders", "targetTopic": "orders_dr"}],
+            "restore": {"topicCreation": "strimzi", "defaultReplicationFactor": 1}
+        }),
+    );
+
+    let (result, requests) = reconcile_with_kafka_topics(restore, Vec::new(), Vec::new()).await;
+    result.expect("reconcile should succeed");
+
+    let applied: Vec<&RecordedRequest> = requests
+        .iter()
+        .filter(|r| r.method == "PATCH" && r.path.contains("/kafkatopics/"))
+        .collect();
+    assert_eq!(applied.len(), 1, "topics are selected by spec.topics");
+    let topic = &applied[0];
+    assert!(topic
+        .path
+        .ends_with("/namespaces/kafka/kafkatopics/orders-dr"));
+    assert_eq!(topic.body["kind"], json!("KafkaTopic"));
+    assert_eq!(topic.body["spec"]["topicName"], json!("orders_dr"));
+    assert_eq!(topic.body["spec"]["partitions"], json!(12));
+    assert_eq!(topic.body["spec"]["replicas"], json!(1));
+    assert_eq!(
+        topic.body["spec"]["config"]["cleanup.policy"],
+        json!("compact")
+    );
+    let labels = &topic.body["metadata"]["labels"];
+    assert_eq!(labels["strimzi.io/cluster"], json!("dr-cluster"));
+    assert_eq!(labels["kafkabackup.com/restore"], json!("dr-restore"));
+    assert_eq!(labels["team"], json!("checkout"));
+
+    let status_patch = requests
+        .iter()
+        .find(|r| r.method == "PATCH" && r.path.ends_with("/kafkarestores/dr-restore/status"))
+        .expect("reconcile must report that it is waiting");
+    let condition = &status_patch.body["status"]["conditions"][0];
+    assert_eq!(condition["reason"], json!("WaitingForTopics"));
+    assert!(condition["message"].as_str().unwrap().contains("orders-dr"));
+
+    assert!(!requests
+        .iter()
+        .any(|r| r.path.ends_with("/jobs") && r.method == "POST"));
+    assert!(!requests.iter().any(|r| r.path.contains("/configmaps/")));
+}
+
+#[tokio::test]
+async fn test_strimzi_topic_creation_starts_job_once_topics_are_ready() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let restore = restore_with_stored_resources(
+        temp_dir.path(),
+        json!({"restore": {"topicCreation": "strimzi"}}),
+    );
+
+    let (result, requests) = reconcile_with_kafka_topics(
+        restore,
+        Vec::new(),
+        vec![ready_topic("orders"), ready_topic("payments")],
+    )
+    .await;
+    result.expect("reconcile should succeed");
+
+    let config_map = requests
+        .iter()
+        .find(|r| r.method == "PATCH" && r.path.ends_with("/configmaps/dr-restore-config"))
+        .expect("reconcile must write the restore config");
+    let config: serde_yaml::Value =
+        serde_yaml::from_str(config_map.body["data"]["restore.yaml"].as_str().unwrap()).unwrap();
+    assert_eq!(
+        config["restore"]["create_topics"].as_bool(),
+        Some(false),
+        "the Topic Operator creates the topics, not the restore Job"
+    );
+    assert!(requests
+        .iter()
+        .any(|r| r.method == "POST" && r.path.ends_with("/jobs")));
+}
+
+#[tokio::test]
+async fn test_dry_run_restore_creates_no_kafka_topics() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let restore = restore_with_stored_resources(
+        temp_dir.path(),
+        json!({"restore": {"topicCreation": "strimzi", "dryRun": true}}),
+    );
+
+    let (result, requests) = reconcile_with_kafka_topics(restore, Vec::new(), Vec::new()).await;
+    result.expect("reconcile should succeed");
+
+    assert!(!requests.iter().any(|r| r.path.contains("/kafkatopics")));
+    assert!(requests
+        .iter()
+        .any(|r| r.method == "POST" && r.path.ends_with("/jobs")));
+}
+
+#[tokio::test]
+async fn test_strimzi_topic_creation_requires_kafka_topic_snapshot() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let mut restore = restore_with_stored_resources(
+        temp_dir.path(),
+        json!({"restore": {"topicCreation": "strimzi"}}),
+    );
+    restore.spec.backup_ref.backup_id = Some("daily-backup-20260101-020000".to_string());
+
+    let (result, requests) = reconcile_with_kafka_topics(restore, Vec::new(), Vec::new()).await;
+    let err = result.unwrap_err();
+    assert_eq!(err.reason(), "InvalidConfiguration");
+    assert!(err.to_string().contains("no KafkaTopic snapshot"));
+    assert!(!requests
+        .iter()
+        .any(|r| r.method == "POST" && r.path.ends_with("/jobs")));
+}
+
+#[tokio::test]
+async fn test_restore_recreates_selected_kafka_users() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let restore = restore_with_stored_resources(
+        temp_dir.path(),
+        json!({
+            "users": {"exclude": ["admin"]},
+            "topicMapping": [{"sourceTopic": "orders", "targetTopic": "orders-dr"}]
+        }),
+    );
+
+    let (result, requests) = reconcile_with_mock_api(restore, Vec::new()).await;
+    result.expect("reconcile should succeed");
+
+    let applied: Vec<&RecordedRequest> = requests
+        .iter()
+        .filter(|r| r.method == "PATCH" && r.path.contains("/kafkausers/"))
+        .collect();
+    assert_eq!(applied.len(), 1, "users are selected by spec.users");
+    let user = &applied[0];
+    assert!(user
+        .path
+        .ends_with("/namespaces/kafka/kafkausers/orders-app"));
+    assert_eq!(user.body["kind"], json!("KafkaUser"));
+    assert_eq!(
+        user.body["metadata"]["labels"]["strimzi.io/cluster"],
+        json!("dr-cluster")
+    );
+    assert_eq!(
+        user.body["metadata"]["labels"]["kafkabackup.com/restore"],
+        json!("dr-restore")
+    );
+    let acls = user.body["spec"]["authorization"]["acls"]
+        .as_array()
+        .unwrap();
+    assert_eq!(acls[0]["resource"]["name"], json!("orders-dr"));
+    assert_eq!(
+        acls[1]["resource"]["name"],
+        json!("orders"),
+        "prefix ACLs are not renamed"
+    );
+    assert_eq!(
+        acls[2]["resource"]["name"],
+        json!("orders"),
+        "only topic ACLs are renamed"
+    );
+    assert_eq!(
+        user.body["spec"]["quotas"]["consumerByteRate"],
+        json!(1048576)
+    );
+
+    assert!(requests
+        .iter()
+        .any(|r| r.method == "POST" && r.path.ends_with("/jobs")));
+}
+
+#[tokio::test]
+async fn test_dry_run_restore_applies_no_kafka_users() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let restore = restore_with_stored_resources(
+        temp_dir.path(),
+        json!({"users": {}, "restore": {"dryRun": true}}),
+    );
+
+    let (result, requests) = reconcile_with_mock_api(restore, Vec::new()).await;
+    result.expect("reconcile should succeed");
+
+    assert!(!requests.iter().any(|r| r.path.contains("/kafkausers")));
+    assert!(requests
+        .iter()
+        .any(|r| r.method == "POST" && r.path.ends_with("/jobs")));
+}
+
+#[tokio::test]
+async fn test_create_cluster_rebuilds_missing_kafka_and_waits() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let restore = restore_with_stored_resources(
+        temp_dir.path(),
+        json!({
+            "strimziClusterRef": {"name": "rebuilt-cluster"},
+            "restore": {"createCluster": true}
+        }),
+    );
+
+    let (result, requests) = reconcile_with_mock_api(restore, Vec::new()).await;
+    result.expect("reconcile should succeed");
+
+    let applied: Vec<&RecordedRequest> = requests
+        .iter()
+        .filter(|r| r.method == "PATCH" && r.path.contains("/kafka.strimzi.io/"))
+        .collect();
+    assert_eq!(applied.len(), 2);
+    assert!(
+        applied[0].path.ends_with("/kafkanodepools/dual-role"),
+        "node pools are created before the Kafka CR"
+    );
+    assert_eq!(
+        applied[0].body["metadata"]["labels"]["strimzi.io/cluster"],
+        json!("rebuilt-cluster")
+    );
+    assert!(applied[1].path.ends_with("/kafkas/rebuilt-cluster"));
+    assert_eq!(applied[1].body["kind"], json!("Kafka"));
+    assert_eq!(
+        applied[1].body["metadata"]["annotations"]["strimzi.io/node-pools"],
+        json!("enabled")
+    );
+
+    let status_patch = requests
+        .iter()
+        .find(|r| r.method == "PATCH" && r.path.ends_with("/kafkarestores/dr-restore/status"))
+        .expect("reconcile must report that it is waiting");
+    assert_eq!(
+        status_patch.body["status"]["conditions"][0]["reason"],
+        json!("WaitingForCluster")
+    );
+    assert!(!requests
+        .iter()
+        .any(|r| r.method == "POST" && r.path.ends_with("/jobs")));
+}
+
+#[tokio::test]
+async fn test_dry_run_create_cluster_only_reports_the_missing_kafka() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let restore = restore_with_stored_resources(
+        temp_dir.path(),
+        json!({
+            "strimziClusterRef": {"name": "rebuilt-cluster"},
+            "restore": {"createCluster": true, "dryRun": true}
+        }),
+    );
+
+    let (result, requests) = reconcile_with_mock_api(restore, Vec::new()).await;
+    result.expect("reconcile should succeed");
+
+    assert!(!requests
+        .iter()
+        .any(|r| r.method != "GET" && r.path.contains("/kafka.strimzi.io/")));
+    let condition = &status_patch(&requests)["conditions"][0];
+    assert_eq!(condition["reason"], json!("WaitingForCluster"));
+    assert!(condition["message"]
+        .as_str()
+        .unwrap()
+        .contains("would be created from backup daily-backup-20260213-020000"));
+    assert!(!requests
+        .iter()
+        .any(|r| r.method == "POST" && r.path.ends_with("/jobs")));
+}
+
+#[tokio::test]
+async fn test_create_cluster_leaves_existing_kafka_untouched() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let restore =
+        restore_with_stored_resources(temp_dir.path(), json!({"restore": {"createCluster": true}}));
+
+    // dr-cluster exists but reports no Ready condition yet
+    let (result, requests) = reconcile_with_mock_api(restore, Vec::new()).await;
+    result.expect("reconcile should succeed");
+
+    assert!(!requests
+        .iter()
+        .any(|r| r.method == "PATCH" && r.path.contains("/kafka.strimzi.io/")));
+    let status_patch = requests
+        .iter()
+        .find(|r| r.method == "PATCH" && r.path.ends_with("/kafkarestores/dr-restore/status"))
+        .expect("reconcile must report that it is waiting");
+    assert_eq!(
+        status_patch.body["status"]["conditions"][0]["reason"],
+        json!("WaitingForCluster")
+    );
+}
+
+#[tokio::test]
+async fn test_restore_recreates_kafka_connect_with_paused_connectors() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let restore = restore_with_stored_resources(
+        temp_dir.path(),
+        json!({
+            "connect": {},
+            "topicMapping": [{"sourceTopic": "pipelines-offsets", "targetTopic": "pipelines-offsets-dr"}]
+        }),
+    );
+
+    let (result, requests) = reconcile_with_mock_api(restore.clone(), Vec::new()).await;
+    result.expect("reconcile should succeed");
+    assert!(
+        !requests.iter().any(|r| r.path.contains("/kafkaconnect")),
+        "KafkaConnects must wait for the data restore"
+    );
+    assert!(requests
+        .iter()
+        .any(|r| r.method == "POST" && r.path.ends_with("/jobs")));
+
+    let (result, requests) = reconcile_with_objects(
+        restore,
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        vec![finished_restore_job("Complete")],
+        Vec::new(),
+        &MetricsState::new(),
+    )
+    .await;
+    result.expect("reconcile should succeed");
+
+    let connect = requests
+        .iter()
+        .find(|r| {
+            r.method == "PATCH"
+                && r.path
+                    .ends_with("/namespaces/kafka/kafkaconnects/pipelines")
+        })
+        .expect("the KafkaConnect must be recreated");
+    assert_eq!(connect.body["kind"], json!("KafkaConnect"));
+    assert_eq!(
+        connect.body["spec"]["bootstrapServers"],
+        json!("dr-cluster-kafka-bootstrap:9093")
+    );
+    assert_eq!(
+        connect.body["spec"]["tls"]["trustedCertificates"][0]["secretName"],
+        json!("dr-cluster-cluster-ca-cert")
+    );
+    assert_eq!(
+        connect.body["spec"]["config"]["offset.storage.topic"],
+        json!("pipelines-offsets-dr")
+    );
+    assert_eq!(
+        connect.body["metadata"]["labels"]["kafkabackup.com/restore"],
+        json!("dr-restore")
+    );
+
+    let connector = requests
+        .iter()
+        .find(|r| r.method == "PATCH" && r.path.ends_with("/kafkaconnectors/orders-sink"))
+        .expect("the KafkaConnector must be recreated");
+    assert_eq!(connector.body["spec"]["state"], json!("paused"));
+    assert_eq!(connector.body["spec"].get("pause"), None);
+    assert_eq!(
+        connector.body["metadata"]["labels"]["strimzi.io/cluster"],
+        json!("pipelines")
+    );
+    assert_eq!(
+        status_patch(&requests)["kafkaConnectsRestored"],
+        json!(true)
+    );
+}
+
+#[tokio::test]
+async fn test_restored_kafka_connects_are_not_applied_again() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let mut restore = restore_with_stored_resources(temp_dir.path(), json!({"connect": {}}));
+    restore.status = Some(KafkaRestoreStatus {
+        kafka_connects_restored: Some(true),
+        ..Default::default()
+    });
+
+    let (result, requests) = reconcile_with_objects(
+        restore,
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        vec![finished_restore_job("Complete")],
+        Vec::new(),
+        &MetricsState::new(),
+    )
+    .await;
+    result.expect("reconcile should succeed");
+
+    assert!(!requests.iter().any(|r| r.path.contains("/kafkaconnect")));
+    assert_eq!(
+        status_patch(&requests)["restore"]["status"],
+        json!("Completed")
+    );
+}
+
+/// A KafkaBackup in `kafka` writing to `backup_dir`, whose latest backup,
+/// `daily-backup-20260214-020000`, holds no files
+fn local_backup(backup_dir: &std::path::Path) -> serde_json::Value {
+    json!({
+        "apiVersion": "kafkabackup.com/v1alpha1",
+        "kind": "KafkaBackup",
+        "metadata": {"name": "daily-backup", "namespace": "kafka"},
+        "spec": {
+            "strimziClusterRef": {"name": "production-cluster"},
+            "storage": {"type": "filesystem", "filesystem": {"path": backup_dir.to_string_lossy()}}
+        },
+        "status": {"lastBackup": {
+            "id": "daily-backup-20260214-020000",
+            "startTime": "2026-02-14T02:00:00Z",
+            "status": "Completed"
+        }}
+    })
+}
+
+#[tokio::test]
+async fn test_restore_job_records_the_backup_id() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let mut restore = restore_with_stored_resources(temp_dir.path(), json!({}));
+    restore.spec.backup_ref = serde_json::from_value(json!({"name": "daily-backup"})).unwrap();
+
+    let (result, requests) = reconcile_with_objects(
+        restore,
+        Vec::new(),
+        vec![local_backup(temp_dir.path())],
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        &MetricsState::new(),
+    )
+    .await;
+    result.expect("reconcile should succeed");
+
+    assert!(requests
+        .iter()
+        .any(|r| r.method == "POST" && r.path.ends_with("/jobs")));
+    assert_eq!(
+        status_patch(&requests)["restore"]["backupId"],
+        json!("daily-backup-20260214-020000")
+    );
+}
+
+#[tokio::test]
+async fn test_kafka_connects_come_from_the_restored_backup() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let mut restore = restore_with_stored_resources(temp_dir.path(), json!({"connect": {}}));
+    restore.spec.backup_ref = serde_json::from_value(json!({"name": "daily-backup"})).unwrap();
+    // The Job restored an older backup than the KafkaBackup's latest one
+    restore.status = Some(
+        serde_json::from_value(json!({"restore": {
+            "startTime": "2026-02-13T03:00:00Z",
+            "status": "Running",
+            "backupId": "daily-backup-20260213-020000"
+        }}))
+        .unwrap(),
+    );
+
+    let (result, requests) = reconcile_with_objects(
+        restore,
+        Vec::new(),
+        vec![local_backup(temp_dir.path())],
+        Vec::new(),
+        vec![finished_restore_job("Complete")],
+        Vec::new(),
+        &MetricsState::new(),
+    )
+    .await;
+    result.expect("reconcile should succeed");
+
+    assert!(requests.iter().any(|r| {
+        r.method == "PATCH"
+            && r.path
+                .ends_with("/namespaces/kafka/kafkaconnects/pipelines")
+    }));
+    let completed = requests
+        .iter()
+        .rfind(|r| r.method == "PATCH" && r.path.ends_with("/kafkarestores/dr-restore/status"))
+        .unwrap();
+    assert_eq!(
+        completed.body["status"]["restore"]["backupId"],
+        json!("daily-backup-20260213-020000")
+    );
+}
+
+#[tokio::test]
+async fn test_dry_run_restore_deploys_no_kafka_connects() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let restore = restore_with_stored_resources(
+        temp_dir.path(),
+        json!({"connect": {}, "restore": {"dryRun": true}}),
+    );
+
+    let (result, requests) = reconcile_with_objects(
+        restore,
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        vec![finished_restore_job("Complete")],
+        Vec::new(),
+        &MetricsState::new(),
+    )
+    .await;
+    result.expect("reconcile should succeed");
+
+    assert!(!requests.iter().any(|r| r.path.contains("/kafkaconnect")));
+    assert_eq!(
+        status_patch(&requests)["restore"]["status"],
+        json!("Completed")
+    );
+}
+
+#[tokio::test]
+async fn test_imported_schemas_are_not_imported_again() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    // The backup holds no schemas.json, so importing again would fail
+    let mut restore = restore_with_stored_resources(
+        temp_dir.path(),
+        json!({"schemaRegistry": {"url": "http://schema-registry.kafka.svc:8081"}}),
+    );
+    restore.status = Some(KafkaRestoreStatus {
+        schemas_imported: Some(true),
+        ..Default::default()
+    });
+
+    let (result, requests) = reconcile_with_mock_api(restore, Vec::new()).await;
+    result.expect("reconcile should succeed");
+
+    assert!(requests
+        .iter()
+        .any(|r| r.method == "POST" && r.path.ends_with("/jobs")));
+}
+
+fn finished_restore_job(condition: &str) -> serde_json::Value {
+    json!({
+        "apiVersion": "batch/v1",
+        "kind": "Job",
+        "metadata": {"name": "dr-restore-20260213-030000", "namespace": "kafka"},
+        "status": {
+            "startTime": "2026-02-13T03:00:00Z",
+            "completionTime": "2026-02-13T03:20:00Z",
+            "conditions": [{"type": condition, "status": "True"}]
+        }
+    })
+}
+
+/// A pod of the restore Job whose container terminated with `message`
+fn restore_pod(exit_code: i32, message: &str) -> serde_json::Value {
+    json!({
+        "apiVersion": "v1",
+        "kind": "Pod",
+        "metadata": {
+            "name": "dr-restore-20260213-030000-x7k2p",
+            "namespace": "kafka",
+            "labels": {"job-name": "dr-restore-20260213-030000"}
+        },
+        "status": {"containerStatuses": [{
+            "name": "restore",
+            "image": "osodevops/kafka-backup:v0.15.12",
+            "imageID": "",
+            "ready": false,
+            "restartCount": 0,
+            "state": {"terminated": {
+                "exitCode": exit_code,
+                "finishedAt": "2026-02-13T03:19:58Z",
+                "message": message
+            }}
+        }]}
+    })
+}
+
+fn status_patch(requests: &[RecordedRequest]) -> &serde_json::Value {
+    &requests
+        .iter()
+        .find(|r| r.method == "PATCH" && r.path.ends_with("/kafkarestores/dr-restore/status"))
+        .expect("reconcile must update the KafkaRestore status")
+        .body["status"]
+}
+
+#[tokio::test]
+async fn test_completed_restore_records_the_job_result() {
+    let restore = restore_from_storage(json!({
+        "storageRef": {"name": "dr-bucket"},
+        "backupId": "daily-backup-20260213-020000"
+    }));
+    let message = r#"{"exitCode":0,"records":1200,"bytes":52428800,
+        "topics":[{"name":"orders","partitions":6,"records":1000},
+                  {"name":"payments","partitions":3,"records":200}],
+        "pointInTime":"2026-02-13T01:59:59.250Z"}"#;
+    let metrics = MetricsState::new();
+
+    let (result, requests) = reconcile_with_objects(
+        restore,
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        vec![finished_restore_job("Complete")],
+        vec![restore_pod(0, message)],
+        &metrics,
+    )
+    .await;
+    result.expect("reconcile should succeed");
+
+    assert!(
+        requests
+            .iter()
+            .any(|r| r.method == "GET" && r.path.ends_with("/namespaces/kafka/pods")),
+        "the restore Job's pods must be read"
+    );
+
+    let info = &status_patch(&requests)["restore"];
+    assert_eq!(info["status"], json!("Completed"));
+    assert_eq!(info["restoredTopics"], json!(2));
+    assert_eq!(info["restoredPartitions"], json!(9));
+    assert_eq!(info["restoredBytes"], json!(52428800));
+    assert_eq!(info["actualPointInTime"], json!("2026-02-13T01:59:59.250Z"));
+
+    let event = events_posted(&requests)
+        .next()
+        .expect("a completed restore must be reported as an Event");
+    assert_eq!(event["type"], json!("Normal"));
+    assert_eq!(event["reason"], json!("RestoreCompleted"));
+    assert_eq!(
+        event["reportingController"],
+        json!("strimzi-backup-operator")
+    );
+
+    let labels = r#"{cluster="dr-cluster",restore_name="dr-restore"}"#;
+    let output = metrics.gather();
+    assert!(output.contains(&format!("strimzi_restore_records_total{labels} 1200")));
+    assert!(output.contains(&format!("strimzi_restore_bytes_total{labels} 52428800")));
+    assert!(output.contains(&format!(
+        "strimzi_restore_duration_seconds_sum{labels} 1200"
+    )));
+}
+
+#[tokio::test]
+async fn test_completed_restore_counts_come_from_the_manifest() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let restore = restore_with_stored_resources(
+        temp_dir.path(),
+        json!({
+            "topics": {"include": ["orders"]},
+            "topicMapping": [{"sourceTopic": "orders", "targetTopic": "orders-dr"}],
+            "pointInTime": {"timestamp": "2026-02-13T01:15:00Z"}
+        }),
+    );
+    let segment = |size: u64, records: u64, start: i64, end: i64| {
+        json!({"compressed_size": size, "record_count": records,
+               "start_timestamp": start, "end_timestamp": end})
+    };
+    std::fs::write(
+        temp_dir
+            .path()
+            .join("daily-backup-20260213-020000/manifest.json"),
+        serde_json::to_vec(&json!({
+            "backup_id": "daily-backup-20260213-020000",
+            "created_at": 1_770_948_000_000_i64,
+            "topics": [
+                {"name": "orders", "partitions": [
+                    {"segments": [
+                        segment(1000, 10, 1_770_940_800_000, 1_770_944_400_000),
+                        // Starts after the point in time, so it is not restored
+                        segment(2000, 20, 1_770_945_600_000, 1_770_948_000_000)
+                    ]},
+                    {"segments": [segment(500, 5, 1_770_942_600_000, 1_770_945_000_000)]}
+                ]},
+                {"name": "payments", "partitions": [
+                    {"segments": [segment(700, 7, 1_770_940_800_000, 1_770_944_400_000)]}
+                ]}
+            ]
+        }))
+        .unwrap(),
+    )
+    .unwrap();
+    let metrics = MetricsState::new();
+
+    let (result, requests) = reconcile_with_objects(
+        restore,
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        vec![finished_restore_job("Complete")],
+        vec![restore_pod(0, "restore finished")],
+        &metrics,
+    )
+    .await;
+    result.expect("reconcile should succeed");
+
+    let info = &status_patch(&requests)["restore"];
+    assert_eq!(info["restoredTopics"], json!(1));
+    assert_eq!(info["restoredPartitions"], json!(2));
+    assert_eq!(info["restoredBytes"], json!(1500));
+    assert_eq!(info["pointInTimeTarget"], json!("2026-02-13T01:15:00Z"));
+    assert_eq!(info["actualPointInTime"], json!("2026-02-13T01:10:00Z"));
+
+    let labels = r#"{cluster="dr-cluster",restore_name="dr-restore"}"#;
+    assert!(metrics
+        .gather()
+        .contains(&format!("strimzi_restore_records_total{labels} 15")));
+}
+
+#[tokio::test]
+async fn test_completed_restore_counts_come_from_the_restored_backup() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let mut restore = restore_with_stored_resources(temp_dir.path(), json!({}));
+    restore.spec.backup_ref = serde_json::from_value(json!({"name": "daily-backup"})).unwrap();
+    restore.status = Some(
+        serde_json::from_value(json!({"restore": {
+            "startTime": "2026-02-13T03:00:00Z",
+            "status": "Running",
+            "backupId": "daily-backup-20260213-020000"
+        }}))
+        .unwrap(),
+    );
+    std::fs::write(
+        temp_dir
+            .path()
+            .join("daily-backup-20260213-020000/manifest.json"),
+        serde_json::to_vec(&json!({
+            "backup_id": "daily-backup-20260213-020000",
+            "created_at": 1_770_948_000_000_i64,
+            "topics": [{"name": "orders", "partitions": [
+                {"segments": [{"compressed_size": 1000, "record_count": 10}]}
+            ]}]
+        }))
+        .unwrap(),
+    )
+    .unwrap();
+
+    let (result, requests) = reconcile_with_objects(
+        restore,
+        Vec::new(),
+        vec![local_backup(temp_dir.path())],
+        Vec::new(),
+        vec![finished_restore_job("Complete")],
+        vec![restore_pod(0, "restore finished")],
+        &MetricsState::new(),
+    )
+    .await;
+    result.expect("reconcile should succeed");
+
+    let info = &status_patch(&requests)["restore"];
+    assert_eq!(info["restoredTopics"], json!(1));
+    assert_eq!(info["restoredBytes"], json!(1000));
+}
+
+#[tokio::test]
+async fn test_failed_restore_reports_the_error_class() {
+    let restore = restore_from_storage(json!({
+        "storageRef": {"name": "dr-bucket"},
+        "backupId": "daily-backup-20260213-020000"
+    }));
+
+    let (result, requests) = reconcile_with_objects(
+        restore,
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        vec![finished_restore_job("Failed")],
+        vec![restore_pod(
+            137,
+            r#"{"exitCode":137,"errorClass":"Killed"}"#,
+        )],
+        &MetricsState::new(),
+    )
+    .await;
+    result.expect("reconcile should succeed");
+
+    let conditions = status_patch(&requests)["conditions"].as_array().unwrap();
+    let error = conditions
+        .iter()
+        .find(|c| c["type"] == "Error")
+        .expect("a failed restore must report an Error condition");
+    assert_eq!(
+        error["message"],
+        json!("Restore job dr-restore-20260213-030000 failed: Killed, exit code 137")
+    );
+
+    let event = events_posted(&requests)
+        .next()
+        .expect("a failed restore must be reported as an Event");
+    assert_eq!(event["type"], json!("Warning"));
+    assert_eq!(event["reason"], json!("RestoreFailed"));
+    assert_eq!(event["note"], error["message"]);
+}
+
+#[tokio::test]
+async fn test_completed_restore_without_a_result_is_still_recorded() {
+    let restore = restore_from_storage(json!({
+        "storageRef": {"name": "dr-bucket"},
+        "backupId": "daily-backup-20260213-020000"
+    }));
+
+    let metrics = MetricsState::new();
+
+    let (result, requests) = reconcile_with_objects(
+        restore,
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        vec![finished_restore_job("Complete")],
+        vec![restore_pod(0, "restore finished")],
+        &metrics,
+    )
+    .await;
+    result.expect("reconcile should succeed");
+
+    let info = &status_patch(&requests)["restore"];
+    assert_eq!(info["status"], json!("Completed"));
+    assert!(info.get("restoredBytes").is_none());
+    assert!(info.get("actualPointInTime").is_none());
+    assert!(
+        !metrics.gather().contains("strimzi_restore_records_total{"),
+        "unknown record counts are not exported as zero"
+    );
+}
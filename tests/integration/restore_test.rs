@@ -1,5 +1,5 @@
 use k8s_openapi::api::core::v1::HostAlias;
-use kafka_backup_operator::adapters::restore_config::build_restore_config_yaml;
+use kafka_backup_operator::adapters::restore_config::{build_restore_config_yaml, RestoreSource};
 use kafka_backup_operator::crd::common::*;
 use kafka_backup_operator::crd::kafka_backup::*;
 use kafka_backup_operator::crd::kafka_restore::*;
@@ -20,9 +20,12 @@ fn sample_backup() -> KafkaBackup {
         topics: None,
         connection: None,
         consumer_groups: None,
+        users: None,
+        connect: None,
+        schema_registry: None,
         logging: None,
         env: Vec::new(),
-        storage: StorageSpec {
+        storage: Some(StorageSpec {
             storage_type: StorageType::S3,
             s3: Some(S3StorageSpec {
                 bucket: "my-kafka-backups".to_string(),
@@ -41,7 +44,8 @@ fn sample_backup() -> KafkaBackup {
             azure: None,
             gcs: None,
             filesystem: None,
-        },
+        }),
+        storage_ref: None,
         backup: None,
         metrics: None,
         offset_storage: None,
@@ -57,6 +61,10 @@ fn sample_backup() -> KafkaBackup {
     backup
 }
 
+fn source(restore: &KafkaRestore, backup: &KafkaBackup) -> RestoreSource {
+    RestoreSource::from_backup(restore, backup).unwrap()
+}
+
 fn sample_restore() -> KafkaRestore {
     let spec = KafkaRestoreSpec {
         strimzi_cluster_ref: StrimziClusterRef {
@@ -68,8 +76,12 @@ fn sample_restore() -> KafkaRestore {
         authentication: None,
         topics: None,
         backup_ref: BackupRef {
-            name: "daily-backup".to_string(),
+            kind: None,
+            name: Some("daily-backup".to_string()),
+            namespace: None,
             backup_id: Some("backup-20260213-020000".to_string()),
+            storage: None,
+            storage_ref: None,
         },
         point_in_time: Some(PointInTimeSpec {
             start_timestamp: None,
@@ -100,6 +112,9 @@ fn sample_restore() -> KafkaRestore {
                 target_group: "order-processor".to_string(),
             }],
         }),
+        users: None,
+        connect: None,
+        schema_registry: None,
         restore: Some(RestoreOptionsSpec {
             topic_creation: Some(TopicCreationPolicy::Auto),
             existing_topic_policy: Some(ExistingTopicPolicy::Append),
@@ -117,6 +132,7 @@ fn sample_restore() -> KafkaRestore {
             checkpoint_interval_secs: None,
             default_replication_factor: None,
             repartitioning: Vec::new(),
+            create_cluster: None,
             purge_topics: None,
         }),
         metrics: None,
@@ -164,8 +180,14 @@ fn test_restore_config_generation() {
     let backup = sample_backup();
     let cluster = sample_cluster();
 
-    let yaml =
-        build_restore_config_yaml(&restore, &backup, &cluster, &None, &ResolvedAuth::None).unwrap();
+    let yaml = build_restore_config_yaml(
+        &restore,
+        &source(&restore, &backup),
+        &cluster,
+        &None,
+        &ResolvedAuth::None,
+    )
+    .unwrap();
 
     assert!(yaml.contains("mode: restore"));
     assert!(yaml.contains("backup_id: backup-20260213-020000"));
@@ -195,8 +217,14 @@ fn test_restore_logging_config_generation() {
     let backup = sample_backup();
     let cluster = sample_cluster();
 
-    let yaml =
-        build_restore_config_yaml(&restore, &backup, &cluster, &None, &ResolvedAuth::None).unwrap();
+    let yaml = build_restore_config_yaml(
+        &restore,
+        &source(&restore, &backup),
+        &cluster,
+        &None,
+        &ResolvedAuth::None,
+    )
+    .unwrap();
 
     assert!(yaml.contains("logging:"));
     assert!(yaml.contains("level: debug"));
@@ -215,7 +243,7 @@ fn test_restore_metrics_keep_alive_generation() {
 
     let yaml = build_restore_config_yaml(
         &restore,
-        &sample_backup(),
+        &source(&restore, &sample_backup()),
         &sample_cluster(),
         &None,
         &ResolvedAuth::None,
@@ -242,7 +270,7 @@ fn test_restore_job_creation() {
         "pitr-restore-config",
         &cluster,
         &ResolvedAuth::None,
-        &backup,
+        &source(&restore, &backup),
         Some("strimzi-backup-operator"),
     )
     .unwrap();
@@ -319,7 +347,7 @@ fn test_restore_job_applies_template_host_aliases() {
         "pitr-restore-config",
         &cluster,
         &ResolvedAuth::None,
-        &backup,
+        &source(&restore, &backup),
         Some("strimzi-backup-operator"),
     )
     .unwrap();
@@ -349,8 +377,14 @@ fn test_restore_with_offset_from_end() {
     let backup = sample_backup();
     let cluster = sample_cluster();
 
-    let err = build_restore_config_yaml(&restore, &backup, &cluster, &None, &ResolvedAuth::None)
-        .unwrap_err();
+    let err = build_restore_config_yaml(
+        &restore,
+        &source(&restore, &backup),
+        &cluster,
+        &None,
+        &ResolvedAuth::None,
+    )
+    .unwrap_err();
 
     assert!(err
         .to_string()
@@ -365,8 +399,14 @@ fn test_restore_with_unsupported_existing_topic_fail_policy() {
     let backup = sample_backup();
     let cluster = sample_cluster();
 
-    let err = build_restore_config_yaml(&restore, &backup, &cluster, &None, &ResolvedAuth::None)
-        .unwrap_err();
+    let err = build_restore_config_yaml(
+        &restore,
+        &source(&restore, &backup),
+        &cluster,
+        &None,
+        &ResolvedAuth::None,
+    )
+    .unwrap_err();
 
     assert!(err
         .to_string()
@@ -384,8 +424,14 @@ fn test_restore_config_topic_selection() {
     let backup = sample_backup();
     let cluster = sample_cluster();
 
-    let yaml =
-        build_restore_config_yaml(&restore, &backup, &cluster, &None, &ResolvedAuth::None).unwrap();
+    let yaml = build_restore_config_yaml(
+        &restore,
+        &source(&restore, &backup),
+        &cluster,
+        &None,
+        &ResolvedAuth::None,
+    )
+    .unwrap();
 
     let config: serde_yaml::Value = serde_yaml::from_str(&yaml).unwrap();
     let topics = &config["target"]["topics"];
@@ -408,7 +454,9 @@ fn test_restore_scram_auth_emits_binary_compatible_sasl_mechanism() {
         password_key: "password".to_string(),
     };
 
-    let yaml = build_restore_config_yaml(&restore, &backup, &cluster, &None, &auth).unwrap();
+    let yaml =
+        build_restore_config_yaml(&restore, &source(&restore, &backup), &cluster, &None, &auth)
+            .unwrap();
 
     let config: serde_yaml::Value = serde_yaml::from_str(&yaml).unwrap();
     assert_eq!(
@@ -427,8 +475,14 @@ fn test_restore_config_omits_topic_selection_by_default() {
     let backup = sample_backup();
     let cluster = sample_cluster();
 
-    let yaml =
-        build_restore_config_yaml(&restore, &backup, &cluster, &None, &ResolvedAuth::None).unwrap();
+    let yaml = build_restore_config_yaml(
+        &restore,
+        &source(&restore, &backup),
+        &cluster,
+        &None,
+        &ResolvedAuth::None,
+    )
+    .unwrap();
 
     let config: serde_yaml::Value = serde_yaml::from_str(&yaml).unwrap();
     assert!(config["target"].get("topics").is_none());
@@ -453,7 +507,7 @@ fn test_restore_job_applies_template_service_account() {
         "pitr-restore-config",
         &cluster,
         &ResolvedAuth::None,
-        &backup,
+        &source(&restore, &backup),
         Some("strimzi-backup-operator"),
     )
     .unwrap();
@@ -477,7 +531,7 @@ fn test_restore_job_default_backoff_limit_is_zero() {
         "pitr-restore-config",
         &sample_cluster(),
         &ResolvedAuth::None,
-        &sample_backup(),
+        &source(&restore, &sample_backup()),
         None,
     )
     .unwrap();
@@ -495,10 +549,54 @@ fn test_restore_job_custom_backoff_limit() {
         "pitr-restore-config",
         &sample_cluster(),
         &ResolvedAuth::None,
-        &sample_backup(),
+        &source(&restore, &sample_backup()),
         None,
     )
     .unwrap();
 
     assert_eq!(job.spec.as_ref().unwrap().backoff_limit, Some(2));
 }
+
+#[test]
+fn test_restore_with_window_start_after_end() {
+    let mut restore = sample_restore();
+    restore.spec.point_in_time = Some(PointInTimeSpec {
+        start_timestamp: Some("2026-02-13T02:00:00Z".to_string()),
+        timestamp: Some("2026-02-13T01:30:00Z".to_string()),
+        offset_from_end: None,
+    });
+
+    let backup = sample_backup();
+    let err = build_restore_config_yaml(
+        &restore,
+        &source(&restore, &backup),
+        &sample_cluster(),
+        &None,
+        &ResolvedAuth::None,
+    )
+    .unwrap_err();
+
+    assert!(err
+        .to_string()
+        .contains("pointInTime.startTimestamp must be before pointInTime.timestamp"));
+}
+
+#[test]
+fn test_restore_with_duplicate_mapping_targets() {
+    let mut restore = sample_restore();
+    restore.spec.topic_mapping[1].target_topic = "orders-restored".to_string();
+
+    let backup = sample_backup();
+    let err = build_restore_config_yaml(
+        &restore,
+        &source(&restore, &backup),
+        &sample_cluster(),
+        &None,
+        &ResolvedAuth::None,
+    )
+    .unwrap_err();
+
+    assert!(err
+        .to_string()
+        .contains("same targetTopic 'orders-restored'"));
+}
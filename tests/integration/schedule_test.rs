@@ -0,0 +1,355 @@
+use std::sync::{Arc, Mutex};
+
+use http::{Request, Response};
+use http_body_util::BodyExt;
+use kafka_backup_operator::crd::kafka_backup::ScheduleSpec;
+use kafka_backup_operator::crd::{
+    KafkaBackupSchedule, KafkaBackupScheduleSpec, KafkaBackupTemplate,
+};
+use kafka_backup_operator::error::Result;
+use kafka_backup_operator::metrics::prometheus::MetricsState;
+use kafka_backup_operator::reconcilers::schedule::{
+    build_scheduled_backup, reconcile_schedule, scheduled_backup_name,
+};
+use kube::client::Body;
+use kube::{Client, ResourceExt};
+use serde_json::json;
+use tower_test::mock;
+
+#[derive(Debug)]
+struct RecordedRequest {
+    method: String,
+    path: String,
+    query: Option<String>,
+    body: serde_json::Value,
+}
+
+fn nightly_schedule() -> KafkaBackupSchedule {
+    let spec = KafkaBackupScheduleSpec {
+        cluster_selector: serde_json::from_value(json!({
+            "matchLabels": {"backup-tier": "gold"}
+        }))
+        .unwrap(),
+        backup_template: KafkaBackupTemplate {
+            metadata: None,
+            spec: json!({
+                "strimziClusterRef": {"listener": "tls"},
+                "storage": {"type": "filesystem", "filesystem": {"path": "/backups"}},
+                "schedule": {"cron": "0 4 * * *"}
+            }),
+        },
+        schedule: Some(ScheduleSpec {
+            cron: "0 2 * * *".to_string(),
+            timezone: None,
+            suspend: false,
+            successful_jobs_history_limit: None,
+            failed_jobs_history_limit: None,
+        }),
+    };
+    let mut schedule = KafkaBackupSchedule::new("nightly", spec);
+    schedule.metadata.namespace = Some("kafka".to_string());
+    schedule.metadata.uid = Some("schedule-uid".to_string());
+    schedule.metadata.generation = Some(1);
+    schedule
+}
+
+fn kafka(name: &str) -> serde_json::Value {
+    json!({
+        "apiVersion": "kafka.strimzi.io/v1beta2",
+        "kind": "Kafka",
+        "metadata": {"name": name, "namespace": "kafka", "labels": {"backup-tier": "gold"}},
+        "spec": {}
+    })
+}
+
+fn generated_backup(name: &str, ready: bool) -> serde_json::Value {
+    json!({
+        "apiVersion": "kafkabackup.com/v1alpha1",
+        "kind": "KafkaBackup",
+        "metadata": {
+            "name": name,
+            "namespace": "kafka",
+            "labels": {"kafkabackup.com/schedule": "nightly"},
+            "ownerReferences": [{
+                "apiVersion": "kafkabackup.com/v1alpha1",
+                "kind": "KafkaBackupSchedule",
+                "name": "nightly",
+                "uid": "schedule-uid",
+                "controller": true
+            }]
+        },
+        "spec": {
+            "strimziClusterRef": {"name": "unused"},
+            "storage": {"type": "filesystem", "filesystem": {"path": "/backups"}}
+        },
+        "status": {
+            "conditions": [{
+                "type": "Ready",
+                "status": if ready { "True" } else { "False" },
+                "reason": if ready { "BackupScheduled" } else { "ClusterNotFound" }
+            }]
+        }
+    })
+}
+
+/// Existing generated backups for `alpha` and a no-longer-matching `gamma`
+fn existing_backups() -> Vec<serde_json::Value> {
+    vec![
+        generated_backup("nightly-alpha", true),
+        generated_backup("nightly-gamma", true),
+    ]
+}
+
+/// Run `reconcile_schedule` against a mock API server that serves clusters
+/// `alpha` and `beta` and the KafkaBackups in `existing`.
+async fn reconcile_with_mock_api(
+    schedule: KafkaBackupSchedule,
+    existing: Vec<serde_json::Value>,
+) -> (Result<()>, Vec<RecordedRequest>) {
+    let (mock_service, mut handle) = mock::pair::<Request<Body>, Response<Body>>();
+    let recorded = Arc::new(Mutex::new(Vec::new()));
+
+    let schedule_json = serde_json::to_value(&schedule).unwrap();
+    let dispatcher = {
+        let recorded = Arc::clone(&recorded);
+        tokio::spawn(async move {
+            while let Some((request, send)) = handle.next_request().await {
+                let method = request.method().to_string();
+                let path = request.uri().path().to_string();
+                let query = request.uri().query().map(str::to_string);
+                let bytes = request.into_body().collect().await.unwrap().to_bytes();
+                let body: serde_json::Value = if bytes.is_empty() {
+                    serde_json::Value::Null
+                } else {
+                    serde_json::from_slice(&bytes).unwrap()
+                };
+
+                let name = path.rsplit('/').next().unwrap_or_default();
+                let existing_backup = existing
+                    .iter()
+                    .find(|backup| backup["metadata"]["name"] == name);
+                let (status, response_body) = if method == "GET" && path.ends_with("/kafkas") {
+                    (
+                        200,
+                        json!({
+                            "apiVersion": "kafka.strimzi.io/v1beta2",
+                            "kind": "KafkaList",
+                            "metadata": {},
+                            "items": [kafka("beta"), kafka("alpha")]
+                        }),
+                    )
+                } else if method == "GET" && path.ends_with("/kafkabackups") {
+                    let children: Vec<&serde_json::Value> = existing
+                        .iter()
+                        .filter(|backup| {
+                            backup["metadata"]["labels"]["kafkabackup.com/schedule"] == "nightly"
+                        })
+                        .collect();
+                    (
+                        200,
+                        json!({
+                            "apiVersion": "kafkabackup.com/v1alpha1",
+                            "kind": "KafkaBackupList",
+                            "metadata": {},
+                            "items": children
+                        }),
+                    )
+                } else if method == "GET" && path.contains("/kafkabackups/") {
+                    match existing_backup {
+                        Some(backup) => (200, backup.clone()),
+                        None => (
+                            404,
+                            json!({
+                                "kind": "Status", "apiVersion": "v1", "metadata": {},
+                                "status": "Failure", "message": "not found",
+                                "reason": "NotFound", "code": 404
+                            }),
+                        ),
+                    }
+                } else if path.contains("/kafkabackupschedules/") {
+                    (200, schedule_json.clone())
+                } else if method == "DELETE" {
+                    (
+                        200,
+                        json!({"kind": "Status", "apiVersion": "v1", "metadata": {}, "status": "Success"}),
+                    )
+                } else {
+                    // Server-side apply of a KafkaBackup: echo the object back
+                    (200, body.clone())
+                };
+
+                recorded.lock().unwrap().push(RecordedRequest {
+                    method,
+                    path,
+                    query,
+                    body,
+                });
+
+                let response = Response::builder()
+                    .status(status)
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&response_body).unwrap()))
+                    .unwrap();
+                send.send_response(response);
+            }
+        })
+    };
+
+    let client = Client::new(mock_service, "kafka");
+    let metrics = MetricsState::new();
+    let result = reconcile_schedule(Arc::new(schedule), client, &metrics).await;
+
+    dispatcher.await.unwrap();
+    (
+        result,
+        Arc::try_unwrap(recorded).unwrap().into_inner().unwrap(),
+    )
+}
+
+#[test]
+fn test_build_scheduled_backup_targets_matched_cluster() {
+    let backup = build_scheduled_backup(&nightly_schedule(), "alpha").unwrap();
+
+    assert_eq!(backup.metadata.name.as_deref(), Some("nightly-alpha"));
+    assert_eq!(backup.metadata.namespace.as_deref(), Some("kafka"));
+    assert_eq!(backup.spec.strimzi_cluster_ref.name, "alpha");
+    assert_eq!(
+        backup.spec.strimzi_cluster_ref.namespace.as_deref(),
+        Some("kafka")
+    );
+    // Template fields other than name/namespace are kept
+    assert_eq!(
+        backup.spec.strimzi_cluster_ref.listener.as_deref(),
+        Some("tls")
+    );
+    // The schedule-level cron overrides the template's
+    assert_eq!(backup.spec.schedule.unwrap().cron, "0 2 * * *");
+
+    let labels = backup.metadata.labels.unwrap();
+    assert_eq!(labels["kafkabackup.com/schedule"], "nightly");
+    assert_eq!(labels["strimzi.io/cluster"], "alpha");
+
+    let owner = &backup.metadata.owner_references.unwrap()[0];
+    assert_eq!(owner.kind, "KafkaBackupSchedule");
+    assert_eq!(owner.uid, "schedule-uid");
+    assert_eq!(owner.controller, Some(true));
+}
+
+#[test]
+fn test_long_scheduled_backup_names_are_shortened_with_a_hash() {
+    let mut schedule = nightly_schedule();
+    schedule.metadata.name = Some(format!("nightly-{}", "x".repeat(60)));
+
+    let alpha = build_scheduled_backup(&schedule, "alpha").unwrap();
+    let beta = build_scheduled_backup(&schedule, "beta").unwrap();
+
+    let (alpha_name, beta_name) = (alpha.name_any(), beta.name_any());
+    for name in [&alpha_name, &beta_name] {
+        assert!(
+            name.len() <= 42,
+            "{name} leaves no room for its CronJob name"
+        );
+        assert!(name.starts_with("nightly-xxx"));
+        assert!(name.ends_with(|c: char| c.is_ascii_alphanumeric()));
+    }
+    assert_ne!(alpha_name, beta_name);
+    assert_eq!(
+        alpha_name,
+        scheduled_backup_name(&schedule.name_any(), "alpha")
+    );
+
+    let label = &alpha.metadata.labels.unwrap()["kafkabackup.com/schedule"];
+    assert!(label.len() <= 63);
+    assert!(label.starts_with("nightly-xxx"));
+}
+
+#[test]
+fn test_build_scheduled_backup_rejects_invalid_template() {
+    let mut schedule = nightly_schedule();
+    schedule.spec.backup_template.spec = json!({"storage": {"type": "tape"}});
+
+    let err = build_scheduled_backup(&schedule, "alpha").unwrap_err();
+    assert_eq!(err.reason(), "InvalidConfiguration");
+}
+
+#[tokio::test]
+async fn test_reconcile_schedule_applies_prunes_and_aggregates() {
+    let (result, requests) = reconcile_with_mock_api(nightly_schedule(), existing_backups()).await;
+    result.expect("reconcile should succeed");
+
+    let list = requests
+        .iter()
+        .find(|r| r.method == "GET" && r.path.ends_with("/kafkas"))
+        .expect("reconcile must list Kafka clusters");
+    assert!(list
+        .query
+        .as_deref()
+        .is_some_and(|q| q.contains("labelSelector=backup-tier%3Dgold")));
+
+    let applied: Vec<&str> = requests
+        .iter()
+        .filter(|r| r.method == "PATCH" && r.path.contains("/kafkabackups/"))
+        .map(|r| r.path.rsplit('/').next().unwrap())
+        .collect();
+    assert_eq!(applied, vec!["nightly-alpha", "nightly-beta"]);
+
+    let deleted: Vec<&str> = requests
+        .iter()
+        .filter(|r| r.method == "DELETE")
+        .map(|r| r.path.as_str())
+        .collect();
+    assert_eq!(
+        deleted,
+        vec!["/apis/kafkabackup.com/v1alpha1/namespaces/kafka/kafkabackups/nightly-gamma"]
+    );
+
+    let status_patch = requests
+        .iter()
+        .find(|r| r.method == "PATCH" && r.path.ends_with("/kafkabackupschedules/nightly/status"))
+        .expect("reconcile must update the schedule status");
+    let status = &status_patch.body["status"];
+    assert_eq!(status["readyClusters"], json!("1/2"));
+    assert_eq!(status["conditions"][0]["status"], json!("False"));
+    assert_eq!(status["conditions"][0]["reason"], json!("ClustersNotReady"));
+    assert_eq!(status["clusters"][0]["cluster"], json!("alpha"));
+    assert_eq!(status["clusters"][0]["ready"], json!(true));
+    assert_eq!(status["clusters"][1]["backupName"], json!("nightly-beta"));
+    assert_eq!(status["clusters"][1]["ready"], json!(false));
+}
+
+#[tokio::test]
+async fn test_reconcile_schedule_does_not_take_over_a_backup_it_does_not_control() {
+    // A hand-written KafkaBackup that happens to share the generated name
+    let mut hand_written = generated_backup("nightly-beta", true);
+    hand_written["metadata"]
+        .as_object_mut()
+        .unwrap()
+        .remove("ownerReferences");
+    hand_written["metadata"]["labels"] = json!({});
+    let mut existing = existing_backups();
+    existing.push(hand_written);
+
+    let (result, requests) = reconcile_with_mock_api(nightly_schedule(), existing).await;
+    let err = result.expect_err("the conflict must be reported");
+    assert_eq!(err.reason(), "InvalidConfiguration");
+
+    let applied: Vec<&str> = requests
+        .iter()
+        .filter(|r| r.method == "PATCH" && r.path.contains("/kafkabackups/"))
+        .map(|r| r.path.rsplit('/').next().unwrap())
+        .collect();
+    assert_eq!(applied, vec!["nightly-alpha"]);
+
+    let status_patch = requests
+        .iter()
+        .find(|r| r.method == "PATCH" && r.path.ends_with("/kafkabackupschedules/nightly/status"))
+        .expect("the conflict must be recorded in the schedule status");
+    let condition = &status_patch.body["status"]["conditions"][0];
+    assert_eq!(condition["type"], json!("Ready"));
+    assert_eq!(condition["status"], json!("False"));
+    assert_eq!(condition["reason"], json!("InvalidConfiguration"));
+    assert!(condition["message"]
+        .as_str()
+        .unwrap()
+        .contains("nightly-beta"));
+}
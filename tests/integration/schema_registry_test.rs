@@ -0,0 +1,324 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::{Method, StatusCode, Uri};
+use axum::response::IntoResponse;
+use axum::Json;
+use kafka_backup_operator::adapters::schema_registry::{
+    mapped_subject, SchemaRegistryClient, SubjectExport,
+};
+use kafka_backup_operator::crd::common::SubjectSelection;
+use kafka_backup_operator::crd::kafka_restore::TopicMappingEntry;
+use serde_json::{json, Value};
+
+#[derive(Clone, Debug)]
+struct RegisteredSchema {
+    version: i32,
+    id: i64,
+    schema: String,
+    schema_type: Option<String>,
+}
+
+/// In-memory stand-in for the Schema Registry REST API
+#[derive(Debug, Default)]
+struct FakeRegistry {
+    mode: String,
+    subjects: BTreeMap<String, Vec<RegisteredSchema>>,
+    subject_compatibility: BTreeMap<String, String>,
+    subject_modes: BTreeMap<String, String>,
+    next_id: i64,
+    writes: Vec<(String, String, Value)>,
+}
+
+impl FakeRegistry {
+    fn with_mode(mode: &str) -> Self {
+        Self {
+            mode: mode.to_string(),
+            next_id: 100,
+            ..Default::default()
+        }
+    }
+
+    fn register(&mut self, subject: &str, schema: &str, schema_type: Option<&str>) {
+        let versions = self.subjects.entry(subject.to_string()).or_default();
+        self.next_id += 1;
+        versions.push(RegisteredSchema {
+            version: versions.len() as i32 + 1,
+            id: self.next_id,
+            schema: schema.to_string(),
+            schema_type: schema_type.map(str::to_string),
+        });
+    }
+
+    fn handle(&mut self, method: &Method, uri: &Uri, body: Value) -> (StatusCode, Value) {
+        let segments: Vec<&str> = uri.path().trim_start_matches('/').split('/').collect();
+        let default_to_global = uri
+            .query()
+            .is_some_and(|q| q.contains("defaultToGlobal=true"));
+        if *method != Method::GET {
+            self.writes
+                .push((method.to_string(), uri.path().to_string(), body.clone()));
+        }
+        let not_found = (StatusCode::NOT_FOUND, json!({"error_code": 40401}));
+
+        match (method.as_str(), segments.as_slice()) {
+            ("GET", ["subjects"]) => (
+                StatusCode::OK,
+                json!(self.subjects.keys().collect::<Vec<_>>()),
+            ),
+            ("GET", ["subjects", subject, "versions"]) => match self.subjects.get(*subject) {
+                Some(versions) => (
+                    StatusCode::OK,
+                    json!(versions.iter().map(|v| v.version).collect::<Vec<_>>()),
+                ),
+                None => not_found,
+            },
+            ("GET", ["subjects", subject, "versions", version]) => {
+                let found = self.subjects.get(*subject).and_then(|versions| {
+                    versions.iter().find(|v| v.version.to_string() == *version)
+                });
+                match found {
+                    Some(v) => {
+                        let mut body = json!({
+                            "subject": subject, "version": v.version, "id": v.id, "schema": v.schema
+                        });
+                        if let Some(schema_type) = &v.schema_type {
+                            body["schemaType"] = json!(schema_type);
+                        }
+                        (StatusCode::OK, body)
+                    }
+                    None => not_found,
+                }
+            }
+            ("POST", ["subjects", subject]) => {
+                let found = self.subjects.get(*subject).and_then(|versions| {
+                    versions.iter().find(|v| json!(v.schema) == body["schema"])
+                });
+                match found {
+                    Some(v) => (
+                        StatusCode::OK,
+                        json!({"subject": subject, "id": v.id, "version": v.version}),
+                    ),
+                    None => not_found,
+                }
+            }
+            ("POST", ["subjects", subject, "versions"]) => {
+                let schema = body["schema"].as_str().unwrap().to_string();
+                let schema_type = body["schemaType"].as_str().map(str::to_string);
+                let Some(id) = body["id"].as_i64() else {
+                    self.register(subject, &schema, schema_type.as_deref());
+                    return (StatusCode::OK, json!({"id": self.next_id}));
+                };
+                let mode = self.subject_modes.get(*subject).unwrap_or(&self.mode);
+                if mode != "IMPORT" {
+                    return (
+                        StatusCode::UNPROCESSABLE_ENTITY,
+                        json!({"error_code": 42205, "message": "Subject is not in import mode"}),
+                    );
+                }
+                self.subjects
+                    .entry(subject.to_string())
+                    .or_default()
+                    .push(RegisteredSchema {
+                        version: body["version"].as_i64().unwrap() as i32,
+                        id,
+                        schema,
+                        schema_type,
+                    });
+                (StatusCode::OK, json!({"id": id}))
+            }
+            ("GET", ["config"]) => (StatusCode::OK, json!({"compatibilityLevel": "BACKWARD"})),
+            ("GET", ["config", subject]) => match self.subject_compatibility.get(*subject) {
+                Some(level) => (StatusCode::OK, json!({"compatibilityLevel": level})),
+                None => (StatusCode::NOT_FOUND, json!({"error_code": 40408})),
+            },
+            ("PUT", ["config", subject]) => {
+                let level = body["compatibility"].as_str().unwrap().to_string();
+                self.subject_compatibility
+                    .insert(subject.to_string(), level.clone());
+                (StatusCode::OK, json!({"compatibility": level}))
+            }
+            ("GET", ["mode"]) => (StatusCode::OK, json!({"mode": self.mode})),
+            ("GET", ["mode", subject]) => match self.subject_modes.get(*subject) {
+                Some(mode) => (StatusCode::OK, json!({"mode": mode})),
+                None if default_to_global => (StatusCode::OK, json!({"mode": self.mode})),
+                None => (StatusCode::NOT_FOUND, json!({"error_code": 40409})),
+            },
+            ("PUT", ["mode", subject]) => {
+                let mode = body["mode"].as_str().unwrap().to_string();
+                self.subject_modes.insert(subject.to_string(), mode.clone());
+                (StatusCode::OK, json!({"mode": mode}))
+            }
+            _ => not_found,
+        }
+    }
+}
+
+/// Serve `registry` on a local port and return its URL
+async fn serve(registry: Arc<Mutex<FakeRegistry>>) -> String {
+    async fn handler(
+        State(registry): State<Arc<Mutex<FakeRegistry>>>,
+        method: Method,
+        uri: Uri,
+        body: Bytes,
+    ) -> impl IntoResponse {
+        let body = if body.is_empty() {
+            Value::Null
+        } else {
+            serde_json::from_slice(&body).unwrap()
+        };
+        let (status, response) = registry.lock().unwrap().handle(&method, &uri, body);
+        (status, Json(response))
+    }
+
+    let app = axum::Router::new().fallback(handler).with_state(registry);
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    format!("http://{address}")
+}
+
+const ORDER_V1: &str =
+    r#"{"type":"record","name":"Order","fields":[{"name":"id","type":"string"}]}"#;
+const ORDER_V2: &str = r#"{"type":"record","name":"Order","fields":[{"name":"id","type":"string"},{"name":"note","type":["null","string"],"default":null}]}"#;
+
+fn source_registry() -> FakeRegistry {
+    let mut registry = FakeRegistry::with_mode("READWRITE");
+    registry.register("orders-value", ORDER_V1, None);
+    registry.register("orders-value", ORDER_V2, None);
+    registry.register(
+        "payments-value",
+        "syntax = \"proto3\"; message Payment {}",
+        Some("PROTOBUF"),
+    );
+    registry.register("internal-audit", r#""string""#, None);
+    registry
+        .subject_compatibility
+        .insert("orders-value".to_string(), "FULL".to_string());
+    registry
+}
+
+/// The `orders-value` subject exported from [`source_registry`]
+async fn exported_orders() -> SubjectExport {
+    let url = serve(Arc::new(Mutex::new(source_registry()))).await;
+    let selection = SubjectSelection {
+        include: vec!["orders-value".to_string()],
+        exclude: Vec::new(),
+    };
+    let mut export = SchemaRegistryClient::new(&url, None)
+        .unwrap()
+        .export(Some(&selection))
+        .await
+        .unwrap();
+    export.subjects.remove(0)
+}
+
+#[tokio::test]
+async fn test_export_reads_subjects_versions_and_settings() {
+    let url = serve(Arc::new(Mutex::new(source_registry()))).await;
+    let selection = SubjectSelection {
+        include: Vec::new(),
+        exclude: vec!["internal-*".to_string()],
+    };
+
+    let export = SchemaRegistryClient::new(&url, None)
+        .unwrap()
+        .export(Some(&selection))
+        .await
+        .unwrap();
+
+    assert_eq!(export.compatibility.as_deref(), Some("BACKWARD"));
+    assert_eq!(export.mode.as_deref(), Some("READWRITE"));
+    let subjects: Vec<&str> = export.subjects.iter().map(|s| s.subject.as_str()).collect();
+    assert_eq!(subjects, vec!["orders-value", "payments-value"]);
+
+    let orders = &export.subjects[0];
+    assert_eq!(orders.compatibility.as_deref(), Some("FULL"));
+    assert_eq!(
+        orders.mode, None,
+        "subjects without their own mode record none"
+    );
+    let versions: Vec<(i32, i64)> = orders.versions.iter().map(|v| (v.version, v.id)).collect();
+    assert_eq!(versions, vec![(1, 101), (2, 102)]);
+    assert_eq!(orders.versions[1].schema, ORDER_V2);
+    assert_eq!(
+        export.subjects[1].versions[0].schema_type.as_deref(),
+        Some("PROTOBUF")
+    );
+}
+
+#[tokio::test]
+async fn test_import_preserves_ids_in_import_mode() {
+    let subject = exported_orders().await;
+    let target = Arc::new(Mutex::new(FakeRegistry::with_mode("IMPORT")));
+    let url = serve(Arc::clone(&target)).await;
+    let client = SchemaRegistryClient::new(&url, None).unwrap();
+
+    let registered = client
+        .import_subject(&subject, "orders-value")
+        .await
+        .unwrap();
+    assert_eq!(registered, 2);
+
+    let target = target.lock().unwrap();
+    let versions: Vec<(i32, i64)> = target.subjects["orders-value"]
+        .iter()
+        .map(|v| (v.version, v.id))
+        .collect();
+    assert_eq!(versions, vec![(1, 101), (2, 102)]);
+    assert_eq!(target.subject_compatibility["orders-value"], "FULL");
+}
+
+#[tokio::test]
+async fn test_import_without_import_mode_registers_new_ids_once() {
+    let subject = exported_orders().await;
+    let target = Arc::new(Mutex::new(FakeRegistry::with_mode("READWRITE")));
+    let url = serve(Arc::clone(&target)).await;
+    let client = SchemaRegistryClient::new(&url, None).unwrap();
+
+    assert_eq!(
+        client
+            .import_subject(&subject, "orders-dr-value")
+            .await
+            .unwrap(),
+        2
+    );
+    assert_eq!(
+        client
+            .import_subject(&subject, "orders-dr-value")
+            .await
+            .unwrap(),
+        0,
+        "versions already registered are skipped"
+    );
+
+    let target = target.lock().unwrap();
+    let registered = &target.subjects["orders-dr-value"];
+    assert_eq!(registered.len(), 2);
+    assert_eq!(registered[0].schema, ORDER_V1);
+    let registrations: Vec<&Value> = target
+        .writes
+        .iter()
+        .filter(|(method, path, _)| method == "POST" && path.ends_with("/versions"))
+        .map(|(_, _, body)| body)
+        .collect();
+    assert_eq!(registrations.len(), 2);
+    assert!(registrations.iter().all(|body| body.get("id").is_none()));
+}
+
+#[test]
+fn test_mapped_subject_follows_topic_mapping() {
+    let mapping = vec![TopicMappingEntry {
+        source_topic: "orders".to_string(),
+        target_topic: "orders-dr".to_string(),
+    }];
+    assert_eq!(mapped_subject("orders-value", &mapping), "orders-dr-value");
+    assert_eq!(mapped_subject("orders-key", &mapping), "orders-dr-key");
+    assert_eq!(mapped_subject("payments-value", &mapping), "payments-value");
+    assert_eq!(
+        mapped_subject("com.example.Order", &mapping),
+        "com.example.Order"
+    );
+}
@@ -0,0 +1,447 @@
+use std::sync::{Arc, Mutex};
+
+use http::{Request, Response};
+use http_body_util::BodyExt;
+use kafka_backup_operator::crd::common::{BackupHistoryEntry, BackupStatus};
+use kafka_backup_operator::crd::{KafkaBackup, KafkaBackupSnapshot};
+use kafka_backup_operator::metrics::prometheus::MetricsState;
+use kafka_backup_operator::reconcilers::snapshot::{
+    build_backup_snapshot, reconcile_snapshot, snapshot_name, sync_backup_snapshots,
+};
+use kube::client::Body;
+use kube::Client;
+use serde_json::json;
+use tower_test::mock;
+
+#[derive(Debug)]
+struct RecordedRequest {
+    method: String,
+    path: String,
+    body: serde_json::Value,
+}
+
+fn daily_backup(storage_path: &str) -> KafkaBackup {
+    serde_json::from_value(json!({
+        "apiVersion": "kafkabackup.com/v1alpha1",
+        "kind": "KafkaBackup",
+        "metadata": {"name": "daily-backup", "namespace": "kafka", "uid": "backup-uid"},
+        "spec": {
+            "strimziClusterRef": {"name": "production-cluster"},
+            "storage": {"type": "filesystem", "filesystem": {"path": storage_path}}
+        }
+    }))
+    .unwrap()
+}
+
+fn deleted_snapshot(backup_id: &str) -> KafkaBackupSnapshot {
+    serde_json::from_value(json!({
+        "apiVersion": "kafkabackup.com/v1alpha1",
+        "kind": "KafkaBackupSnapshot",
+        "metadata": {
+            "name": backup_id,
+            "namespace": "kafka",
+            "deletionTimestamp": "2026-05-01T00:00:00Z",
+            "finalizers": ["kafkabackup.com/cleanup"]
+        },
+        "spec": {"backupName": "daily-backup", "backupId": backup_id}
+    }))
+    .unwrap()
+}
+
+fn write_manifest(root: &std::path::Path, backup_id: &str, created_at: i64) {
+    let dir = root.join(backup_id);
+    std::fs::create_dir_all(dir.join("topics/orders/partition=0")).unwrap();
+    std::fs::write(
+        dir.join("manifest.json"),
+        json!({
+            "backup_id": backup_id,
+            "created_at": created_at,
+            "topics": [{"partitions": [{"segments": [{"compressed_size": 42}]}]}]
+        })
+        .to_string(),
+    )
+    .unwrap();
+    std::fs::write(
+        dir.join("topics/orders/partition=0/segment-000.bin.zst"),
+        b"segment",
+    )
+    .unwrap();
+}
+
+/// Serve requests from `respond` and record every request the operator makes.
+fn mock_client(
+    respond: impl Fn(&str, &str, &serde_json::Value) -> (u16, serde_json::Value) + Send + 'static,
+) -> (
+    Client,
+    Arc<Mutex<Vec<RecordedRequest>>>,
+    tokio::task::JoinHandle<()>,
+) {
+    let (mock_service, mut handle) = mock::pair::<Request<Body>, Response<Body>>();
+    let recorded = Arc::new(Mutex::new(Vec::new()));
+
+    let dispatcher = {
+        let recorded = Arc::clone(&recorded);
+        tokio::spawn(async move {
+            while let Some((request, send)) = handle.next_request().await {
+                let method = request.method().to_string();
+                let path = request.uri().path().to_string();
+                let bytes = request.into_body().collect().await.unwrap().to_bytes();
+                let body: serde_json::Value = if bytes.is_empty() {
+                    serde_json::Value::Null
+                } else {
+                    serde_json::from_slice(&bytes).unwrap()
+                };
+
+                let (status, response_body) = respond(&method, &path, &body);
+                recorded
+                    .lock()
+                    .unwrap()
+                    .push(RecordedRequest { method, path, body });
+
+                let response = Response::builder()
+                    .status(status)
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&response_body).unwrap()))
+                    .unwrap();
+                send.send_response(response);
+            }
+        })
+    };
+
+    (Client::new(mock_service, "kafka"), recorded, dispatcher)
+}
+
+fn not_found() -> (u16, serde_json::Value) {
+    (
+        404,
+        json!({
+            "kind": "Status", "apiVersion": "v1", "metadata": {},
+            "status": "Failure", "message": "not found",
+            "reason": "NotFound", "code": 404
+        }),
+    )
+}
+
+#[test]
+fn test_snapshot_name_is_a_valid_object_name() {
+    let name = snapshot_name("daily-backup", "daily-backup-20260213-020000").unwrap();
+    assert!(name.starts_with("daily-backup-20260213-020000-"));
+    assert_eq!(name.len(), "daily-backup-20260213-020000".len() + 9);
+
+    let name = snapshot_name("daily-backup", "Daily_Backup/1").unwrap();
+    assert!(name.starts_with("daily-backup-1-"));
+    assert!(name
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-'));
+
+    let long = snapshot_name("daily-backup", &"a".repeat(400)).unwrap();
+    assert!(long.len() <= 253);
+}
+
+#[test]
+fn test_snapshot_names_do_not_collide() {
+    // IDs that sanitize alike, and the same ID under another backup, get
+    // their own snapshots.
+    assert_ne!(
+        snapshot_name("daily-backup", "Daily_Backup/1").unwrap(),
+        snapshot_name("daily-backup", "daily-backup-1").unwrap()
+    );
+    assert_ne!(
+        snapshot_name("daily-backup", "backup-1").unwrap(),
+        snapshot_name("weekly-backup", "backup-1").unwrap()
+    );
+    assert_eq!(
+        snapshot_name("daily-backup", "backup-1").unwrap(),
+        snapshot_name("daily-backup", "backup-1").unwrap()
+    );
+}
+
+#[test]
+fn test_snapshot_name_rejects_ids_without_usable_characters() {
+    assert!(snapshot_name("daily-backup", "__/__").is_err());
+}
+
+#[test]
+fn test_build_backup_snapshot_records_manifest_details() {
+    let backup = daily_backup("/backups");
+    let entry = BackupHistoryEntry {
+        id: "daily-backup-20260213-020000".to_string(),
+        status: BackupStatus::Completed,
+        start_time: "2026-02-13T02:00:00Z".parse().unwrap(),
+        completion_time: Some("2026-02-13T02:05:00Z".parse().unwrap()),
+        size_bytes: Some(1024),
+        topics_backed_up: Some(3),
+        partitions_backed_up: Some(9),
+        oldest_timestamp: None,
+        newest_timestamp: None,
+    };
+
+    let snapshot = build_backup_snapshot(&backup, &entry).unwrap();
+
+    assert_eq!(
+        snapshot.metadata.name.unwrap(),
+        snapshot_name("daily-backup", &entry.id).unwrap()
+    );
+    assert_eq!(snapshot.spec.backup_name, "daily-backup");
+    assert_eq!(snapshot.spec.backup_id, entry.id);
+    assert_eq!(snapshot.spec.completion_time, entry.completion_time);
+    assert_eq!(snapshot.spec.topics, Some(3));
+    assert_eq!(snapshot.spec.partitions, Some(9));
+    assert_eq!(snapshot.spec.size_bytes, Some(1024));
+    assert_eq!(
+        snapshot.metadata.labels.unwrap()["kafkabackup.com/backup"],
+        "daily-backup"
+    );
+    assert_eq!(
+        snapshot.metadata.finalizers,
+        Some(vec!["kafkabackup.com/cleanup".to_string()])
+    );
+    let owner = &snapshot.metadata.owner_references.unwrap()[0];
+    assert_eq!(owner.kind, "KafkaBackup");
+    assert_eq!(owner.uid, "backup-uid");
+}
+
+#[test]
+fn test_long_backup_names_get_valid_snapshot_labels() {
+    let prefix = "a".repeat(50);
+    let named = |suffix: &str| {
+        let mut backup = daily_backup("/backups");
+        backup.metadata.name = Some(format!("{prefix}-{suffix}"));
+        backup
+    };
+    let (first, second) = (named("first-backup"), named("second-backup"));
+    let entry = BackupHistoryEntry {
+        id: "backup-1".to_string(),
+        status: BackupStatus::Completed,
+        start_time: "2026-02-13T02:00:00Z".parse().unwrap(),
+        completion_time: None,
+        size_bytes: None,
+        topics_backed_up: None,
+        partitions_backed_up: None,
+        oldest_timestamp: None,
+        newest_timestamp: None,
+    };
+
+    let label = |backup: &KafkaBackup| {
+        build_backup_snapshot(backup, &entry)
+            .unwrap()
+            .metadata
+            .labels
+            .unwrap()["kafkabackup.com/backup"]
+            .clone()
+    };
+    let (first, second) = (label(&first), label(&second));
+    for value in [&first, &second] {
+        assert!(value.len() <= 63, "{value} is too long for a label");
+        assert!(value.starts_with(&prefix));
+        assert!(value.ends_with(|c: char| c.is_ascii_alphanumeric()));
+    }
+    assert_ne!(first, second);
+}
+
+#[tokio::test]
+async fn test_deleting_snapshot_prunes_backup_from_storage() {
+    let storage = tempfile::TempDir::new().unwrap();
+    write_manifest(storage.path(), "daily-backup-1", 1_777_334_400_000);
+    write_manifest(storage.path(), "daily-backup-2", 1_777_420_800_000);
+
+    let backup_json = serde_json::to_value(daily_backup(storage.path().to_str().unwrap())).unwrap();
+    let snapshot_json = serde_json::to_value(deleted_snapshot("daily-backup-1")).unwrap();
+    let (client, recorded, dispatcher) = mock_client(move |_, path, _| {
+        if path.ends_with("/kafkabackups/daily-backup") {
+            (200, backup_json.clone())
+        } else {
+            (200, snapshot_json.clone())
+        }
+    });
+
+    reconcile_snapshot(
+        Arc::new(deleted_snapshot("daily-backup-1")),
+        client,
+        &MetricsState::new(),
+    )
+    .await
+    .expect("reconcile should succeed");
+    dispatcher.await.unwrap();
+
+    assert!(!storage.path().join("daily-backup-1/manifest.json").exists());
+    assert!(storage.path().join("daily-backup-2/manifest.json").exists());
+
+    let requests = recorded.lock().unwrap();
+    let finalizer_patch = requests
+        .iter()
+        .find(|r| r.method == "PATCH" && r.path.ends_with("/kafkabackupsnapshots/daily-backup-1"))
+        .expect("reconcile must release the finalizer");
+    assert!(finalizer_patch.body["metadata"]["finalizers"].is_null());
+}
+
+#[tokio::test]
+async fn test_deleting_snapshot_without_backup_keeps_storage() {
+    let storage = tempfile::TempDir::new().unwrap();
+    write_manifest(storage.path(), "daily-backup-1", 1_777_334_400_000);
+
+    let snapshot_json = serde_json::to_value(deleted_snapshot("daily-backup-1")).unwrap();
+    let (client, recorded, dispatcher) = mock_client(move |_, path, _| {
+        if path.ends_with("/kafkabackups/daily-backup") {
+            not_found()
+        } else {
+            (200, snapshot_json.clone())
+        }
+    });
+
+    reconcile_snapshot(
+        Arc::new(deleted_snapshot("daily-backup-1")),
+        client,
+        &MetricsState::new(),
+    )
+    .await
+    .expect("reconcile should succeed");
+    dispatcher.await.unwrap();
+
+    assert!(storage.path().join("daily-backup-1/manifest.json").exists());
+    assert!(recorded
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|r| r.method == "PATCH" && r.path.ends_with("/kafkabackupsnapshots/daily-backup-1")));
+}
+
+#[tokio::test]
+async fn test_sync_creates_snapshots_and_drops_stale_records_without_pruning() {
+    let storage = tempfile::TempDir::new().unwrap();
+    write_manifest(storage.path(), "daily-backup-1", 1_777_334_400_000);
+    write_manifest(storage.path(), "daily-backup-2", 1_777_420_800_000);
+    // Belongs to another KafkaBackup sharing the bucket
+    write_manifest(storage.path(), "weekly-backup-1", 1_777_420_800_000);
+
+    let existing = json!({
+        "apiVersion": "kafkabackup.com/v1alpha1",
+        "kind": "KafkaBackupSnapshotList",
+        "metadata": {},
+        "items": [{
+            "apiVersion": "kafkabackup.com/v1alpha1",
+            "kind": "KafkaBackupSnapshot",
+            "metadata": {
+                "name": "daily-backup-0",
+                "namespace": "kafka",
+                "labels": {"kafkabackup.com/backup": "daily-backup"},
+                "finalizers": ["kafkabackup.com/cleanup"],
+                "ownerReferences": [{
+                    "apiVersion": "kafkabackup.com/v1alpha1",
+                    "kind": "KafkaBackup",
+                    "name": "daily-backup",
+                    "uid": "backup-uid",
+                    "controller": true
+                }]
+            },
+            "spec": {"backupName": "daily-backup", "backupId": "daily-backup-0"}
+        }]
+    });
+    let (client, recorded, dispatcher) = mock_client(move |method, path, body| {
+        if method == "GET" && path.ends_with("/kafkabackupsnapshots") {
+            (200, existing.clone())
+        } else if method == "GET" {
+            not_found()
+        } else if method == "DELETE" {
+            (
+                200,
+                json!({"kind": "Status", "apiVersion": "v1", "metadata": {}, "status": "Success"}),
+            )
+        } else if method == "PATCH" && body["spec"].is_null() {
+            (
+                200,
+                json!({
+                    "apiVersion": "kafkabackup.com/v1alpha1",
+                    "kind": "KafkaBackupSnapshot",
+                    "metadata": {"name": "daily-backup-0", "namespace": "kafka"},
+                    "spec": {"backupName": "daily-backup", "backupId": "daily-backup-0"}
+                }),
+            )
+        } else {
+            (200, body.clone())
+        }
+    });
+
+    sync_backup_snapshots(&client, &daily_backup(storage.path().to_str().unwrap()))
+        .await
+        .expect("sync should succeed");
+    drop(client);
+    dispatcher.await.unwrap();
+
+    let requests = recorded.lock().unwrap();
+    let applied: Vec<String> = requests
+        .iter()
+        .filter(|r| r.method == "PATCH" && !r.body["spec"].is_null())
+        .map(|r| r.path.rsplit('/').next().unwrap().to_string())
+        .collect();
+    assert_eq!(
+        applied,
+        vec![
+            snapshot_name("daily-backup", "daily-backup-1").unwrap(),
+            snapshot_name("daily-backup", "daily-backup-2").unwrap()
+        ]
+    );
+
+    // The stale record loses its finalizer before it is deleted, so removing
+    // it can never prune storage.
+    let stale: Vec<(&str, &str)> = requests
+        .iter()
+        .filter(|r| r.path.ends_with("/kafkabackupsnapshots/daily-backup-0"))
+        .map(|r| (r.method.as_str(), r.path.as_str()))
+        .collect();
+    assert_eq!(stale.len(), 2);
+    assert_eq!(stale[0].0, "PATCH");
+    assert_eq!(stale[1].0, "DELETE");
+    assert!(storage.path().join("daily-backup-1/manifest.json").exists());
+}
+
+#[tokio::test]
+async fn test_sync_leaves_snapshots_controlled_by_another_backup_alone() {
+    let storage = tempfile::TempDir::new().unwrap();
+    write_manifest(storage.path(), "daily-backup-1", 1_777_334_400_000);
+    let taken_name = snapshot_name("daily-backup", "daily-backup-1").unwrap();
+
+    let taken = json!({
+        "apiVersion": "kafkabackup.com/v1alpha1",
+        "kind": "KafkaBackupSnapshot",
+        "metadata": {
+            "name": taken_name,
+            "namespace": "kafka",
+            "ownerReferences": [{
+                "apiVersion": "kafkabackup.com/v1alpha1",
+                "kind": "KafkaBackup",
+                "name": "other-backup",
+                "uid": "other-uid",
+                "controller": true
+            }]
+        },
+        "spec": {"backupName": "other-backup", "backupId": "daily-backup-1"}
+    });
+    let (client, recorded, dispatcher) = mock_client(move |method, path, _| {
+        if method == "GET" && path.ends_with("/kafkabackupsnapshots") {
+            (
+                200,
+                json!({
+                    "apiVersion": "kafkabackup.com/v1alpha1",
+                    "kind": "KafkaBackupSnapshotList",
+                    "metadata": {},
+                    "items": []
+                }),
+            )
+        } else {
+            (200, taken.clone())
+        }
+    });
+
+    sync_backup_snapshots(&client, &daily_backup(storage.path().to_str().unwrap()))
+        .await
+        .expect("sync should succeed");
+    drop(client);
+    dispatcher.await.unwrap();
+
+    let requests = recorded.lock().unwrap();
+    assert!(requests.iter().all(|r| r.method == "GET"));
+    assert!(requests.iter().any(|r| r
+        .path
+        .ends_with(&format!("/kafkabackupsnapshots/{taken_name}"))));
+}
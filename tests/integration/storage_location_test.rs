@@ -0,0 +1,201 @@
+use std::sync::{Arc, Mutex};
+
+use http::{Request, Response};
+use http_body_util::BodyExt;
+use kafka_backup_operator::adapters::storage_location::{resolve_storage, with_resolved_storage};
+use kafka_backup_operator::crd::common::{
+    FilesystemStorageSpec, StorageLocationKind, StorageLocationRef, StorageSpec, StorageType,
+};
+use kafka_backup_operator::crd::{BackupStorageLocation, KafkaBackup};
+use kafka_backup_operator::metrics::prometheus::MetricsState;
+use kafka_backup_operator::reconcilers::storage_location::reconcile_storage_location;
+use kube::client::Body;
+use kube::Client;
+use serde_json::json;
+use tower_test::mock;
+
+#[derive(Debug)]
+struct RecordedRequest {
+    method: String,
+    path: String,
+    body: serde_json::Value,
+}
+
+fn filesystem_storage(path: &str) -> StorageSpec {
+    StorageSpec {
+        storage_type: StorageType::Filesystem,
+        s3: None,
+        azure: None,
+        gcs: None,
+        filesystem: Some(FilesystemStorageSpec {
+            path: path.to_string(),
+        }),
+    }
+}
+
+fn location(path: &str) -> BackupStorageLocation {
+    serde_json::from_value(json!({
+        "apiVersion": "kafkabackup.com/v1alpha1",
+        "kind": "BackupStorageLocation",
+        "metadata": {"name": "primary", "namespace": "kafka", "generation": 1},
+        "spec": {"storage": {"type": "filesystem", "filesystem": {"path": path}}}
+    }))
+    .unwrap()
+}
+
+/// Serve every request with `response` and record the requests made.
+fn mock_client(
+    response: serde_json::Value,
+) -> (
+    Client,
+    Arc<Mutex<Vec<RecordedRequest>>>,
+    tokio::task::JoinHandle<()>,
+) {
+    let (mock_service, mut handle) = mock::pair::<Request<Body>, Response<Body>>();
+    let recorded = Arc::new(Mutex::new(Vec::new()));
+
+    let dispatcher = {
+        let recorded = Arc::clone(&recorded);
+        tokio::spawn(async move {
+            while let Some((request, send)) = handle.next_request().await {
+                let method = request.method().to_string();
+                let path = request.uri().path().to_string();
+                let bytes = request.into_body().collect().await.unwrap().to_bytes();
+                let body: serde_json::Value = if bytes.is_empty() {
+                    serde_json::Value::Null
+                } else {
+                    serde_json::from_slice(&bytes).unwrap()
+                };
+                recorded
+                    .lock()
+                    .unwrap()
+                    .push(RecordedRequest { method, path, body });
+
+                let response = Response::builder()
+                    .status(200)
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&response).unwrap()))
+                    .unwrap();
+                send.send_response(response);
+            }
+        })
+    };
+
+    (Client::new(mock_service, "kafka"), recorded, dispatcher)
+}
+
+#[tokio::test]
+async fn test_storage_ref_resolves_through_backup_storage_location() {
+    let (client, recorded, dispatcher) =
+        mock_client(serde_json::to_value(location("/mnt/backups")).unwrap());
+    let backup: KafkaBackup = serde_json::from_value(json!({
+        "apiVersion": "kafkabackup.com/v1alpha1",
+        "kind": "KafkaBackup",
+        "metadata": {"name": "daily-backup", "namespace": "kafka"},
+        "spec": {
+            "strimziClusterRef": {"name": "production-cluster"},
+            "storageRef": {"name": "primary"}
+        }
+    }))
+    .unwrap();
+
+    let resolved = with_resolved_storage(&client, &backup).await.unwrap();
+    drop(client);
+    dispatcher.await.unwrap();
+
+    let storage = resolved.spec.storage.expect("storage must be resolved");
+    assert_eq!(storage.filesystem.unwrap().path, "/mnt/backups");
+    assert!(resolved.spec.storage_ref.is_none());
+    assert_eq!(
+        recorded.lock().unwrap()[0].path,
+        "/apis/kafkabackup.com/v1alpha1/namespaces/kafka/backupstoragelocations/primary"
+    );
+}
+
+#[tokio::test]
+async fn test_cluster_storage_ref_is_looked_up_cluster_wide() {
+    let cluster_location = json!({
+        "apiVersion": "kafkabackup.com/v1alpha1",
+        "kind": "ClusterBackupStorageLocation",
+        "metadata": {"name": "shared"},
+        "spec": {"storage": {"type": "filesystem", "filesystem": {"path": "/shared"}}}
+    });
+    let (client, recorded, dispatcher) = mock_client(cluster_location);
+    let storage_ref = StorageLocationRef {
+        kind: Some(StorageLocationKind::ClusterBackupStorageLocation),
+        name: "shared".to_string(),
+    };
+
+    let storage = resolve_storage(&client, "kafka", None, Some(&storage_ref))
+        .await
+        .unwrap();
+    drop(client);
+    dispatcher.await.unwrap();
+
+    assert_eq!(storage.filesystem.unwrap().path, "/shared");
+    assert_eq!(
+        recorded.lock().unwrap()[0].path,
+        "/apis/kafkabackup.com/v1alpha1/clusterbackupstoragelocations/shared"
+    );
+}
+
+#[tokio::test]
+async fn test_storage_and_storage_ref_are_mutually_exclusive() {
+    let (client, recorded, dispatcher) = mock_client(serde_json::Value::Null);
+    let storage = filesystem_storage("/backups");
+    let storage_ref = StorageLocationRef {
+        kind: None,
+        name: "primary".to_string(),
+    };
+
+    let both = resolve_storage(&client, "kafka", Some(&storage), Some(&storage_ref))
+        .await
+        .unwrap_err();
+    let neither = resolve_storage(&client, "kafka", None, None)
+        .await
+        .unwrap_err();
+    drop(client);
+    dispatcher.await.unwrap();
+
+    assert_eq!(both.reason(), "InvalidConfiguration");
+    assert_eq!(neither.reason(), "InvalidConfiguration");
+    assert!(recorded.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_reachable_location_reports_ready() {
+    let storage_dir = tempfile::TempDir::new().unwrap();
+    let location = location(storage_dir.path().to_str().unwrap());
+    let (client, recorded, dispatcher) = mock_client(serde_json::to_value(&location).unwrap());
+
+    reconcile_storage_location(Arc::new(location), client, &MetricsState::new())
+        .await
+        .unwrap();
+    dispatcher.await.unwrap();
+
+    let requests = recorded.lock().unwrap();
+    let status_patch = requests
+        .iter()
+        .find(|r| r.method == "PATCH" && r.path.ends_with("/backupstoragelocations/primary/status"))
+        .expect("reconcile must report reachability");
+    let condition = &status_patch.body["status"]["conditions"][0];
+    assert_eq!(condition["type"], json!("Ready"));
+    assert_eq!(condition["status"], json!("True"));
+    assert_eq!(condition["reason"], json!("StorageReachable"));
+}
+
+#[tokio::test]
+async fn test_unreachable_location_reports_not_ready() {
+    let location = location("/nonexistent/kafka-backup-storage-location");
+    let (client, recorded, dispatcher) = mock_client(serde_json::to_value(&location).unwrap());
+
+    reconcile_storage_location(Arc::new(location), client, &MetricsState::new())
+        .await
+        .unwrap();
+    dispatcher.await.unwrap();
+
+    let requests = recorded.lock().unwrap();
+    let condition = &requests[0].body["status"]["conditions"][0];
+    assert_eq!(condition["status"], json!("False"));
+    assert_eq!(condition["reason"], json!("StorageUnreachable"));
+}
@@ -0,0 +1,82 @@
+use std::sync::{Arc, Mutex};
+
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::HeaderMap;
+use axum::routing::post;
+use axum::Router;
+use kafka_backup_operator::telemetry::{self, TracingConfig};
+use tracing_subscriber::layer::SubscriberExt;
+
+/// Requests received by the collector stand-in, as (content-type, body)
+type Received = Arc<Mutex<Vec<(String, Vec<u8>)>>>;
+
+/// A local stand-in for an OTLP/HTTP collector, recording what is posted to
+/// `/v1/traces`
+async fn collector() -> (String, Received) {
+    let received: Received = Arc::default();
+    let app = Router::new()
+        .route(
+            "/v1/traces",
+            post(
+                |State(received): State<Received>, headers: HeaderMap, body: Bytes| async move {
+                    let content_type = headers
+                        .get("content-type")
+                        .and_then(|v| v.to_str().ok())
+                        .unwrap_or_default()
+                        .to_string();
+                    received.lock().unwrap().push((content_type, body.to_vec()));
+                },
+            ),
+        )
+        .with_state(Arc::clone(&received));
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    (format!("http://{address}/v1/traces"), received)
+}
+
+fn contains(haystack: &[u8], needle: &str) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle.as_bytes())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_reconcile_spans_are_exported_to_the_collector() {
+    let (endpoint, received) = collector().await;
+    let provider = telemetry::tracer_provider(&TracingConfig {
+        endpoint,
+        service_name: "backup-operator-test".to_string(),
+    })
+    .unwrap();
+
+    let subscriber = tracing_subscriber::registry().with(telemetry::layer(&provider));
+    let traceparent = tracing::subscriber::with_default(subscriber, || {
+        let _reconcile = tracing::info_span!("reconciling object").entered();
+        drop(tracing::info_span!("resolve_kafka_cluster", cluster = "my-cluster").entered());
+        let _create_job = tracing::info_span!("create_job", job = "daily-backup").entered();
+        telemetry::current_traceparent()
+    });
+    assert!(traceparent.is_some());
+
+    // Flushing blocks on the exporter's HTTP client
+    tokio::task::spawn_blocking(move || provider.shutdown())
+        .await
+        .unwrap()
+        .unwrap();
+
+    let received = received.lock().unwrap();
+    assert!(!received.is_empty(), "no spans were exported");
+    let (content_type, _) = &received[0];
+    assert_eq!(content_type, "application/x-protobuf");
+    let body: Vec<u8> = received.iter().flat_map(|(_, b)| b.clone()).collect();
+    for name in [
+        "reconciling object",
+        "resolve_kafka_cluster",
+        "create_job",
+        "backup-operator-test",
+    ] {
+        assert!(contains(&body, name), "{name} was not exported");
+    }
+}
@@ -0,0 +1,454 @@
+use std::sync::{Arc, Mutex};
+
+use http::{Request, Response};
+use http_body_util::BodyExt;
+use kafka_backup_operator::crd::{KafkaBackup, KafkaBackupVerification, VerificationMode};
+use kafka_backup_operator::metrics::prometheus::MetricsState;
+use kafka_backup_operator::reconcilers::verification::{
+    build_verification_restore, reconcile_verification,
+};
+use kafka_backup_operator::retention::storage::BackupTopic;
+use kube::client::Body;
+use kube::Client;
+use serde_json::json;
+use tower_test::mock;
+
+#[derive(Debug)]
+struct RecordedRequest {
+    method: String,
+    path: String,
+    body: serde_json::Value,
+}
+
+fn verification(mode: &str, status: serde_json::Value) -> KafkaBackupVerification {
+    serde_json::from_value(json!({
+        "apiVersion": "kafkabackup.com/v1alpha1",
+        "kind": "KafkaBackupVerification",
+        "metadata": {
+            "name": "orders-verify",
+            "namespace": "kafka",
+            "uid": "verification-uid",
+            "generation": 1,
+            "creationTimestamp": "2026-01-01T00:00:00Z",
+            "finalizers": ["kafkabackup.com/cleanup"]
+        },
+        "spec": {
+            "backupName": "orders-backup",
+            "schedule": {"cron": "* * * * *"},
+            "mode": mode
+        },
+        "status": status
+    }))
+    .unwrap()
+}
+
+fn source_backup() -> serde_json::Value {
+    json!({
+        "apiVersion": "kafkabackup.com/v1alpha1",
+        "kind": "KafkaBackup",
+        "metadata": {"name": "orders-backup", "namespace": "kafka"},
+        "spec": {
+            "strimziClusterRef": {"name": "production-cluster", "listener": "tls"},
+            "authentication": {"type": "tls", "kafkaUserRef": {"name": "backup-user"}},
+            "storage": {"type": "filesystem", "filesystem": {"path": "/backups"}}
+        },
+        "status": {
+            "conditions": [{"type": "Ready", "status": "True", "reason": "BackupCompleted"}],
+            "lastBackup": {
+                "id": "orders-backup-20260213-020000",
+                "startTime": "2026-02-13T02:00:00Z",
+                "status": "Completed"
+            }
+        }
+    })
+}
+
+fn completed_restore() -> serde_json::Value {
+    json!({
+        "apiVersion": "kafkabackup.com/v1alpha1",
+        "kind": "KafkaRestore",
+        "metadata": {"name": "orders-verify-20260213-060000", "namespace": "kafka"},
+        "spec": {
+            "strimziClusterRef": {"name": "production-cluster"},
+            "backupRef": {"name": "orders-backup"}
+        },
+        "status": {
+            "conditions": [
+                {"type": "Ready", "status": "True", "reason": "RestoreCompleted"},
+                {"type": "RestoreComplete", "status": "True", "reason": "RestoreCompleted"}
+            ]
+        }
+    })
+}
+
+/// Run `reconcile_verification` against a mock API server holding the
+/// source KafkaBackup and a completed verification KafkaRestore.
+async fn reconcile_with_mock_api(verification: KafkaBackupVerification) -> Vec<RecordedRequest> {
+    reconcile_with_mock_objects(verification, source_backup(), Vec::new()).await
+}
+
+/// Like [`reconcile_with_mock_api`], with `backup` as the source KafkaBackup
+/// and the API server also listing `kafka_topics`.
+async fn reconcile_with_mock_objects(
+    verification: KafkaBackupVerification,
+    backup: serde_json::Value,
+    kafka_topics: Vec<serde_json::Value>,
+) -> Vec<RecordedRequest> {
+    let (mock_service, mut handle) = mock::pair::<Request<Body>, Response<Body>>();
+    let recorded = Arc::new(Mutex::new(Vec::new()));
+
+    let verification_json = serde_json::to_value(&verification).unwrap();
+    let dispatcher = {
+        let recorded = Arc::clone(&recorded);
+        tokio::spawn(async move {
+            while let Some((request, send)) = handle.next_request().await {
+                let method = request.method().to_string();
+                let path = request.uri().path().to_string();
+                let bytes = request.into_body().collect().await.unwrap().to_bytes();
+                let body: serde_json::Value = if bytes.is_empty() {
+                    serde_json::Value::Null
+                } else {
+                    serde_json::from_slice(&bytes).unwrap()
+                };
+
+                let response_body = if method == "GET" && path.ends_with("/kafkarestores") {
+                    json!({
+                        "apiVersion": "kafkabackup.com/v1alpha1",
+                        "kind": "KafkaRestoreList",
+                        "metadata": {},
+                        "items": []
+                    })
+                } else if method == "GET" && path.contains("/kafkarestores/") {
+                    completed_restore()
+                } else if method == "GET" && path.ends_with("/kafkatopics") {
+                    json!({
+                        "apiVersion": "kafka.strimzi.io/v1",
+                        "kind": "KafkaTopicList",
+                        "metadata": {},
+                        "items": kafka_topics
+                    })
+                } else if path.contains("/kafkabackups/orders-backup") {
+                    backup.clone()
+                } else if path.contains("/kafkabackupverifications/") {
+                    verification_json.clone()
+                } else {
+                    // Creation of a KafkaRestore or KafkaTopic: echo the
+                    // object back
+                    body.clone()
+                };
+
+                recorded
+                    .lock()
+                    .unwrap()
+                    .push(RecordedRequest { method, path, body });
+
+                let response = Response::builder()
+                    .status(200)
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&response_body).unwrap()))
+                    .unwrap();
+                send.send_response(response);
+            }
+        })
+    };
+
+    let client = Client::new(mock_service, "kafka");
+    let metrics = MetricsState::new();
+    reconcile_verification(Arc::new(verification), client, &metrics)
+        .await
+        .expect("reconcile should succeed");
+
+    dispatcher.await.unwrap();
+    Arc::try_unwrap(recorded).unwrap().into_inner().unwrap()
+}
+
+fn orders_topics() -> Vec<BackupTopic> {
+    vec![
+        BackupTopic {
+            name: "orders".to_string(),
+            partitions: 6,
+        },
+        BackupTopic {
+            name: "payments".to_string(),
+            partitions: 3,
+        },
+    ]
+}
+
+#[test]
+fn test_dry_run_restore_targets_source_cluster() {
+    let backup: KafkaBackup = serde_json::from_value(source_backup()).unwrap();
+
+    let restore = build_verification_restore(
+        &verification("DryRun", json!({})),
+        &backup,
+        "orders-backup-20260213-020000",
+        &[],
+        "orders-verify-20260213-060000",
+    )
+    .unwrap();
+
+    assert_eq!(restore.spec.strimzi_cluster_ref.name, "production-cluster");
+    assert_eq!(
+        restore.spec.strimzi_cluster_ref.listener.as_deref(),
+        Some("tls")
+    );
+    assert!(restore.spec.authentication.is_some());
+    assert_eq!(
+        restore.spec.backup_ref.backup_id.as_deref(),
+        Some("orders-backup-20260213-020000")
+    );
+    assert_eq!(restore.spec.restore.unwrap().dry_run, Some(true));
+    assert!(restore.spec.topic_mapping.is_empty());
+
+    let labels = restore.metadata.labels.unwrap();
+    assert_eq!(labels["kafkabackup.com/verification"], "orders-verify");
+    let owner = &restore.metadata.owner_references.unwrap()[0];
+    assert_eq!(owner.kind, "KafkaBackupVerification");
+    assert_eq!(owner.uid, "verification-uid");
+}
+
+#[test]
+fn test_scratch_restore_maps_topics_under_prefix() {
+    let backup: KafkaBackup = serde_json::from_value(source_backup()).unwrap();
+    let mut verification = verification("Scratch", json!({}));
+    verification.spec.strimzi_cluster_ref =
+        Some(serde_json::from_value(json!({"name": "scratch-cluster"})).unwrap());
+    verification.spec.topic_prefix = Some("canary-".to_string());
+
+    let restore = build_verification_restore(
+        &verification,
+        &backup,
+        "orders-backup-20260213-020000",
+        &orders_topics(),
+        "orders-verify-20260213-060000",
+    )
+    .unwrap();
+
+    assert_eq!(restore.spec.strimzi_cluster_ref.name, "scratch-cluster");
+    // The backup's credentials are not sent to a different cluster
+    assert!(restore.spec.authentication.is_none());
+    assert_eq!(restore.spec.restore.unwrap().dry_run, Some(false));
+    let mapping: Vec<(&str, &str)> = restore
+        .spec
+        .topic_mapping
+        .iter()
+        .map(|m| (m.source_topic.as_str(), m.target_topic.as_str()))
+        .collect();
+    assert_eq!(
+        mapping,
+        vec![("orders", "canary-orders"), ("payments", "canary-payments")]
+    );
+}
+
+#[test]
+fn test_scratch_restore_requires_cluster_ref() {
+    let backup: KafkaBackup = serde_json::from_value(source_backup()).unwrap();
+    let verification = verification("Scratch", json!({}));
+    assert_eq!(verification.spec.mode, VerificationMode::Scratch);
+
+    let err = build_verification_restore(
+        &verification,
+        &backup,
+        "orders-backup-20260213-020000",
+        &orders_topics(),
+        "orders-verify-20260213-060000",
+    )
+    .unwrap_err();
+    assert_eq!(err.reason(), "InvalidConfiguration");
+}
+
+#[tokio::test]
+async fn test_reconcile_records_passed_run_on_backup() {
+    let requests = reconcile_with_mock_api(verification(
+        "DryRun",
+        json!({
+            "activeRun": {
+                "backupId": "orders-backup-20260213-020000",
+                "restoreName": "orders-verify-20260213-060000",
+                "startTime": "2026-02-13T06:00:00Z"
+            },
+            "lastScheduleTime": "2099-01-01T00:00:00Z"
+        }),
+    ))
+    .await;
+
+    let backup_patch = requests
+        .iter()
+        .find(|r| r.method == "PATCH" && r.path.ends_with("/kafkabackups/orders-backup/status"))
+        .expect("the outcome must be recorded on the KafkaBackup");
+    let status = &backup_patch.body["status"];
+    assert_eq!(
+        status["lastVerifiedBackupId"],
+        json!("orders-backup-20260213-020000")
+    );
+    let conditions = status["conditions"].as_array().unwrap();
+    // The backup reconciler's conditions are kept alongside Verified
+    assert_eq!(conditions[0]["type"], json!("Ready"));
+    assert_eq!(conditions[1]["type"], json!("Verified"));
+    assert_eq!(conditions[1]["status"], json!("True"));
+    assert_eq!(conditions[1]["reason"], json!("VerificationPassed"));
+
+    let status_patch = requests
+        .iter()
+        .find(|r| {
+            r.method == "PATCH"
+                && r.path
+                    .ends_with("/kafkabackupverifications/orders-verify/status")
+        })
+        .expect("reconcile must update the verification status");
+    let status = &status_patch.body["status"];
+    assert_eq!(status["activeRun"], serde_json::Value::Null);
+    assert_eq!(status["lastVerification"]["result"], json!("Passed"));
+    assert_eq!(
+        status["lastVerification"]["restoreName"],
+        json!("orders-verify-20260213-060000")
+    );
+    assert_eq!(
+        status["conditions"][0]["reason"],
+        json!("VerificationPassed")
+    );
+
+    assert!(
+        !requests.iter().any(|r| r.method == "POST"),
+        "no new run is due yet"
+    );
+}
+
+#[tokio::test]
+async fn test_reconcile_starts_due_run_from_latest_backup() {
+    let requests = reconcile_with_mock_api(verification(
+        "DryRun",
+        json!({"lastScheduleTime": "2026-02-13T06:00:00Z"}),
+    ))
+    .await;
+
+    let created = requests
+        .iter()
+        .find(|r| r.method == "POST" && r.path.ends_with("/kafkarestores"))
+        .expect("a due run must create a KafkaRestore");
+    assert_eq!(
+        created.body["spec"]["backupRef"]["backupId"],
+        json!("orders-backup-20260213-020000")
+    );
+    assert_eq!(created.body["spec"]["restore"]["dryRun"], json!(true));
+    let restore_name = created.body["metadata"]["name"].as_str().unwrap();
+    assert!(restore_name.starts_with("orders-verify-"));
+
+    let status_patch = requests
+        .iter()
+        .find(|r| {
+            r.method == "PATCH"
+                && r.path
+                    .ends_with("/kafkabackupverifications/orders-verify/status")
+        })
+        .expect("reconcile must update the verification status");
+    let status = &status_patch.body["status"];
+    assert_eq!(status["activeRun"]["restoreName"], json!(restore_name));
+    assert_eq!(
+        status["conditions"][0]["reason"],
+        json!("VerificationRunning")
+    );
+}
+
+/// A Scratch verification due now, restoring `orders-backup` from a
+/// filesystem store at `storage` holding one backup with topic `orders`
+fn due_scratch_verification(
+    storage: &std::path::Path,
+) -> (KafkaBackupVerification, serde_json::Value) {
+    let backup_id = "orders-backup-20260213-020000";
+    std::fs::create_dir_all(storage.join(backup_id)).unwrap();
+    std::fs::write(
+        storage.join(backup_id).join("manifest.json"),
+        json!({
+            "backup_id": backup_id,
+            "created_at": 1_770_948_000_000_i64,
+            "topics": [{"name": "orders", "partitions": [{"segments": []}]}]
+        })
+        .to_string(),
+    )
+    .unwrap();
+    let mut backup = source_backup();
+    backup["spec"]["storage"]["filesystem"]["path"] = storage.to_string_lossy().into();
+
+    let mut verification = verification(
+        "Scratch",
+        json!({"lastScheduleTime": "2026-02-13T06:00:00Z"}),
+    );
+    verification.spec.strimzi_cluster_ref =
+        Some(serde_json::from_value(json!({"name": "scratch-cluster"})).unwrap());
+    (verification, backup)
+}
+
+fn scratch_topic(ready: bool) -> serde_json::Value {
+    json!({
+        "apiVersion": "kafka.strimzi.io/v1",
+        "kind": "KafkaTopic",
+        "metadata": {"name": "orders-verify-topic-0", "namespace": "kafka", "generation": 1},
+        "spec": {"topicName": "verify-orders", "partitions": 1},
+        "status": {
+            "observedGeneration": 1,
+            "conditions": [{"type": "Ready", "status": if ready { "True" } else { "False" }}]
+        }
+    })
+}
+
+#[tokio::test]
+async fn test_scratch_run_waits_for_topics_before_restoring() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let (verification, backup) = due_scratch_verification(temp_dir.path());
+
+    let requests =
+        reconcile_with_mock_objects(verification, backup, vec![scratch_topic(false)]).await;
+
+    assert!(requests
+        .iter()
+        .any(|r| r.method == "PATCH" && r.path.ends_with("/kafkatopics/orders-verify-topic-0")));
+    assert!(
+        !requests.iter().any(|r| r.method == "POST"),
+        "no KafkaRestore before the scratch topics are Ready"
+    );
+    let status = &requests
+        .iter()
+        .rfind(|r| {
+            r.path
+                .ends_with("/kafkabackupverifications/orders-verify/status")
+        })
+        .expect("reconcile must update the verification status")
+        .body["status"];
+    assert_eq!(status["conditions"][0]["reason"], json!("WaitingForTopics"));
+    // The run has not started, so the schedule does not move on
+    assert_eq!(status["lastScheduleTime"], json!("2026-02-13T06:00:00Z"));
+    assert!(status["activeRun"].is_null());
+}
+
+#[tokio::test]
+async fn test_scratch_run_restores_once_topics_are_ready() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let (verification, backup) = due_scratch_verification(temp_dir.path());
+
+    let requests =
+        reconcile_with_mock_objects(verification, backup, vec![scratch_topic(true)]).await;
+
+    let created = requests
+        .iter()
+        .find(|r| r.method == "POST" && r.path.ends_with("/kafkarestores"))
+        .expect("ready scratch topics must be restored into");
+    assert_eq!(
+        created.body["spec"]["topicMapping"],
+        json!([{"sourceTopic": "orders", "targetTopic": "verify-orders"}])
+    );
+    let status = &requests
+        .iter()
+        .rfind(|r| {
+            r.path
+                .ends_with("/kafkabackupverifications/orders-verify/status")
+        })
+        .unwrap()
+        .body["status"];
+    assert_ne!(status["lastScheduleTime"], json!("2026-02-13T06:00:00Z"));
+    assert_eq!(
+        status["activeRun"]["restoreName"],
+        created.body["metadata"]["name"]
+    );
+}
@@ -0,0 +1,266 @@
+use kafka_backup_operator::webhook::{mutate, review};
+use kube::core::admission::AdmissionReview;
+use kube::core::DynamicObject;
+use serde_json::{json, Value};
+
+fn backup(spec: Value) -> Value {
+    json!({
+        "apiVersion": "kafkabackup.com/v1alpha1",
+        "kind": "KafkaBackup",
+        "metadata": {"name": "orders-backup", "namespace": "kafka"},
+        "spec": spec
+    })
+}
+
+fn backup_spec() -> Value {
+    json!({
+        "strimziClusterRef": {"name": "production-cluster"},
+        "authentication": {"type": "tls", "kafkaUserRef": {"name": "backup-user"}},
+        "storage": {"type": "s3", "s3": {"bucket": "kafka-backups"}},
+        "schedule": {"cron": "0 2 * * *"}
+    })
+}
+
+fn restore(spec: Value) -> Value {
+    json!({
+        "apiVersion": "kafkabackup.com/v1alpha1",
+        "kind": "KafkaRestore",
+        "metadata": {"name": "orders-restore", "namespace": "kafka"},
+        "spec": spec
+    })
+}
+
+fn restore_spec() -> Value {
+    json!({
+        "strimziClusterRef": {"name": "production-cluster"},
+        "backupRef": {"name": "orders-backup"},
+        "pointInTime": {"timestamp": "2026-02-13T02:00:00Z"},
+        "restore": {"existingTopicPolicy": "append"}
+    })
+}
+
+/// Send an AdmissionReview for `operation` on `object` to the validating
+/// webhook and return the response
+fn admit(operation: &str, object: Option<Value>) -> Value {
+    serde_json::to_value(review(admission_review(operation, object))).unwrap()["response"].clone()
+}
+
+/// Send an AdmissionReview for creating `object` to the mutating webhook and
+/// return `object` with the returned JSON patch applied
+fn defaulted(mut object: Value) -> Value {
+    let response = serde_json::to_value(mutate(admission_review("CREATE", Some(object.clone()))))
+        .unwrap()["response"]
+        .clone();
+    assert_eq!(response["allowed"], json!(true), "{response}");
+    if let Some(patch) = response.get("patch") {
+        assert_eq!(response["patchType"], json!("JSONPatch"));
+        let patch: Vec<u8> = serde_json::from_value(patch.clone()).unwrap();
+        let patch: json_patch::Patch = serde_json::from_slice(&patch).unwrap();
+        json_patch::patch(&mut object, &patch).unwrap();
+    }
+    object
+}
+
+fn admission_review(operation: &str, object: Option<Value>) -> AdmissionReview<DynamicObject> {
+    let kind = object
+        .as_ref()
+        .map_or("KafkaBackup", |o| o["kind"].as_str().unwrap())
+        .to_string();
+    let plural = format!("{}s", kind.to_lowercase());
+    serde_json::from_value(json!({
+        "apiVersion": "admission.k8s.io/v1",
+        "kind": "AdmissionReview",
+        "request": {
+            "uid": "705ab4f5-6393-11e8-b7cc-42010a800002",
+            "kind": {"group": "kafkabackup.com", "version": "v1alpha1", "kind": kind},
+            "resource": {"group": "kafkabackup.com", "version": "v1alpha1", "resource": plural},
+            "name": "orders",
+            "namespace": "kafka",
+            "operation": operation,
+            "userInfo": {"username": "admin"},
+            "object": object,
+            "oldObject": null,
+            "dryRun": false
+        }
+    }))
+    .unwrap()
+}
+
+fn assert_denied(response: &Value, message: &str) {
+    assert_eq!(response["allowed"], json!(false), "{response}");
+    let reason = response["status"]["message"].as_str().unwrap();
+    assert!(
+        reason.contains(message),
+        "expected '{message}' in '{reason}'"
+    );
+}
+
+#[test]
+fn test_valid_resources_are_admitted() {
+    let response = admit("CREATE", Some(backup(backup_spec())));
+    assert_eq!(response["allowed"], json!(true), "{response}");
+    assert_eq!(
+        response["uid"],
+        json!("705ab4f5-6393-11e8-b7cc-42010a800002")
+    );
+
+    let response = admit("UPDATE", Some(restore(restore_spec())));
+    assert_eq!(response["allowed"], json!(true), "{response}");
+}
+
+#[test]
+fn test_delete_without_object_is_admitted() {
+    let response = admit("DELETE", None);
+    assert_eq!(response["allowed"], json!(true), "{response}");
+}
+
+#[test]
+fn test_backup_with_bad_cron_is_denied() {
+    let mut spec = backup_spec();
+    spec["schedule"]["cron"] = json!("every night");
+    assert_denied(
+        &admit("CREATE", Some(backup(spec))),
+        "invalid cron expression 'every night'",
+    );
+}
+
+#[test]
+fn test_backup_with_mismatched_storage_type_is_denied() {
+    let mut spec = backup_spec();
+    spec["storage"] = json!({"type": "s3", "filesystem": {"path": "/backups"}});
+    assert_denied(
+        &admit("CREATE", Some(backup(spec))),
+        "Storage type is S3 but s3 config is missing",
+    );
+}
+
+#[test]
+fn test_backup_with_storage_and_storage_ref_is_denied() {
+    let mut spec = backup_spec();
+    spec["storageRef"] = json!({"name": "shared-s3"});
+    assert_denied(
+        &admit("CREATE", Some(backup(spec))),
+        "storage and storageRef are mutually exclusive",
+    );
+}
+
+#[test]
+fn test_backup_with_scram_without_username_is_denied() {
+    let mut spec = backup_spec();
+    spec["authentication"] = json!({
+        "type": "scram-sha-512",
+        "passwordSecret": {"name": "backup-user", "key": "password"}
+    });
+    assert_denied(
+        &admit("CREATE", Some(backup(spec))),
+        "SCRAM authentication requires username",
+    );
+}
+
+#[test]
+fn test_restore_with_non_rfc3339_timestamp_is_denied() {
+    let mut spec = restore_spec();
+    spec["pointInTime"]["timestamp"] = json!("2026-02-13 02:00");
+    assert_denied(
+        &admit("CREATE", Some(restore(spec))),
+        "Invalid timestamp format: 2026-02-13 02:00",
+    );
+}
+
+#[test]
+fn test_restore_with_offset_from_end_is_denied() {
+    let mut spec = restore_spec();
+    spec["pointInTime"] = json!({"offsetFromEnd": "1h"});
+    assert_denied(
+        &admit("CREATE", Some(restore(spec))),
+        "pointInTime.offsetFromEnd is not supported",
+    );
+}
+
+#[test]
+fn test_restore_with_fail_existing_topic_policy_is_denied() {
+    let mut spec = restore_spec();
+    spec["restore"]["existingTopicPolicy"] = json!("fail");
+    assert_denied(
+        &admit("UPDATE", Some(restore(spec))),
+        "restore.existingTopicPolicy: fail is not supported",
+    );
+}
+
+#[test]
+fn test_restore_with_storage_and_backup_name_is_denied() {
+    let mut spec = restore_spec();
+    spec["backupRef"]["storage"] =
+        json!({"type": "filesystem", "filesystem": {"path": "/backups"}});
+    spec["backupRef"]["backupId"] = json!("orders-backup-20260213-020000");
+    assert_denied(
+        &admit("CREATE", Some(restore(spec))),
+        "backupRef.kind, backupRef.name and backupRef.namespace must be omitted",
+    );
+}
+
+#[test]
+fn test_backup_defaults_are_written_into_spec() {
+    let spec = defaulted(backup(backup_spec()))["spec"].clone();
+
+    assert_eq!(spec["image"], json!("osodevops/kafka-backup:v0.15.12"));
+    assert_eq!(spec["backoffLimit"], json!(3));
+    assert_eq!(spec["metrics"], json!({"enabled": true, "port": 8080}));
+    assert_eq!(spec["schedule"]["successfulJobsHistoryLimit"], json!(3));
+    assert_eq!(spec["schedule"]["failedJobsHistoryLimit"], json!(3));
+    // Fields without an operator default are left unset
+    assert!(spec.get("retention").is_none());
+}
+
+#[test]
+fn test_restore_defaults_keep_values_that_are_set() {
+    let mut spec = restore_spec();
+    spec["image"] = json!("registry.local/kafka-backup:pinned");
+    spec["metrics"] = json!({"enabled": false});
+    let spec = defaulted(restore(spec))["spec"].clone();
+
+    assert_eq!(spec["image"], json!("registry.local/kafka-backup:pinned"));
+    assert_eq!(spec["backoffLimit"], json!(0));
+    assert_eq!(spec["metrics"], json!({"enabled": false}));
+}
+
+#[test]
+fn test_defaulted_resource_is_not_patched_again() {
+    let object = defaulted(backup(backup_spec()));
+    let response = serde_json::to_value(mutate(admission_review("UPDATE", Some(object)))).unwrap()
+        ["response"]
+        .clone();
+    assert_eq!(response["allowed"], json!(true));
+    assert!(response.get("patch").is_none(), "{response}");
+}
+
+#[test]
+fn test_started_restore_is_not_defaulted() {
+    let mut object = restore(restore_spec());
+    object["status"] = json!({
+        "restore": {"startTime": "2026-02-13T03:00:00Z", "status": "Running"}
+    });
+    let response = serde_json::to_value(mutate(admission_review("UPDATE", Some(object)))).unwrap()
+        ["response"]
+        .clone();
+    assert_eq!(response["allowed"], json!(true));
+    assert!(
+        response.get("patch").is_none(),
+        "the spec of a started restore is immutable: {response}"
+    );
+}
+
+#[test]
+fn test_v1beta1_objects_are_validated() {
+    let mut object = backup(backup_spec());
+    object["apiVersion"] = json!("kafkabackup.com/v1beta1");
+    object["spec"]["backup"] = json!({"compression": "zstd"});
+    assert_eq!(
+        admit("CREATE", Some(object.clone()))["allowed"],
+        json!(true)
+    );
+
+    object["spec"].as_object_mut().unwrap().remove("storage");
+    let response = admit("CREATE", Some(object));
+    assert_eq!(response["allowed"], json!(false));
+}